@@ -0,0 +1,366 @@
+//! Ability effects
+//!
+//! [`Ability`](crate::model::pokemon::Ability) resources only describe abilities; their behaviour in
+//! battle comes from [`AbilityEffect`] implementations registered by ability name in [`Abilities`].
+//! See [Bulbapedia](http://bulbapedia.bulbagarden.net/wiki/Ability) for greater detail.
+
+use std::{collections::HashMap, fmt::Debug, sync::Arc};
+
+use super::{
+    damage, move_type, stages,
+    status::{self, Status},
+    target, Battle, BattleEvent, Position, Weather,
+};
+use crate::{
+    generation::Generation,
+    model::{moves::Move, resource::name},
+    names::{PokemonTypeName, StatName},
+};
+
+/// The behaviour of an ability in battle. Every hook does nothing by default.
+pub trait AbilityEffect: Debug + Send + Sync {
+    /// Called when the holder enters the field.
+    fn on_switch_in(&self, _battle: &mut Battle, _holder: Position) {}
+
+    /// Multiplies the damage of a move used by the holder.
+    fn attack_multiplier(
+        &self,
+        _battle: &Battle,
+        _holder: Position,
+        _target: Position,
+        _move_: &Move,
+    ) -> f64 {
+        1.0
+    }
+
+    /// Multiplies the type effectiveness of a move used on the holder. 0 makes the holder immune.
+    fn defence_multiplier(
+        &self,
+        _battle: &Battle,
+        _holder: Position,
+        _attacker: Position,
+        _move_: &Move,
+    ) -> f64 {
+        1.0
+    }
+
+    /// Adjusts the damage the holder is about to take from a move.
+    fn modify_damage_taken(
+        &self,
+        _battle: &Battle,
+        _holder: Position,
+        _move_: &Move,
+        amount: i64,
+    ) -> i64 {
+        amount
+    }
+
+    /// Called after the holder is damaged by a move.
+    fn on_hit(&self, _battle: &mut Battle, _holder: Position, _attacker: Position, _move_: &Move) {}
+
+    /// Called at the end of every turn the holder is on the field.
+    fn on_end_of_turn(&self, _battle: &mut Battle, _holder: Position) {}
+
+    /// Whether a non-volatile status may be inflicted on the holder.
+    fn allows_status(&self, _battle: &Battle, _holder: Position, _status: Status) -> bool {
+        true
+    }
+
+    /// Multiplies the Speed of the holder.
+    fn speed_multiplier(&self, _battle: &Battle, _holder: Position) -> f64 {
+        1.0
+    }
+}
+
+/// The ability effects known to a battle, keyed by PokeAPI ability name.
+#[derive(Debug, Clone)]
+pub struct Abilities {
+    effects: HashMap<String, Arc<dyn AbilityEffect>>,
+}
+
+impl Abilities {
+    /// Creates a registry without any ability effects.
+    pub fn empty() -> Self {
+        Self {
+            effects: HashMap::new(),
+        }
+    }
+
+    /// Registers the effect of an ability, replacing any earlier one of the same name.
+    pub fn register(&mut self, name: impl Into<String>, effect: impl AbilityEffect + 'static) {
+        self.effects.insert(name.into(), Arc::new(effect));
+    }
+
+    pub fn get(&self, name: &str) -> Option<Arc<dyn AbilityEffect>> {
+        self.effects.get(name).cloned()
+    }
+}
+
+impl Default for Abilities {
+    /// Creates a registry with the effects of the common abilities implemented by this module.
+    fn default() -> Self {
+        let mut abilities = Self::empty();
+        abilities.register("intimidate", Intimidate);
+        abilities.register("levitate", Levitate);
+        abilities.register("static", Static);
+        abilities.register(
+            "blaze",
+            Pinch {
+                type_: PokemonTypeName::Fire,
+            },
+        );
+        abilities.register(
+            "torrent",
+            Pinch {
+                type_: PokemonTypeName::Water,
+            },
+        );
+        abilities.register(
+            "overgrow",
+            Pinch {
+                type_: PokemonTypeName::Grass,
+            },
+        );
+        abilities.register(
+            "swarm",
+            Pinch {
+                type_: PokemonTypeName::Bug,
+            },
+        );
+        abilities.register("sturdy", Sturdy);
+        abilities.register("swift-swim", WeatherSpeed(Weather::Rain));
+        abilities.register("chlorophyll", WeatherSpeed(Weather::Sun));
+        abilities.register(
+            "limber",
+            StatusImmunity(|status| status == Status::Paralysis),
+        );
+        abilities.register("insomnia", StatusImmunity(is_sleep));
+        abilities.register("vital-spirit", StatusImmunity(is_sleep));
+        abilities.register(
+            "water-veil",
+            StatusImmunity(|status| status == Status::Burn),
+        );
+        abilities.register(
+            "magma-armor",
+            StatusImmunity(|status| status == Status::Freeze),
+        );
+        abilities.register(
+            "immunity",
+            StatusImmunity(|status| matches!(status, Status::Poison | Status::Toxic { .. })),
+        );
+        abilities
+    }
+}
+
+/// Lowers the Attack of every adjacent opponent on entering the field.
+#[derive(Debug, Clone, Copy)]
+pub struct Intimidate;
+
+impl AbilityEffect for Intimidate {
+    fn on_switch_in(&self, battle: &mut Battle, holder: Position) {
+        activated(battle, holder);
+        for opponent in battle.positions() {
+            if opponent.side != holder.side
+                && target::is_adjacent(battle, holder, opponent)
+                && !battle.battler(opponent).is_fainted()
+            {
+                stages::change_stage(battle, opponent, StatName::Attack, -1);
+            }
+        }
+    }
+}
+
+/// Grants immunity to Ground-type moves.
+#[derive(Debug, Clone, Copy)]
+pub struct Levitate;
+
+impl AbilityEffect for Levitate {
+    fn defence_multiplier(
+        &self,
+        _battle: &Battle,
+        _holder: Position,
+        _attacker: Position,
+        move_: &Move,
+    ) -> f64 {
+        if move_type(move_) == Some(PokemonTypeName::Ground) {
+            0.0
+        } else {
+            1.0
+        }
+    }
+}
+
+/// May paralyze attackers that make contact, and only activates when it does. PokeAPI does not say
+/// which moves make contact, so physical moves are treated as contact moves.
+#[derive(Debug, Clone, Copy)]
+pub struct Static;
+
+impl AbilityEffect for Static {
+    fn on_hit(&self, battle: &mut Battle, holder: Position, attacker: Position, move_: &Move) {
+        let chance = if battle.generation >= Generation::IV {
+            30
+        } else {
+            33
+        };
+        if damage::is_physical(move_)
+            && status::can_inflict_status(battle, attacker, Status::Paralysis)
+            && battle.chance(chance)
+        {
+            activated(battle, holder);
+            status::inflict_status(battle, attacker, Status::Paralysis);
+        }
+    }
+}
+
+/// Blaze, Torrent, Overgrow and Swarm: boosts moves of one type by half when at a third of HP or less.
+#[derive(Debug, Clone, Copy)]
+pub struct Pinch {
+    pub type_: PokemonTypeName,
+}
+
+impl AbilityEffect for Pinch {
+    fn attack_multiplier(
+        &self,
+        battle: &Battle,
+        holder: Position,
+        _target: Position,
+        move_: &Move,
+    ) -> f64 {
+        let battler = battle.battler(holder);
+        if move_type(move_) == Some(self.type_) && battler.hp * 3 <= battler.max_hp() {
+            1.5
+        } else {
+            1.0
+        }
+    }
+}
+
+/// Prevents one-hit knockout moves and, from Generation V, survives any hit from full HP with 1 HP left.
+#[derive(Debug, Clone, Copy)]
+pub struct Sturdy;
+
+impl AbilityEffect for Sturdy {
+    fn defence_multiplier(
+        &self,
+        _battle: &Battle,
+        _holder: Position,
+        _attacker: Position,
+        move_: &Move,
+    ) -> f64 {
+        let category = move_.meta.as_ref().and_then(|meta| name(&meta.category));
+        if category == Some("ohko") {
+            0.0
+        } else {
+            1.0
+        }
+    }
+
+    fn modify_damage_taken(
+        &self,
+        battle: &Battle,
+        holder: Position,
+        _move_: &Move,
+        amount: i64,
+    ) -> i64 {
+        let battler = battle.battler(holder);
+        if battle.generation >= Generation::V
+            && battler.hp == battler.max_hp()
+            && amount >= battler.hp
+        {
+            battler.hp - 1
+        } else {
+            amount
+        }
+    }
+}
+
+/// Swift Swim and Chlorophyll: doubles Speed in the given weather.
+#[derive(Debug, Clone, Copy)]
+pub struct WeatherSpeed(pub Weather);
+
+impl AbilityEffect for WeatherSpeed {
+    fn speed_multiplier(&self, battle: &Battle, _holder: Position) -> f64 {
+        if battle.weather == Some(self.0) {
+            2.0
+        } else {
+            1.0
+        }
+    }
+}
+
+/// Prevents the non-volatile statuses the predicate matches, e.g. paralysis for Limber.
+#[derive(Debug, Clone, Copy)]
+pub struct StatusImmunity(pub fn(Status) -> bool);
+
+impl AbilityEffect for StatusImmunity {
+    fn allows_status(&self, _battle: &Battle, _holder: Position, status: Status) -> bool {
+        !(self.0)(status)
+    }
+}
+
+/// Runs the switch-in hook of a battler's ability.
+pub fn on_switch_in(battle: &mut Battle, holder: Position) {
+    if let Some(effect) = effect_of(battle, holder) {
+        effect.on_switch_in(battle, holder);
+    }
+}
+
+/// The damage multiplier from the attacker's ability.
+pub fn attack_multiplier(battle: &Battle, user: Position, target: Position, move_: &Move) -> f64 {
+    effect_of(battle, user).map_or(1.0, |effect| {
+        effect.attack_multiplier(battle, user, target, move_)
+    })
+}
+
+/// The effectiveness multiplier from the defender's ability.
+pub fn defence_multiplier(battle: &Battle, user: Position, target: Position, move_: &Move) -> f64 {
+    effect_of(battle, target).map_or(1.0, |effect| {
+        effect.defence_multiplier(battle, target, user, move_)
+    })
+}
+
+/// The damage a battler takes from a move after its ability has had its say.
+pub fn modify_damage_taken(battle: &Battle, target: Position, move_: &Move, amount: i64) -> i64 {
+    effect_of(battle, target).map_or(amount, |effect| {
+        effect.modify_damage_taken(battle, target, move_, amount)
+    })
+}
+
+/// Runs the on-hit hook of the ability of a battler damaged by a move.
+pub fn on_hit(battle: &mut Battle, target: Position, user: Position, move_: &Move) {
+    if let Some(effect) = effect_of(battle, target) {
+        effect.on_hit(battle, target, user, move_);
+    }
+}
+
+/// Runs the end-of-turn hook of a battler's ability.
+pub fn on_end_of_turn(battle: &mut Battle, holder: Position) {
+    if let Some(effect) = effect_of(battle, holder) {
+        effect.on_end_of_turn(battle, holder);
+    }
+}
+
+/// Whether a battler's ability lets it receive a non-volatile status.
+pub fn allows_status(battle: &Battle, target: Position, status: Status) -> bool {
+    effect_of(battle, target).is_none_or(|effect| effect.allows_status(battle, target, status))
+}
+
+/// The Speed multiplier from a battler's ability.
+pub fn speed_multiplier(battle: &Battle, holder: Position) -> f64 {
+    effect_of(battle, holder).map_or(1.0, |effect| effect.speed_multiplier(battle, holder))
+}
+
+/// Records that the ability of a battler took effect.
+pub fn activated(battle: &mut Battle, holder: Position) {
+    let ability = battle.battler(holder).ability.clone().unwrap_or_default();
+    battle.emit(BattleEvent::AbilityActivated { holder, ability });
+}
+
+fn effect_of(battle: &Battle, holder: Position) -> Option<Arc<dyn AbilityEffect>> {
+    let ability = battle.battler(holder).ability.as_deref()?;
+    battle.abilities.get(ability)
+}
+
+fn is_sleep(status: Status) -> bool {
+    matches!(status, Status::Sleep { .. })
+}
@@ -0,0 +1,144 @@
+//! Damage calculation
+//!
+//! Uses the formula of Generation III onwards.
+//! See [Bulbapedia](http://bulbapedia.bulbagarden.net/wiki/Damage) for greater detail.
+
+use rand::Rng;
+
+use super::{ability, move_type, status::Status, Battle, Battler, Position, Weather};
+use crate::{
+    generation::Generation,
+    model::moves::Move,
+    names::{DamageClass, PokemonTypeName, StatName},
+};
+
+/// The outcome of a damage roll.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Damage {
+    /// The HP the target loses, before being capped to its remaining HP.
+    pub amount: i64,
+    /// The type effectiveness multiplier, e.g. 2.0 for a super effective hit.
+    pub effectiveness: f64,
+}
+
+/// The damage before any modifier is applied.
+pub fn base_damage(level: i64, power: i64, attack: i64, defence: i64) -> i64 {
+    (2 * level / 5 + 2) * power * attack / defence.max(1) / 50 + 2
+}
+
+/// Whether a move deals physical damage, going by its damage class.
+pub fn is_physical(move_: &Move) -> bool {
+    move_
+        .damage_class
+        .as_ref()
+        .and_then(|class| DamageClass::try_from(class).ok())
+        == Some(DamageClass::Physical)
+}
+
+/// Rolls the damage a move deals from one battler to another.
+/// Critical hits ignore the attacker's lowered and the defender's raised stat stages.
+/// `spread` reduces the damage of a move hitting several targets at once.
+pub fn calculate(
+    battle: &mut Battle,
+    user: Position,
+    target: Position,
+    move_: &Move,
+    critical: bool,
+    spread: bool,
+) -> Damage {
+    let attacker = battle.battler(user);
+    let defender = battle.battler(target);
+    let physical = is_physical(move_);
+    let (attack, defence) = if physical {
+        (StatName::Attack, StatName::Defense)
+    } else {
+        (StatName::SpecialAttack, StatName::SpecialDefense)
+    };
+    let (attack, defence) = if critical {
+        (
+            critical_stat(attacker, attack, |stage| stage < 0),
+            critical_stat(defender, defence, |stage| stage > 0),
+        )
+    } else {
+        (attacker.staged_stat(attack), defender.staged_stat(defence))
+    };
+    let base = base_damage(attacker.level, move_.power.unwrap_or(0), attack, defence);
+
+    let type_ = move_type(move_);
+    let effectiveness = match type_ {
+        Some(type_) => battle
+            .type_chart
+            .effectiveness(type_.name(), &defender.types),
+        None => 1.0,
+    } * ability::defence_multiplier(battle, user, target, move_);
+    let stab = match type_ {
+        Some(type_) if attacker.has_type(type_) => 1.5,
+        _ => 1.0,
+    };
+    let burn = if physical && attacker.status == Some(Status::Burn) {
+        0.5
+    } else {
+        1.0
+    };
+
+    let spread = match (spread, battle.generation >= Generation::IV) {
+        (false, _) => 1.0,
+        (true, true) => 0.75,
+        (true, false) => 0.5,
+    };
+    let weather = match (battle.weather, type_) {
+        (Some(Weather::Rain), Some(PokemonTypeName::Water))
+        | (Some(Weather::Sun), Some(PokemonTypeName::Fire)) => 1.5,
+        (Some(Weather::Rain), Some(PokemonTypeName::Fire))
+        | (Some(Weather::Sun), Some(PokemonTypeName::Water)) => 0.5,
+        _ => 1.0,
+    };
+    let ability = ability::attack_multiplier(battle, user, target, move_);
+    let critical = match (critical, battle.generation >= Generation::VI) {
+        (false, _) => 1.0,
+        (true, true) => 1.5,
+        (true, false) => 2.0,
+    };
+
+    let random = battle.rng().gen_range(85..=100) as f64 / 100.0;
+    let amount = (base as f64
+        * spread
+        * weather
+        * critical
+        * random
+        * stab
+        * effectiveness
+        * burn
+        * ability) as i64;
+    Damage {
+        amount: if effectiveness > 0.0 {
+            amount.max(1)
+        } else {
+            0
+        },
+        effectiveness,
+    }
+}
+
+/// Rolls the typeless 40 power physical hit a confused Pokémon deals to itself.
+pub fn confusion_damage(battle: &mut Battle, position: Position) -> i64 {
+    let battler = battle.battler(position);
+    let base = base_damage(
+        battler.level,
+        40,
+        battler.staged_stat(StatName::Attack),
+        battler.staged_stat(StatName::Defense),
+    );
+    let random = battle.rng().gen_range(85..=100) as f64 / 100.0;
+    ((base as f64 * random) as i64).max(1)
+}
+
+/// A stat for a critical hit, which ignores the stage when `ignored` says so.
+fn critical_stat(battler: &Battler, stat: StatName, ignored: impl Fn(i64) -> bool) -> i64 {
+    let stage = battler.stages.get(stat).unwrap_or_default();
+    if ignored(stage) {
+        battler.stats.get(stat.name()).unwrap_or_default()
+    } else {
+        battler.staged_stat(stat)
+    }
+}
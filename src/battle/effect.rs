@@ -0,0 +1,173 @@
+//! Generic move effects
+//!
+//! Interprets the [`MoveMetaData`] of a move so that multi-hit, draining, recoil, healing and
+//! flinching moves work without code written for each move.
+
+use rand::Rng;
+
+use super::{ability, damage, stages, status, Battle, BattleEvent, DamageCause, Position};
+use crate::{
+    generation::Generation,
+    model::moves::{Move, MoveMetaData},
+};
+
+/// The PokeAPI names of the moves that only work on their user's first turn on the field.
+/// See [Bulbapedia](http://bulbapedia.bulbagarden.net/wiki/Fake_Out_(move)) for greater detail.
+pub const FIRST_TURN_MOVES: [&str; 3] = ["fake-out", "first-impression", "mat-block"];
+
+/// Whether a move fails before affecting anyone, like Fake Out after its user's first turn.
+pub fn fails(battle: &Battle, user: Position, move_: &Move) -> bool {
+    let first_turn_only = move_
+        .name
+        .as_deref()
+        .is_some_and(|name| FIRST_TURN_MOVES.contains(&name));
+    first_turn_only && battle.battler(user).turns_on_field > 0
+}
+
+/// Executes a move that has already hit one of its targets: damage, drain or recoil, healing,
+/// flinching, ailments and stat changes, in that order. `spread` tells whether the move is
+/// hitting several targets at once.
+pub fn execute(battle: &mut Battle, user: Position, target: Position, move_: &Move, spread: bool) {
+    let default_meta = MoveMetaData::default();
+    let meta = move_.meta.as_ref().unwrap_or(&default_meta);
+
+    if move_.power.unwrap_or(0) > 0 {
+        let dealt = match strike(battle, user, target, move_, meta, spread) {
+            Some(dealt) => dealt,
+            None => return,
+        };
+        drain(battle, user, dealt, meta.drain.unwrap_or(0));
+        status::thaw_by_move(battle, target, move_);
+        flinch(battle, target, meta.flinch_chance.unwrap_or(0));
+    } else if status::immune_to_status_move(battle, target, move_) {
+        battle.emit(BattleEvent::NoEffect { target });
+        return;
+    }
+
+    // Damaging moves heal their user, while healing moves heal their targets, which can be the user.
+    let healing = meta.healing.unwrap_or(0);
+    let healed = if move_.power.unwrap_or(0) > 0 {
+        user
+    } else {
+        target
+    };
+    if healing > 0 && !battle.battler(healed).is_fainted() {
+        let amount = battle.battler(healed).fraction_of_max_hp(healing, 100);
+        battle.heal(healed, amount);
+    }
+
+    status::apply_move_ailment(battle, user, target, move_);
+    stages::apply_move_stat_changes(battle, user, target, move_);
+}
+
+/// Rolls how many times a move hits, from its `min_hits` and `max_hits`.
+/// Moves hitting 2 to 5 times favour fewer hits, following the odds of the generation.
+pub fn roll_hits(battle: &mut Battle, meta: &MoveMetaData) -> i64 {
+    let (min, max) = match (meta.min_hits, meta.max_hits) {
+        (Some(min), Some(max)) if max >= min => (min, max),
+        (Some(hits), None) | (None, Some(hits)) => (hits, hits),
+        _ => return 1,
+    };
+    if (min, max) != (2, 5) {
+        return battle.rng().gen_range(min..=max);
+    }
+    // Out of 8 in Generations I to IV and out of 20 afterwards, for 2, 3, 4 and 5 hits.
+    let weights: [i64; 4] = if battle.generation >= Generation::V {
+        [7, 7, 3, 3]
+    } else {
+        [3, 3, 1, 1]
+    };
+    let mut roll = battle.rng().gen_range(0..weights.iter().sum::<i64>());
+    for (hits, weight) in (2..).zip(weights) {
+        if roll < weight {
+            return hits;
+        }
+        roll -= weight;
+    }
+    max
+}
+
+/// The chance of a critical hit, as a fraction, for a critical hit stage.
+/// See [Bulbapedia](http://bulbapedia.bulbagarden.net/wiki/Critical_hit) for greater detail.
+pub fn critical_chance(generation: Generation, stage: i64) -> (i64, i64) {
+    match (generation >= Generation::VI, stage.max(0)) {
+        (_, 0) if generation >= Generation::VII => (1, 24),
+        (_, 0) => (1, 16),
+        (_, 1) => (1, 8),
+        (true, 2) => (1, 2),
+        (true, _) => (1, 1),
+        (false, 2) => (1, 4),
+        (false, 3) => (1, 3),
+        (false, _) => (1, 2),
+    }
+}
+
+/// Rolls whether a hit is critical, given the move's `crit_rate` bonus.
+pub fn roll_critical(battle: &mut Battle, crit_rate: i64) -> bool {
+    let (numerator, denominator) = critical_chance(battle.generation, crit_rate);
+    battle.rng().gen_range(0..denominator) < numerator
+}
+
+/// Deals the damage of every hit of a move. Returns the total damage dealt,
+/// or `None` if the target is immune.
+fn strike(
+    battle: &mut Battle,
+    user: Position,
+    target: Position,
+    move_: &Move,
+    meta: &MoveMetaData,
+    spread: bool,
+) -> Option<i64> {
+    let hits = roll_hits(battle, meta);
+    let mut dealt = 0;
+    let mut landed = 0;
+    for _ in 0..hits {
+        if battle.battler(target).is_fainted() || battle.battler(user).is_fainted() {
+            break;
+        }
+        let critical = roll_critical(battle, meta.crit_rate.unwrap_or(0));
+        let damage = damage::calculate(battle, user, target, move_, critical, spread);
+        if damage.effectiveness == 0.0 {
+            battle.emit(BattleEvent::NoEffect { target });
+            return None;
+        }
+        if critical {
+            battle.emit(BattleEvent::CriticalHit { target });
+        }
+        let amount = ability::modify_damage_taken(battle, target, move_, damage.amount);
+        dealt += battle.damage(target, amount, DamageCause::Move);
+        landed += 1;
+        if !battle.battler(target).is_fainted() {
+            ability::on_hit(battle, target, user, move_);
+        }
+    }
+    if hits > 1 {
+        battle.emit(BattleEvent::HitCount {
+            target,
+            hits: landed,
+        });
+    }
+    Some(dealt)
+}
+
+/// Restores HP to the user for a positive `drain` or hurts it with recoil for a negative one,
+/// as a percentage of the damage dealt.
+fn drain(battle: &mut Battle, user: Position, dealt: i64, drain: i64) {
+    if drain == 0 || dealt == 0 || battle.battler(user).is_fainted() {
+        return;
+    }
+    let amount = (dealt * drain.abs() / 100).max(1);
+    if drain > 0 {
+        battle.heal(user, amount);
+    } else {
+        battle.damage(user, amount, DamageCause::Recoil);
+    }
+}
+
+/// Makes the target flinch if it has yet to move this turn and the `flinch_chance` roll succeeds.
+fn flinch(battle: &mut Battle, target: Position, chance: i64) {
+    let battler = battle.battler(target);
+    if chance > 0 && !battler.is_fainted() && !battler.acted && battle.chance(chance) {
+        battle.battler_mut(target).volatiles.flinch = true;
+    }
+}
@@ -0,0 +1,486 @@
+//! Battle engine
+//!
+//! A [`Battle`] holds the active Pokémon of both sides and resolves moves fetched from the
+//! `move` endpoint, using their [`MoveMetaData`](crate::model::moves::MoveMetaData) to drive
+//! secondary effects.
+
+pub mod ability;
+pub mod damage;
+pub mod effect;
+pub mod stages;
+pub mod status;
+pub mod target;
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+use crate::{
+    generation::Generation,
+    model::moves::Move,
+    names::{PokemonTypeName, StatName},
+    owned::Gender,
+    stats::Stats,
+    type_chart::TypeChart,
+};
+
+use ability::Abilities;
+use stages::StatStages;
+use status::{CantMoveReason, Status, Volatile, Volatiles};
+use target::TargetError;
+
+/// The place of an active Pokémon on the field: which side it fights for and which slot it occupies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Position {
+    pub side: usize,
+    pub slot: usize,
+}
+
+impl Position {
+    pub fn new(side: usize, slot: usize) -> Self {
+        Self { side, slot }
+    }
+}
+
+/// A Pokémon taking part in a battle.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Battler {
+    /// The name shown for this Pokémon.
+    pub name: String,
+    pub level: i64,
+    pub gender: Gender,
+    /// This Pokémon's types, in slot order.
+    pub types: Vec<PokemonTypeName>,
+    /// The calculated stats of this Pokémon. `stats.hp` is its maximum HP.
+    pub stats: Stats,
+    /// The remaining HP.
+    pub hp: i64,
+    /// The non-volatile status condition, if any.
+    pub status: Option<Status>,
+    /// The volatile status conditions.
+    pub volatiles: Volatiles,
+    /// The stat stages raised or lowered by moves.
+    pub stages: StatStages,
+    /// The PokeAPI name of this Pokémon's ability, e.g. `static`.
+    pub ability: Option<String>,
+    /// Whether this battler has had its turn to move this turn, even if it could not move.
+    pub acted: bool,
+    /// How many turns this battler has ended on the field, 0 during the turn it was sent out.
+    pub turns_on_field: i64,
+}
+
+impl Battler {
+    /// Creates a battler at full HP with no status conditions.
+    pub fn new(
+        name: impl Into<String>,
+        level: i64,
+        gender: Gender,
+        types: Vec<PokemonTypeName>,
+        stats: Stats,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            level,
+            gender,
+            types,
+            stats,
+            hp: stats.hp,
+            status: None,
+            volatiles: Volatiles::default(),
+            stages: StatStages::default(),
+            ability: None,
+            acted: false,
+            turns_on_field: 0,
+        }
+    }
+
+    /// Gives the battler an ability by its PokeAPI name.
+    pub fn with_ability(mut self, ability: impl Into<String>) -> Self {
+        self.ability = Some(ability.into());
+        self
+    }
+
+    pub fn max_hp(&self) -> i64 {
+        self.stats.hp
+    }
+
+    pub fn is_fainted(&self) -> bool {
+        self.hp <= 0
+    }
+
+    pub fn has_type(&self, type_: PokemonTypeName) -> bool {
+        self.types.contains(&type_)
+    }
+
+    /// A stat after applying its stage, e.g. the Attack used for damage.
+    pub fn staged_stat(&self, stat: StatName) -> i64 {
+        let stage = self.stages.get(stat).unwrap_or_default();
+        let stat = self.stats.get(stat.name()).unwrap_or_default();
+        (stat as f64 * stages::stat_multiplier(stage)) as i64
+    }
+
+    /// A fraction of this Pokémon's maximum HP, never less than 1.
+    pub fn fraction_of_max_hp(&self, numerator: i64, denominator: i64) -> i64 {
+        (self.max_hp() * numerator / denominator).max(1)
+    }
+}
+
+/// Weather conditions that change the power of moves and trigger some abilities.
+/// See [Bulbapedia](http://bulbapedia.bulbagarden.net/wiki/Weather) for greater detail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Weather {
+    Rain,
+    Sun,
+    Sandstorm,
+    Hail,
+}
+
+/// What caused a Pokémon to lose HP.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DamageCause {
+    Move,
+    Confusion,
+    Burn,
+    Poison,
+    Trap,
+    Recoil,
+}
+
+/// Something that happened during a battle. The [`Battle::log`] lists them in order for UIs to replay.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BattleEvent {
+    MoveUsed {
+        user: Position,
+        move_: String,
+    },
+    Missed {
+        user: Position,
+        target: Position,
+    },
+    NoEffect {
+        target: Position,
+    },
+    /// The move was used but failed, e.g. Fake Out after its user's first turn.
+    Failed {
+        user: Position,
+    },
+    Damaged {
+        target: Position,
+        amount: i64,
+        cause: DamageCause,
+    },
+    Fainted {
+        target: Position,
+    },
+    Healed {
+        target: Position,
+        amount: i64,
+    },
+    CriticalHit {
+        target: Position,
+    },
+    /// A multi-hit move finished, having hit `hits` times.
+    HitCount {
+        target: Position,
+        hits: i64,
+    },
+    CantMove {
+        user: Position,
+        reason: CantMoveReason,
+    },
+    StatusInflicted {
+        target: Position,
+        status: Status,
+    },
+    StatusCured {
+        target: Position,
+    },
+    VolatileInflicted {
+        target: Position,
+        volatile: Volatile,
+    },
+    VolatileEnded {
+        target: Position,
+        volatile: Volatile,
+    },
+    AbilityActivated {
+        holder: Position,
+        ability: String,
+    },
+    /// A stat stage was raised or lowered. A `change` of 0 means it could not go any further.
+    StatStageChanged {
+        target: Position,
+        stat: StatName,
+        change: i64,
+    },
+}
+
+/// A move chosen by a battler for the coming turn.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Action {
+    pub user: Position,
+    /// The chosen target, for moves that let the user choose one.
+    /// May be left out when only one battler could be chosen.
+    pub target: Option<Position>,
+    pub move_: Move,
+}
+
+/// The state of a battle between two sides.
+#[derive(Debug, Clone)]
+pub struct Battle {
+    /// The generation whose mechanics are used.
+    pub generation: Generation,
+    /// The type matchups used for damage calculation.
+    pub type_chart: TypeChart,
+    /// The active battlers of each side, indexed by [`Position`].
+    pub sides: [Vec<Battler>; 2],
+    /// The effects of the abilities battlers may have.
+    pub abilities: Abilities,
+    /// The current weather, if any.
+    pub weather: Option<Weather>,
+    /// Every event since the battle started.
+    pub log: Vec<BattleEvent>,
+    rng: StdRng,
+}
+
+impl Battle {
+    /// Creates a battle. The same seed and the same actions always produce the same battle.
+    pub fn new(
+        generation: Generation,
+        type_chart: TypeChart,
+        sides: [Vec<Battler>; 2],
+        seed: u64,
+    ) -> Self {
+        Self {
+            generation,
+            type_chart,
+            sides,
+            abilities: Abilities::default(),
+            weather: None,
+            log: Vec::new(),
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    /// Creates a double battle between two pairs of Pokémon.
+    pub fn double(
+        generation: Generation,
+        type_chart: TypeChart,
+        player: [Battler; 2],
+        opponent: [Battler; 2],
+        seed: u64,
+    ) -> Self {
+        Self::new(
+            generation,
+            type_chart,
+            [player.into(), opponent.into()],
+            seed,
+        )
+    }
+
+    /// Creates a battle between two single Pokémon.
+    pub fn single(
+        generation: Generation,
+        type_chart: TypeChart,
+        player: Battler,
+        opponent: Battler,
+        seed: u64,
+    ) -> Self {
+        Self::new(generation, type_chart, [vec![player], vec![opponent]], seed)
+    }
+
+    /// # Panics
+    ///
+    /// Panics if no battler occupies the position.
+    pub fn battler(&self, position: Position) -> &Battler {
+        &self.sides[position.side][position.slot]
+    }
+
+    /// # Panics
+    ///
+    /// Panics if no battler occupies the position.
+    pub fn battler_mut(&mut self, position: Position) -> &mut Battler {
+        &mut self.sides[position.side][position.slot]
+    }
+
+    /// The positions of every active battler, side by side.
+    pub fn positions(&self) -> Vec<Position> {
+        (0..self.sides.len())
+            .flat_map(|side| (0..self.sides[side].len()).map(move |slot| Position::new(side, slot)))
+            .collect()
+    }
+
+    /// Sends out every battler, running switch-in abilities in Speed order.
+    pub fn start(&mut self) {
+        let mut positions = self.positions();
+        positions.sort_by_key(|position| std::cmp::Reverse(self.effective_speed(*position)));
+        for position in positions {
+            ability::on_switch_in(self, position);
+        }
+    }
+
+    /// Replaces the battler at a position with a new one and runs its switch-in ability.
+    pub fn switch_in(&mut self, position: Position, battler: Battler) {
+        *self.battler_mut(position) = battler;
+        for other in self.positions() {
+            if self.battler(other).volatiles.infatuation == Some(position) {
+                status::end_volatile(self, other, Volatile::Infatuation);
+            }
+        }
+        ability::on_switch_in(self, position);
+    }
+
+    /// The Speed used to order moves, after stat stages, status and ability modifiers.
+    pub fn effective_speed(&self, position: Position) -> i64 {
+        let battler = self.battler(position);
+        let multiplier = status::speed_multiplier(battler, self.generation)
+            * ability::speed_multiplier(self, position);
+        (battler.staged_stat(StatName::Speed) as f64 * multiplier) as i64
+    }
+
+    /// Resolves a full turn: moves are used in priority then Speed order, followed by end-of-turn effects.
+    /// Nothing happens if any action has an illegal target. Targets are only checked before the turn
+    /// starts: a move whose target faints first is redirected or fails, and the turn goes on.
+    pub fn run_turn(&mut self, mut actions: Vec<Action>) -> Result<(), TargetError> {
+        for action in &actions {
+            target::validate(self, action.user, &action.move_, action.target)?;
+        }
+        actions.shuffle(&mut self.rng);
+        actions.sort_by_key(|action| {
+            let priority = action.move_.priority.unwrap_or(0);
+            std::cmp::Reverse((priority, self.effective_speed(action.user)))
+        });
+        for action in &actions {
+            self.perform(action.user, action.target, &action.move_);
+        }
+        self.end_turn();
+        Ok(())
+    }
+
+    /// Makes a battler use a move, aimed at `choice` if the move lets its user choose a target.
+    /// Moves hitting several battlers at once have their damage reduced.
+    pub fn use_move(
+        &mut self,
+        user: Position,
+        choice: Option<Position>,
+        move_: &Move,
+    ) -> Result<(), TargetError> {
+        target::validate(self, user, move_, choice)?;
+        self.perform(user, choice, move_);
+        Ok(())
+    }
+
+    /// Uses a move whose target choice has already been validated.
+    fn perform(&mut self, user: Position, choice: Option<Position>, move_: &Move) {
+        if self.battler(user).is_fainted() {
+            return;
+        }
+        self.battler_mut(user).acted = true;
+        if !status::can_move(self, user) {
+            return;
+        }
+        self.emit(BattleEvent::MoveUsed {
+            user,
+            move_: move_.name.clone().unwrap_or_default(),
+        });
+        if effect::fails(self, user, move_) {
+            self.emit(BattleEvent::Failed { user });
+            return;
+        }
+        let targets = target::resolve(self, user, move_, choice);
+        let target = target::Target::of(move_);
+        if targets.is_empty() && !target.is_field() {
+            self.emit(BattleEvent::Failed { user });
+            return;
+        }
+        let spread = targets.len() > 1 && target.is_spread();
+        for target in targets {
+            if self.battler(target).is_fainted() || self.battler(user).is_fainted() {
+                continue;
+            }
+            if !self.hits(user, target, move_) {
+                self.emit(BattleEvent::Missed { user, target });
+                continue;
+            }
+            effect::execute(self, user, target, move_, spread);
+        }
+    }
+
+    /// Applies the effects that happen once every battler has moved.
+    pub fn end_turn(&mut self) {
+        for position in self.positions() {
+            status::residual(self, position);
+            if !self.battler(position).is_fainted() {
+                ability::on_end_of_turn(self, position);
+            }
+            let battler = self.battler_mut(position);
+            battler.volatiles.flinch = false;
+            battler.acted = false;
+            battler.turns_on_field += 1;
+        }
+    }
+
+    /// Removes HP from a battler, recording the damage and a possible faint.
+    /// Returns the HP actually lost.
+    pub fn damage(&mut self, target: Position, amount: i64, cause: DamageCause) -> i64 {
+        let battler = self.battler_mut(target);
+        if battler.is_fainted() {
+            return 0;
+        }
+        let amount = amount.min(battler.hp);
+        battler.hp -= amount;
+        let fainted = battler.is_fainted();
+        self.emit(BattleEvent::Damaged {
+            target,
+            amount,
+            cause,
+        });
+        if fainted {
+            self.emit(BattleEvent::Fainted { target });
+        }
+        amount
+    }
+
+    /// Restores HP to a battler, up to its maximum. Returns the HP actually restored.
+    pub fn heal(&mut self, target: Position, amount: i64) -> i64 {
+        let battler = self.battler_mut(target);
+        if battler.is_fainted() {
+            return 0;
+        }
+        let amount = amount.min(battler.max_hp() - battler.hp).max(0);
+        battler.hp += amount;
+        if amount > 0 {
+            self.emit(BattleEvent::Healed { target, amount });
+        }
+        amount
+    }
+
+    /// Rolls a percent chance.
+    pub fn chance(&mut self, percent: i64) -> bool {
+        self.rng.gen_range(0..100) < percent
+    }
+
+    pub fn rng(&mut self) -> &mut StdRng {
+        &mut self.rng
+    }
+
+    pub fn emit(&mut self, event: BattleEvent) {
+        self.log.push(event);
+    }
+
+    fn hits(&mut self, user: Position, target: Position, move_: &Move) -> bool {
+        let accuracy = match move_.accuracy {
+            Some(accuracy) => accuracy,
+            None => return true,
+        };
+        let stage = self.battler(user).stages.accuracy - self.battler(target).stages.evasion;
+        let accuracy = (accuracy as f64 * stages::accuracy_multiplier(stage)) as i64;
+        self.chance(accuracy)
+    }
+}
+
+/// The type of a move, unless PokeAPI gives none or one this crate does not know.
+pub(crate) fn move_type(move_: &Move) -> Option<PokemonTypeName> {
+    move_
+        .type_
+        .as_ref()
+        .and_then(|type_| PokemonTypeName::try_from(type_).ok())
+}
@@ -0,0 +1,136 @@
+//! Stat stages
+//!
+//! Moves raise or lower stats for the rest of a battle through their
+//! [`MoveStatChange`](crate::model::moves::MoveStatChange) list.
+//! See [Bulbapedia](http://bulbapedia.bulbagarden.net/wiki/Stat_modifier) for greater detail.
+
+use super::{Battle, BattleEvent, Position};
+use crate::{
+    model::{moves::Move, resource::name},
+    names::StatName,
+};
+
+/// The stages of the in-battle stats of a battler, each between [`StatStages::MIN`] and [`StatStages::MAX`].
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StatStages {
+    pub attack: i64,
+    pub defence: i64,
+    pub special_attack: i64,
+    pub special_defence: i64,
+    pub speed: i64,
+    pub accuracy: i64,
+    pub evasion: i64,
+}
+
+impl StatStages {
+    pub const MIN: i64 = -6;
+    pub const MAX: i64 = 6;
+
+    /// Gets the stage of a stat, or `None` for HP, which has no stages.
+    pub fn get(&self, stat: StatName) -> Option<i64> {
+        match stat {
+            StatName::Hp => None,
+            StatName::Attack => Some(self.attack),
+            StatName::Defense => Some(self.defence),
+            StatName::SpecialAttack => Some(self.special_attack),
+            StatName::SpecialDefense => Some(self.special_defence),
+            StatName::Speed => Some(self.speed),
+            StatName::Accuracy => Some(self.accuracy),
+            StatName::Evasion => Some(self.evasion),
+        }
+    }
+
+    /// Raises or lowers the stage of a stat, keeping it within bounds.
+    /// Returns the change actually made, or `None` for HP, which has no stages.
+    pub fn change(&mut self, stat: StatName, amount: i64) -> Option<i64> {
+        let stage = match stat {
+            StatName::Hp => return None,
+            StatName::Attack => &mut self.attack,
+            StatName::Defense => &mut self.defence,
+            StatName::SpecialAttack => &mut self.special_attack,
+            StatName::SpecialDefense => &mut self.special_defence,
+            StatName::Speed => &mut self.speed,
+            StatName::Accuracy => &mut self.accuracy,
+            StatName::Evasion => &mut self.evasion,
+        };
+        let before = *stage;
+        *stage = (before + amount).clamp(Self::MIN, Self::MAX);
+        Some(*stage - before)
+    }
+}
+
+/// The multiplier a stage applies to Attack, Defense, Special Attack, Special Defense or Speed.
+pub fn stat_multiplier(stage: i64) -> f64 {
+    let stage = stage.clamp(StatStages::MIN, StatStages::MAX);
+    if stage >= 0 {
+        (2 + stage) as f64 / 2.0
+    } else {
+        2.0 / (2 - stage) as f64
+    }
+}
+
+/// The multiplier applied to a move's accuracy, given the user's accuracy stage minus the target's evasion stage.
+pub fn accuracy_multiplier(stage: i64) -> f64 {
+    let stage = stage.clamp(StatStages::MIN, StatStages::MAX);
+    if stage >= 0 {
+        (3 + stage) as f64 / 3.0
+    } else {
+        3.0 / (3 - stage) as f64
+    }
+}
+
+/// Applies the stat changes of a move, if its `stat_chance` roll succeeds.
+/// Damaging moves that raise stats change the user's stages; every other move changes the target's,
+/// which is the user itself for moves such as Swords Dance.
+pub fn apply_move_stat_changes(
+    battle: &mut Battle,
+    user: Position,
+    target: Position,
+    move_: &Move,
+) {
+    let changes = match &move_.stat_changes {
+        Some(changes) if !changes.is_empty() => changes,
+        _ => return,
+    };
+    let meta = move_.meta.as_ref();
+    let category = meta.and_then(|meta| name(&meta.category));
+    let recipient = if category == Some("damage+raise") {
+        user
+    } else {
+        target
+    };
+    if battle.battler(recipient).is_fainted() {
+        return;
+    }
+
+    // A chance of 0 means the stat change is the point of the move and always applies once it hits.
+    let chance = meta.and_then(|meta| meta.stat_chance).unwrap_or(0);
+    if chance > 0 && !battle.chance(chance) {
+        return;
+    }
+
+    for change in changes {
+        let stat = change
+            .stat
+            .as_ref()
+            .and_then(|stat| StatName::try_from(stat).ok());
+        if let (Some(stat), Some(amount)) = (stat, change.change) {
+            change_stage(battle, recipient, stat, amount);
+        }
+    }
+}
+
+/// Raises or lowers one stage of a battler and records the result.
+/// Returns the change actually made, which is 0 when the stage is already at its limit.
+pub fn change_stage(battle: &mut Battle, target: Position, stat: StatName, amount: i64) -> i64 {
+    let change = match battle.battler_mut(target).stages.change(stat, amount) {
+        Some(change) => change,
+        None => return 0,
+    };
+    battle.emit(BattleEvent::StatStageChanged {
+        target,
+        stat,
+        change,
+    });
+    change
+}
@@ -0,0 +1,436 @@
+//! Status conditions
+//!
+//! Moves describe the conditions they cause through the `ailment`, `ailment_chance`, `min_turns`
+//! and `max_turns` fields of their [`MoveMetaData`](crate::model::moves::MoveMetaData).
+//! See [Bulbapedia](http://bulbapedia.bulbagarden.net/wiki/Status_condition) for greater detail.
+
+use std::ops::RangeInclusive;
+
+use rand::Rng;
+
+use super::{ability, damage, move_type, Battle, BattleEvent, Battler, DamageCause, Position};
+use crate::{
+    generation::Generation,
+    model::{moves::Move, resource::name},
+    names::{Ailment, PokemonTypeName},
+};
+
+/// A non-volatile status condition. A Pokémon can only have one at a time and keeps it after switching out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Status {
+    Burn,
+    Paralysis,
+    Poison,
+    /// Badly poisoned. `turns` counts the turns spent badly poisoned, which scales the damage taken.
+    Toxic {
+        turns: i64,
+    },
+    /// Asleep for the given number of turns.
+    Sleep {
+        turns: i64,
+    },
+    Freeze,
+}
+
+/// A volatile status condition. Several can affect a Pokémon at once and they end when it switches out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Volatile {
+    Confusion,
+    Trap,
+    Infatuation,
+}
+
+/// The volatile status conditions affecting a battler.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct Volatiles {
+    /// The remaining turns of confusion.
+    pub confusion: Option<i64>,
+    /// The remaining turns of being bound by a trapping move.
+    pub trap: Option<i64>,
+    /// The Pokémon this battler is infatuated with.
+    pub infatuation: Option<Position>,
+    /// Whether this battler flinched and cannot move for the rest of the turn.
+    pub flinch: bool,
+}
+
+/// Why a Pokémon could not use its move.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CantMoveReason {
+    Asleep,
+    Frozen,
+    Flinched,
+    FullyParalyzed,
+    HurtItselfInConfusion,
+    Infatuated,
+}
+
+/// The numbers behind status conditions, which differ between generations.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatusRules {
+    /// Burn damage at the end of each turn, as a fraction of maximum HP.
+    pub burn_damage: (i64, i64),
+    /// Poison damage at the end of each turn, as a fraction of maximum HP.
+    pub poison_damage: (i64, i64),
+    /// Damage from trapping moves at the end of each turn, as a fraction of maximum HP.
+    pub trap_damage: (i64, i64),
+    /// The Speed multiplier of a paralyzed Pokémon.
+    pub paralysis_speed: f64,
+    /// Percent chance that a paralyzed Pokémon cannot move.
+    pub full_paralysis_chance: i64,
+    /// Percent chance that a frozen Pokémon thaws out before moving.
+    pub thaw_chance: i64,
+    /// Percent chance that a confused Pokémon hurts itself.
+    pub confusion_self_hit_chance: i64,
+    /// Percent chance that an infatuated Pokémon cannot move.
+    pub infatuation_chance: i64,
+    /// How long sleep lasts when the move does not say.
+    pub sleep_turns: RangeInclusive<i64>,
+    /// How long confusion lasts when the move does not say.
+    pub confusion_turns: RangeInclusive<i64>,
+    /// How long trapping moves last when the move does not say.
+    pub trap_turns: RangeInclusive<i64>,
+    /// Whether Electric types cannot be paralyzed.
+    pub electric_immune_to_paralysis: bool,
+    /// Whether Steel types cannot be poisoned.
+    pub steel_immune_to_poison: bool,
+    /// Whether Normal-type status moves, i.e. Glare, affect Ghost types despite the type chart.
+    /// See [Bulbapedia](http://bulbapedia.bulbagarden.net/wiki/Glare_(move)) for greater detail.
+    pub normal_status_moves_affect_ghosts: bool,
+}
+
+impl StatusRules {
+    pub fn for_generation(generation: Generation) -> Self {
+        use Generation::*;
+        Self {
+            burn_damage: match generation {
+                I | VII | VIII | IX => (1, 16),
+                _ => (1, 8),
+            },
+            poison_damage: if generation == I { (1, 16) } else { (1, 8) },
+            trap_damage: if generation >= VI { (1, 8) } else { (1, 16) },
+            paralysis_speed: if generation >= VII { 0.5 } else { 0.25 },
+            full_paralysis_chance: 25,
+            thaw_chance: match generation {
+                I => 0,
+                II => 10,
+                _ => 20,
+            },
+            confusion_self_hit_chance: if generation >= VII { 33 } else { 50 },
+            infatuation_chance: 50,
+            sleep_turns: match generation {
+                I => 1..=7,
+                II => 1..=6,
+                III | IV => 2..=5,
+                _ => 1..=3,
+            },
+            confusion_turns: if generation >= VII { 2..=5 } else { 1..=4 },
+            trap_turns: if generation >= V { 4..=5 } else { 2..=5 },
+            electric_immune_to_paralysis: generation >= VI,
+            steel_immune_to_poison: generation >= II,
+            normal_status_moves_affect_ghosts: generation == I || generation >= IV,
+        }
+    }
+}
+
+/// The Speed multiplier a battler's status condition imposes.
+pub fn speed_multiplier(battler: &Battler, generation: Generation) -> f64 {
+    match battler.status {
+        Some(Status::Paralysis) => StatusRules::for_generation(generation).paralysis_speed,
+        _ => 1.0,
+    }
+}
+
+/// Checks the conditions that can stop a battler from moving, advancing their counters.
+/// Returns whether the battler may use its move.
+pub fn can_move(battle: &mut Battle, user: Position) -> bool {
+    let rules = StatusRules::for_generation(battle.generation);
+
+    match battle.battler(user).status {
+        Some(Status::Sleep { turns }) if turns > 0 => {
+            battle.battler_mut(user).status = Some(Status::Sleep { turns: turns - 1 });
+            return cant_move(battle, user, CantMoveReason::Asleep);
+        }
+        Some(Status::Sleep { .. }) => cure(battle, user),
+        Some(Status::Freeze) => {
+            if !battle.chance(rules.thaw_chance) {
+                return cant_move(battle, user, CantMoveReason::Frozen);
+            }
+            cure(battle, user);
+        }
+        _ => {}
+    }
+
+    if battle.battler(user).volatiles.flinch {
+        return cant_move(battle, user, CantMoveReason::Flinched);
+    }
+
+    if let Some(turns) = battle.battler(user).volatiles.confusion {
+        if turns == 0 {
+            end_volatile(battle, user, Volatile::Confusion);
+        } else {
+            battle.battler_mut(user).volatiles.confusion = Some(turns - 1);
+            if battle.chance(rules.confusion_self_hit_chance) {
+                cant_move(battle, user, CantMoveReason::HurtItselfInConfusion);
+                let amount = damage::confusion_damage(battle, user);
+                battle.damage(user, amount, DamageCause::Confusion);
+                return false;
+            }
+        }
+    }
+
+    if battle.battler(user).status == Some(Status::Paralysis)
+        && battle.chance(rules.full_paralysis_chance)
+    {
+        return cant_move(battle, user, CantMoveReason::FullyParalyzed);
+    }
+
+    if let Some(source) = battle.battler(user).volatiles.infatuation {
+        let source_active = battle
+            .sides
+            .get(source.side)
+            .and_then(|side| side.get(source.slot))
+            .is_some_and(|source| !source.is_fainted());
+        if !source_active {
+            end_volatile(battle, user, Volatile::Infatuation);
+        } else if battle.chance(rules.infatuation_chance) {
+            return cant_move(battle, user, CantMoveReason::Infatuated);
+        }
+    }
+
+    true
+}
+
+/// Inflicts the ailment of a move on its target, if its metadata has one and the chance roll succeeds.
+pub fn apply_move_ailment(battle: &mut Battle, user: Position, target: Position, move_: &Move) {
+    let meta = match &move_.meta {
+        Some(meta) => meta,
+        None => return,
+    };
+    let ailment = match meta
+        .ailment
+        .as_ref()
+        .and_then(|ailment| Ailment::try_from(ailment).ok())
+    {
+        Some(ailment) => ailment,
+        None => return,
+    };
+    if battle.battler(target).is_fainted() {
+        return;
+    }
+    // A chance of 0 means the ailment is the point of the move and always applies once it hits.
+    let chance = meta.ailment_chance.unwrap_or(0);
+    if chance > 0 && !battle.chance(chance) {
+        return;
+    }
+
+    let turns = match (meta.min_turns, meta.max_turns) {
+        (Some(min), Some(max)) => Some(min..=max),
+        (Some(turns), None) | (None, Some(turns)) => Some(turns..=turns),
+        (None, None) => None,
+    };
+    if ailment == Ailment::Poison && badly_poisons(move_) {
+        inflict_status(battle, target, Status::Toxic { turns: 0 });
+    } else {
+        inflict(battle, user, target, ailment, turns);
+    }
+}
+
+/// Whether a move's `poison` ailment badly poisons the target, like Toxic. PokeAPI does not tell both
+/// kinds of poison apart but in the English effect of the move, e.g. "Badly poisons the target".
+pub fn badly_poisons(move_: &Move) -> bool {
+    move_
+        .effect_entries
+        .iter()
+        .flatten()
+        .filter(|entry| name(&entry.language) == Some("en"))
+        .filter_map(|entry| entry.short_effect.as_deref())
+        .any(|effect| effect.to_lowercase().contains("badly poison"))
+}
+
+/// Whether a status move cannot affect a target because of its types, like Thunder Wave against
+/// Ground types. Status moves ignore the type chart, except those paralyzing or poisoning their target.
+pub fn immune_to_status_move(battle: &Battle, target: Position, move_: &Move) -> bool {
+    let ailment = move_
+        .meta
+        .as_ref()
+        .and_then(|meta| meta.ailment.as_ref())
+        .and_then(|ailment| Ailment::try_from(ailment).ok());
+    if !matches!(ailment, Some(Ailment::Paralysis | Ailment::Poison)) {
+        return false;
+    }
+    let Some(type_) = move_type(move_) else {
+        return false;
+    };
+    let rules = StatusRules::for_generation(battle.generation);
+    if type_ == PokemonTypeName::Normal && rules.normal_status_moves_affect_ghosts {
+        return false;
+    }
+    let defender = battle.battler(target);
+    battle
+        .type_chart
+        .effectiveness(type_.name(), &defender.types)
+        == 0.0
+}
+
+/// Inflicts an ailment. Ailments without an effect here, such as Nightmare, are not inflicted.
+/// `turns` overrides the generation's default duration for sleep, confusion and trapping.
+/// Returns whether the target is now affected.
+pub fn inflict(
+    battle: &mut Battle,
+    source: Position,
+    target: Position,
+    ailment: Ailment,
+    turns: Option<RangeInclusive<i64>>,
+) -> bool {
+    let rules = StatusRules::for_generation(battle.generation);
+    match ailment {
+        Ailment::Burn => inflict_status(battle, target, Status::Burn),
+        Ailment::Paralysis => inflict_status(battle, target, Status::Paralysis),
+        Ailment::Poison => inflict_status(battle, target, Status::Poison),
+        Ailment::Freeze => inflict_status(battle, target, Status::Freeze),
+        Ailment::Sleep => {
+            let turns = battle.rng().gen_range(turns.unwrap_or(rules.sleep_turns));
+            inflict_status(battle, target, Status::Sleep { turns })
+        }
+        Ailment::Confusion => {
+            if battle.battler(target).volatiles.confusion.is_some() {
+                return false;
+            }
+            let turns = battle
+                .rng()
+                .gen_range(turns.unwrap_or(rules.confusion_turns));
+            battle.battler_mut(target).volatiles.confusion = Some(turns);
+            inflicted_volatile(battle, target, Volatile::Confusion)
+        }
+        Ailment::Trap => {
+            if battle.battler(target).volatiles.trap.is_some() {
+                return false;
+            }
+            let turns = battle.rng().gen_range(turns.unwrap_or(rules.trap_turns));
+            battle.battler_mut(target).volatiles.trap = Some(turns);
+            inflicted_volatile(battle, target, Volatile::Trap)
+        }
+        Ailment::Infatuation => {
+            let target_gender = battle.battler(target).gender;
+            if battle.battler(target).volatiles.infatuation.is_some()
+                || !battle.battler(source).gender.is_opposite(target_gender)
+            {
+                return false;
+            }
+            battle.battler_mut(target).volatiles.infatuation = Some(source);
+            inflicted_volatile(battle, target, Volatile::Infatuation)
+        }
+        _ => false,
+    }
+}
+
+/// Inflicts a non-volatile status unless the target already has one or its types make it immune.
+/// Returns whether the target is now affected.
+pub fn inflict_status(battle: &mut Battle, target: Position, status: Status) -> bool {
+    if !can_inflict_status(battle, target, status) {
+        return false;
+    }
+    battle.battler_mut(target).status = Some(status);
+    battle.emit(BattleEvent::StatusInflicted { target, status });
+    true
+}
+
+/// Whether a non-volatile status would take hold of the target: it has none yet, has not fainted,
+/// and neither its types nor its ability make it immune.
+pub fn can_inflict_status(battle: &Battle, target: Position, status: Status) -> bool {
+    let rules = StatusRules::for_generation(battle.generation);
+    let battler = battle.battler(target);
+    let immune = match status {
+        Status::Burn => battler.has_type(PokemonTypeName::Fire),
+        Status::Paralysis => {
+            rules.electric_immune_to_paralysis && battler.has_type(PokemonTypeName::Electric)
+        }
+        Status::Poison | Status::Toxic { .. } => {
+            battler.has_type(PokemonTypeName::Poison)
+                || (rules.steel_immune_to_poison && battler.has_type(PokemonTypeName::Steel))
+        }
+        Status::Freeze => battler.has_type(PokemonTypeName::Ice),
+        Status::Sleep { .. } => false,
+    };
+    !immune
+        && battler.status.is_none()
+        && !battler.is_fainted()
+        && ability::allows_status(battle, target, status)
+}
+
+/// Thaws a frozen target hit by a damaging Fire-type move.
+pub fn thaw_by_move(battle: &mut Battle, target: Position, move_: &Move) {
+    if move_type(move_) == Some(PokemonTypeName::Fire)
+        && battle.battler(target).status == Some(Status::Freeze)
+    {
+        cure(battle, target);
+    }
+}
+
+/// Applies end-of-turn damage from burn, poison and trapping moves.
+pub fn residual(battle: &mut Battle, position: Position) {
+    let rules = StatusRules::for_generation(battle.generation);
+    if battle.battler(position).is_fainted() {
+        return;
+    }
+
+    let status_damage = match battle.battler(position).status {
+        Some(Status::Burn) => Some((rules.burn_damage, DamageCause::Burn)),
+        Some(Status::Poison) => Some((rules.poison_damage, DamageCause::Poison)),
+        Some(Status::Toxic { turns }) => {
+            let turns = (turns + 1).min(15);
+            battle.battler_mut(position).status = Some(Status::Toxic { turns });
+            Some(((turns, 16), DamageCause::Poison))
+        }
+        _ => None,
+    };
+    if let Some(((numerator, denominator), cause)) = status_damage {
+        let amount = battle
+            .battler(position)
+            .fraction_of_max_hp(numerator, denominator);
+        battle.damage(position, amount, cause);
+    }
+
+    if let Some(turns) = battle.battler(position).volatiles.trap {
+        if turns == 0 {
+            end_volatile(battle, position, Volatile::Trap);
+        } else if !battle.battler(position).is_fainted() {
+            battle.battler_mut(position).volatiles.trap = Some(turns - 1);
+            let (numerator, denominator) = rules.trap_damage;
+            let amount = battle
+                .battler(position)
+                .fraction_of_max_hp(numerator, denominator);
+            battle.damage(position, amount, DamageCause::Trap);
+        }
+    }
+}
+
+/// Removes a battler's non-volatile status.
+pub fn cure(battle: &mut Battle, target: Position) {
+    if battle.battler_mut(target).status.take().is_some() {
+        battle.emit(BattleEvent::StatusCured { target });
+    }
+}
+
+/// Ends a volatile status on a battler.
+pub fn end_volatile(battle: &mut Battle, target: Position, volatile: Volatile) {
+    let volatiles = &mut battle.battler_mut(target).volatiles;
+    let ended = match volatile {
+        Volatile::Confusion => volatiles.confusion.take().is_some(),
+        Volatile::Trap => volatiles.trap.take().is_some(),
+        Volatile::Infatuation => volatiles.infatuation.take().is_some(),
+    };
+    if ended {
+        battle.emit(BattleEvent::VolatileEnded { target, volatile });
+    }
+}
+
+fn inflicted_volatile(battle: &mut Battle, target: Position, volatile: Volatile) -> bool {
+    battle.emit(BattleEvent::VolatileInflicted { target, volatile });
+    true
+}
+
+fn cant_move(battle: &mut Battle, user: Position, reason: CantMoveReason) -> bool {
+    battle.emit(BattleEvent::CantMove { user, reason });
+    false
+}
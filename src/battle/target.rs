@@ -0,0 +1,287 @@
+//! Move targeting
+//!
+//! Interprets the [`MoveTarget`](crate::model::moves::MoveTarget) named by `Move.target` to work out
+//! which battlers a move affects in single, double and triple battles.
+//! See [Bulbapedia](http://bulbapedia.bulbagarden.net/wiki/Target) for greater detail.
+
+use std::fmt;
+
+use rand::seq::SliceRandom;
+
+use super::{Battle, Position};
+use crate::model::{moves::Move, resource::name};
+
+/// The kinds of targets from the `move-target` endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Target {
+    /// The Pokémon that used a specific move, e.g. the one Counter reflects.
+    SpecificMove,
+    /// An opponent chosen by the user, which must be about to use a damaging move.
+    SelectedPokemonMeFirst,
+    Ally,
+    UsersField,
+    UserOrAlly,
+    OpponentsField,
+    User,
+    RandomOpponent,
+    AllOtherPokemon,
+    SelectedPokemon,
+    AllOpponents,
+    EntireField,
+    UserAndAllies,
+    AllPokemon,
+    AllAllies,
+    FaintingPokemon,
+}
+
+impl Target {
+    /// Looks up a target by its PokeAPI name, e.g. `all-opponents`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "specific-move" => Target::SpecificMove,
+            "selected-pokemon-me-first" => Target::SelectedPokemonMeFirst,
+            "ally" => Target::Ally,
+            "users-field" => Target::UsersField,
+            "user-or-ally" => Target::UserOrAlly,
+            "opponents-field" => Target::OpponentsField,
+            "user" => Target::User,
+            "random-opponent" => Target::RandomOpponent,
+            "all-other-pokemon" => Target::AllOtherPokemon,
+            "selected-pokemon" => Target::SelectedPokemon,
+            "all-opponents" => Target::AllOpponents,
+            "entire-field" => Target::EntireField,
+            "user-and-allies" => Target::UserAndAllies,
+            "all-pokemon" => Target::AllPokemon,
+            "all-allies" => Target::AllAllies,
+            "fainting-pokemon" => Target::FaintingPokemon,
+            _ => return None,
+        })
+    }
+
+    /// The target of a move, defaulting to a selected Pokémon when it is missing or unknown.
+    pub fn of(move_: &Move) -> Self {
+        name(&move_.target)
+            .and_then(Self::from_name)
+            .unwrap_or(Target::SelectedPokemon)
+    }
+
+    /// Whether the user chooses the target of the move.
+    pub fn is_chosen(self) -> bool {
+        matches!(
+            self,
+            Target::SelectedPokemonMeFirst
+                | Target::Ally
+                | Target::UserOrAlly
+                | Target::SelectedPokemon
+        )
+    }
+
+    /// Whether the move affects the field itself rather than any battler.
+    pub fn is_field(self) -> bool {
+        matches!(
+            self,
+            Target::UsersField
+                | Target::OpponentsField
+                | Target::EntireField
+                | Target::FaintingPokemon
+        )
+    }
+
+    /// Whether the move can hit several battlers at once and so has its damage reduced.
+    pub fn is_spread(self) -> bool {
+        matches!(
+            self,
+            Target::AllOtherPokemon | Target::AllOpponents | Target::AllPokemon
+        )
+    }
+}
+
+/// Why a target choice is illegal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TargetError {
+    /// The move needs a target to be chosen and there is more than one candidate.
+    Missing,
+    /// The move does not let its user choose a target.
+    Unexpected(Position),
+    /// No battler occupies the chosen position.
+    Empty(Position),
+    /// The chosen battler is too far away from the user.
+    NotAdjacent(Position),
+    /// The move cannot be aimed at the chosen battler, e.g. an opponent with a move for allies.
+    Invalid(Position),
+}
+
+impl fmt::Display for TargetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TargetError::Missing => write!(f, "the move needs a target"),
+            TargetError::Unexpected(position) => {
+                write!(f, "the move cannot be aimed, but {:?} was chosen", position)
+            }
+            TargetError::Empty(position) => write!(f, "no Pokémon is at {:?}", position),
+            TargetError::NotAdjacent(position) => {
+                write!(f, "the Pokémon at {:?} is not adjacent", position)
+            }
+            TargetError::Invalid(position) => {
+                write!(f, "the move cannot target the Pokémon at {:?}", position)
+            }
+        }
+    }
+}
+
+impl std::error::Error for TargetError {}
+
+/// Whether two positions are next to each other. Every position is adjacent in single and double battles;
+/// in triple battles the Pokémon on the left and right are out of reach of the far side of the field.
+pub fn is_adjacent(battle: &Battle, a: Position, b: Position) -> bool {
+    if a == b {
+        return false;
+    }
+    let (a_slot, b_slot) = (a.slot as i64, b.slot as i64);
+    if a.side == b.side {
+        return (a_slot - b_slot).abs() <= 1;
+    }
+    // Opposing sides number their slots from their own left, so slots face each other mirrored.
+    let width = battle.sides[b.side].len() as i64;
+    (a_slot - (width - 1 - b_slot)).abs() <= 1
+}
+
+/// Checks a target choice for a move without resolving it. A choice is only needed when there are
+/// several candidates: with none, the move fails when it is used.
+pub fn validate(
+    battle: &Battle,
+    user: Position,
+    move_: &Move,
+    choice: Option<Position>,
+) -> Result<(), TargetError> {
+    let target = Target::of(move_);
+    match (target.is_chosen(), choice) {
+        (true, Some(choice)) => check_choice(battle, user, target, choice),
+        (true, None) => match candidates(battle, user, target).len() {
+            0 | 1 => Ok(()),
+            _ => Err(TargetError::Missing),
+        },
+        (false, Some(choice)) => Err(TargetError::Unexpected(choice)),
+        (false, None) => Ok(()),
+    }
+}
+
+/// Works out the battlers a move affects when it is used, from a choice that was [validated](validate)
+/// before the turn. A move aimed at an opponent that has fainted since is redirected to another
+/// opponent. A move left without a target affects no one and fails unless it affects the field.
+pub fn resolve(
+    battle: &mut Battle,
+    user: Position,
+    move_: &Move,
+    choice: Option<Position>,
+) -> Vec<Position> {
+    let target = Target::of(move_);
+    let targets = match (target, choice) {
+        (Target::RandomOpponent | Target::SpecificMove, _) => {
+            let opponents = alive(battle, adjacent_opponents(battle, user));
+            opponents
+                .choose(battle.rng())
+                .copied()
+                .into_iter()
+                .collect()
+        }
+        (_, Some(choice)) if battle.battler(choice).is_fainted() && choice.side != user.side => {
+            let opponents = alive(battle, adjacent_opponents(battle, user));
+            opponents
+                .choose(battle.rng())
+                .copied()
+                .into_iter()
+                .collect()
+        }
+        (_, Some(choice)) => vec![choice],
+        // The only candidate when the target was validated, or one of those left.
+        (_, None) if target.is_chosen() => candidates(battle, user, target)
+            .choose(battle.rng())
+            .copied()
+            .into_iter()
+            .collect(),
+        _ => candidates(battle, user, target),
+    };
+    alive(battle, targets)
+}
+
+/// Every battler a move could affect, or could be aimed at when the target is chosen.
+fn candidates(battle: &Battle, user: Position, target: Target) -> Vec<Position> {
+    let positions = battle.positions();
+    let adjacent = |position: &Position| is_adjacent(battle, user, *position);
+    let ally = |position: &Position| position.side == user.side && *position != user;
+    let opponent = |position: &Position| position.side != user.side;
+    let candidates = match target {
+        Target::User => vec![user],
+        Target::Ally => positions
+            .into_iter()
+            .filter(|position| ally(position) && adjacent(position))
+            .collect(),
+        Target::UserOrAlly => positions
+            .into_iter()
+            .filter(|position| *position == user || (ally(position) && adjacent(position)))
+            .collect(),
+        Target::UserAndAllies => positions
+            .into_iter()
+            .filter(|position| position.side == user.side)
+            .collect(),
+        Target::AllAllies => positions.into_iter().filter(ally).collect(),
+        Target::SelectedPokemon | Target::AllOtherPokemon => {
+            positions.into_iter().filter(adjacent).collect()
+        }
+        Target::SelectedPokemonMeFirst | Target::AllOpponents => positions
+            .into_iter()
+            .filter(|position| opponent(position) && adjacent(position))
+            .collect(),
+        Target::RandomOpponent | Target::SpecificMove => adjacent_opponents(battle, user),
+        Target::AllPokemon => positions,
+        Target::UsersField
+        | Target::OpponentsField
+        | Target::EntireField
+        | Target::FaintingPokemon => Vec::new(),
+    };
+    alive(battle, candidates)
+}
+
+fn check_choice(
+    battle: &Battle,
+    user: Position,
+    target: Target,
+    choice: Position,
+) -> Result<(), TargetError> {
+    let occupied = battle
+        .sides
+        .get(choice.side)
+        .is_some_and(|side| choice.slot < side.len());
+    if !occupied {
+        return Err(TargetError::Empty(choice));
+    }
+    let allowed = match target {
+        Target::Ally => choice.side == user.side && choice != user,
+        Target::UserOrAlly => choice.side == user.side,
+        Target::SelectedPokemonMeFirst => choice.side != user.side,
+        _ => choice != user,
+    };
+    if !allowed {
+        return Err(TargetError::Invalid(choice));
+    }
+    if choice != user && !is_adjacent(battle, user, choice) {
+        return Err(TargetError::NotAdjacent(choice));
+    }
+    Ok(())
+}
+
+fn adjacent_opponents(battle: &Battle, user: Position) -> Vec<Position> {
+    battle
+        .positions()
+        .into_iter()
+        .filter(|position| position.side != user.side && is_adjacent(battle, user, *position))
+        .collect()
+}
+
+fn alive(battle: &Battle, positions: Vec<Position>) -> Vec<Position> {
+    positions
+        .into_iter()
+        .filter(|position| !battle.battler(*position).is_fainted())
+        .collect()
+}
@@ -0,0 +1,52 @@
+//! Downloads PokeAPI into an offline bundle: `sync <path> [endpoint...]`.
+//! Every endpoint is downloaded when none is given.
+
+use std::{fmt::Display, sync::Arc};
+
+use pokemon_game::{
+    bundle::{self, LocalSource},
+    source::{DataSource, Http},
+};
+
+#[tokio::main]
+async fn main() {
+    let mut args = std::env::args().skip(1);
+    let Some(path) = args.next() else {
+        eprintln!("usage: sync <path> [endpoint...]");
+        std::process::exit(2);
+    };
+    // Without revalidation, so that responses are not all kept in memory while downloading.
+    let from: Arc<dyn DataSource> = Arc::new(Http::new());
+    let mut endpoints: Vec<String> = args.collect();
+    if endpoints.is_empty() {
+        endpoints = match bundle::endpoints(from.as_ref()).await {
+            Ok(endpoints) => endpoints,
+            Err(why) => fail(why),
+        };
+    }
+
+    let source = match LocalSource::open(&path) {
+        Ok(source) => source,
+        Err(why) => fail(why),
+    };
+    let mut failed = 0;
+    for endpoint in endpoints {
+        let report = match source.sync_endpoint(from.clone(), &endpoint).await {
+            Ok(report) => report,
+            Err(why) => fail(format!("{}: {}", endpoint, why)),
+        };
+        println!("{}: {} resources", endpoint, report.stored);
+        for (path, why) in &report.failed {
+            eprintln!("sync: {}: {}", path, why);
+        }
+        failed += report.failed.len();
+    }
+    if failed > 0 {
+        fail(format!("{} resources could not be downloaded", failed));
+    }
+}
+
+fn fail(why: impl Display) -> ! {
+    eprintln!("sync: {}", why);
+    std::process::exit(1);
+}
@@ -0,0 +1,401 @@
+//! Breeding
+//!
+//! Works out whether two Pokémon can breed from the `egg_groups` and `gender_rate` of their
+//! [`PokemonSpecies`], and what hatches from their eggs.
+//! See [Bulbapedia](http://bulbapedia.bulbagarden.net/wiki/Pokémon_breeding) for greater detail.
+
+use std::fmt;
+
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{
+    encounter, evolve, experience,
+    generation::Generation,
+    model::{
+        evolution::EvolutionChain,
+        pokemon::{GrowthRate, Pokemon, PokemonSpecies},
+        resource::name,
+    },
+    owned::{Gender, OwnedPokemon, BASE_FRIENDSHIP},
+    stats::Stats,
+};
+
+/// The egg group of Pokémon that cannot breed.
+pub const NO_EGGS: &str = "no-eggs";
+/// The egg group of Ditto, which breeds with any Pokémon that can breed except another Ditto.
+pub const DITTO: &str = "ditto";
+
+/// The friendship of a Pokémon hatched up to Generation VII.
+pub const HATCHED_FRIENDSHIP: i64 = 120;
+
+/// Items that make their holder pass down one IV for certain from Generation IV,
+/// by the PokeAPI name of the stat.
+pub const POWER_ITEMS: [(&str, &str); 6] = [
+    ("power-weight", "hp"),
+    ("power-bracer", "attack"),
+    ("power-belt", "defense"),
+    ("power-lens", "special-attack"),
+    ("power-band", "special-defense"),
+    ("power-anklet", "speed"),
+];
+
+/// A Pokémon left at the Day Care along with its species.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Parent<'a> {
+    pub pokemon: &'a OwnedPokemon,
+    pub species: &'a PokemonSpecies,
+}
+
+impl Parent<'_> {
+    fn egg_groups(&self) -> impl Iterator<Item = &str> {
+        self.species.egg_groups.iter().flatten().filter_map(name)
+    }
+
+    fn in_egg_group(&self, group: &str) -> bool {
+        self.egg_groups().any(|name| name == group)
+    }
+
+    fn is_ditto(&self) -> bool {
+        self.in_egg_group(DITTO)
+    }
+
+    fn holds(&self, item: &str) -> bool {
+        self.pokemon.held_item.as_deref() == Some(item)
+    }
+}
+
+/// Why two Pokémon cannot breed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Incompatibility {
+    /// One of them belongs to the No Eggs Discovered group.
+    Undiscovered,
+    BothDitto,
+    /// A genderless Pokémon can only breed with Ditto.
+    Genderless,
+    /// Neither is Ditto and they are not male and female.
+    SameGender,
+    /// Neither is Ditto and they share no egg group.
+    NoSharedEggGroup,
+}
+
+impl fmt::Display for Incompatibility {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Incompatibility::Undiscovered => write!(f, "one of the Pokémon cannot breed"),
+            Incompatibility::BothDitto => write!(f, "two Ditto cannot breed together"),
+            Incompatibility::Genderless => {
+                write!(f, "a genderless Pokémon can only breed with Ditto")
+            }
+            Incompatibility::SameGender => write!(f, "the Pokémon are not male and female"),
+            Incompatibility::NoSharedEggGroup => write!(f, "the Pokémon share no egg group"),
+        }
+    }
+}
+
+impl std::error::Error for Incompatibility {}
+
+/// Checks whether two Pokémon can breed.
+pub fn compatibility(a: &Parent, b: &Parent) -> Result<(), Incompatibility> {
+    if a.in_egg_group(NO_EGGS) || b.in_egg_group(NO_EGGS) {
+        return Err(Incompatibility::Undiscovered);
+    }
+    match (a.is_ditto(), b.is_ditto()) {
+        (true, true) => return Err(Incompatibility::BothDitto),
+        (true, false) | (false, true) => return Ok(()),
+        (false, false) => {}
+    }
+    let (a_gender, b_gender) = (a.pokemon.gender, b.pokemon.gender);
+    if a_gender == Gender::Genderless || b_gender == Gender::Genderless {
+        return Err(Incompatibility::Genderless);
+    }
+    if !a_gender.is_opposite(b_gender) {
+        return Err(Incompatibility::SameGender);
+    }
+    if !a.egg_groups().any(|group| b.in_egg_group(group)) {
+        return Err(Incompatibility::NoSharedEggGroup);
+    }
+    Ok(())
+}
+
+/// The parent whose species the offspring takes after: the female, or the one that is not Ditto.
+pub fn mother<'a, 'b>(a: &'b Parent<'a>, b: &'b Parent<'a>) -> &'b Parent<'a> {
+    match (a.is_ditto(), b.is_ditto()) {
+        (true, _) => b,
+        (_, true) => a,
+        _ if b.pokemon.gender == Gender::Female => b,
+        _ => a,
+    }
+}
+
+/// The other parent, the one [`mother`] does not pick: the male, or Ditto.
+pub fn father<'a, 'b>(a: &'b Parent<'a>, b: &'b Parent<'a>) -> &'b Parent<'a> {
+    match (a.is_ditto(), b.is_ditto()) {
+        (true, _) => a,
+        (_, true) => b,
+        _ if b.pokemon.gender == Gender::Female => a,
+        _ => b,
+    }
+}
+
+/// The species an egg hatches into: the first stage of the mother's evolution chain.
+///
+/// A baby species that needs an incense, the `baby_trigger_item` of the chain,
+/// only hatches when one of the parents holds it; otherwise the stage after it does.
+pub fn offspring_species<'a>(
+    chain: &'a EvolutionChain,
+    mother: &Parent,
+    father: &Parent,
+) -> Option<&'a str> {
+    let root = chain.chain.as_ref()?;
+    let species = mother.species.name.as_deref()?;
+    if !root.is_baby.unwrap_or_default() {
+        return name(&root.species);
+    }
+    let incense = name(&chain.baby_trigger_item);
+    let incense_held = incense.is_none_or(|incense| mother.holds(incense) || father.holds(incense));
+    if incense_held || name(&root.species) == Some(species) {
+        return name(&root.species);
+    }
+    root.evolves_to
+        .iter()
+        .flatten()
+        .find(|link| evolve::find_in(link, species).is_some())
+        .and_then(|link| name(&link.species))
+}
+
+/// The IVs of an egg. Some IVs are passed down from random parents, three of them or five when
+/// a parent holds a Destiny Knot from Generation VI, and from Generation IV a parent holding a
+/// power item passes down its stat. The rest are random.
+pub fn inherit_ivs(generation: Generation, a: &Parent, b: &Parent, rng: &mut impl Rng) -> Stats {
+    let destiny_knot = a.holds("destiny-knot") || b.holds("destiny-knot");
+    let count = if destiny_knot && generation >= Generation::VI {
+        5
+    } else {
+        3
+    };
+    let mut iv = encounter::roll_ivs(rng);
+    let mut remaining: Vec<&str> = Stats::NAMES.to_vec();
+    let inherit = |stat: &str, parent: &Parent, iv: &mut Stats| {
+        if let (Some(value), Some(inherited)) = (iv.get_mut(stat), parent.pokemon.iv.get(stat)) {
+            *value = inherited;
+        }
+    };
+
+    let powered: Vec<(&Parent, &str)> = [a, b]
+        .into_iter()
+        .filter(|_| generation >= Generation::IV)
+        .filter_map(|parent| {
+            POWER_ITEMS
+                .iter()
+                .find(|(item, _)| parent.holds(item))
+                .map(|(_, stat)| (parent, *stat))
+        })
+        .collect();
+    if let Some(&(parent, stat)) = powered.choose(rng) {
+        inherit(stat, parent, &mut iv);
+        remaining.retain(|name| *name != stat);
+    }
+
+    remaining.shuffle(rng);
+    let already = 6 - remaining.len();
+    for stat in remaining.into_iter().take(count - already) {
+        let parent = if rng.gen_bool(0.5) { a } else { b };
+        inherit(stat, parent, &mut iv);
+    }
+    iv
+}
+
+/// The nature of an egg: that of a parent holding an Everstone, or else a random one.
+/// In Generation III only the mother or Ditto passes its nature down, and only half of the time;
+/// from Generation IV either parent does, half of the time until Generation V makes it certain.
+/// See [Bulbapedia](http://bulbapedia.bulbagarden.net/wiki/Everstone) for greater detail.
+pub fn inherit_nature(
+    generation: Generation,
+    a: &Parent,
+    b: &Parent,
+    rng: &mut impl Rng,
+) -> Option<String> {
+    let mother = mother(a, b);
+    let holders: Vec<&Parent> = [a, b]
+        .into_iter()
+        .filter(|_| generation >= Generation::III)
+        .filter(|parent| {
+            generation >= Generation::IV || std::ptr::eq(*parent, mother) || parent.is_ditto()
+        })
+        .filter(|parent| parent.holds("everstone") && parent.pokemon.nature.is_some())
+        .collect();
+    let holder = holders
+        .choose(rng)
+        .filter(|_| generation >= Generation::V || rng.gen_bool(0.5));
+    match holder {
+        Some(parent) => parent.pokemon.nature.clone(),
+        None => encounter::NATURES
+            .choose(rng)
+            .map(|nature| nature.to_string()),
+    }
+}
+
+/// The egg moves of the offspring in a version group, e.g. `ultra-sun-ultra-moon`, that a parent knows.
+/// Only the [`father`] passes egg moves down until Generation VI, when the mother can as well.
+/// See [Bulbapedia](http://bulbapedia.bulbagarden.net/wiki/Egg_move) for greater detail.
+pub fn egg_moves(
+    generation: Generation,
+    offspring: &Pokemon,
+    a: &Parent,
+    b: &Parent,
+    version_group: &str,
+) -> Vec<String> {
+    let parents = if generation >= Generation::VI {
+        vec![a, b]
+    } else {
+        vec![father(a, b)]
+    };
+    offspring
+        .moves
+        .iter()
+        .flatten()
+        .filter(|move_| {
+            move_.version_group_details.iter().flatten().any(|detail| {
+                name(&detail.version_group) == Some(version_group)
+                    && name(&detail.move_learn_method) == Some("egg")
+            })
+        })
+        .filter_map(|move_| name(&move_.move_))
+        .filter(|move_| {
+            parents
+                .iter()
+                .any(|parent| parent.pokemon.moves.iter().any(|known| known == move_))
+        })
+        .map(String::from)
+        .collect()
+}
+
+/// The steps to walk for an egg to hatch, from the `hatch_counter` of its species.
+/// An egg cycle lasts 256 steps up to Generation III, 255 up to Generation VII and 257 from Generation VIII;
+/// a party Pokémon with Flame Body or Magma Armor halves the cycles from Generation V.
+pub fn steps_to_hatch(generation: Generation, species: &PokemonSpecies, flame_body: bool) -> i64 {
+    let cycle = match generation {
+        Generation::I | Generation::II | Generation::III => 256,
+        generation if generation <= Generation::VII => 255,
+        _ => 257,
+    };
+    let cycles = species.hatch_counter.unwrap_or_default() + 1;
+    let cycles = if flame_body && generation >= Generation::V {
+        (cycles + 1) / 2
+    } else {
+        cycles
+    };
+    cycle * cycles
+}
+
+/// The species an egg hatches into, as fetched by the caller after [`offspring_species`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Offspring<'a> {
+    pub pokemon: &'a Pokemon,
+    pub species: &'a PokemonSpecies,
+    /// The growth rate of the species, to give the hatchling the experience of its level.
+    pub growth_rate: Option<&'a GrowthRate>,
+}
+
+/// An egg and the steps left before it hatches.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Egg {
+    /// The Pokémon that will hatch.
+    pub pokemon: OwnedPokemon,
+    pub steps: i64,
+}
+
+/// Produces an egg from two compatible parents. The hatchling is level 5 up to Generation III
+/// and level 1 after, may take its ability after the mother from Generation V and knows the
+/// parents' egg moves after its own level-up moves.
+#[allow(clippy::too_many_arguments)]
+pub fn breed(
+    generation: Generation,
+    a: &Parent,
+    b: &Parent,
+    offspring: &Offspring,
+    version_group: &str,
+    trainer_id: u32,
+    flame_body: bool,
+    rng: &mut impl Rng,
+) -> Result<Egg, Incompatibility> {
+    compatibility(a, b)?;
+    let mother = mother(a, b);
+    let level = if generation <= Generation::III { 5 } else { 1 };
+    let mut egg = OwnedPokemon::new(offspring.pokemon.name.clone().unwrap_or_default(), level);
+    egg.experience = offspring
+        .growth_rate
+        .and_then(|growth_rate| experience::experience_for_level(growth_rate, level))
+        .unwrap_or_default();
+    egg.friendship = if generation >= Generation::VIII {
+        offspring.species.base_happiness.unwrap_or(BASE_FRIENDSHIP)
+    } else {
+        HATCHED_FRIENDSHIP
+    };
+    egg.gender = encounter::roll_gender(offspring.species.gender_rate.unwrap_or(-1), rng);
+    egg.iv = inherit_ivs(generation, a, b, rng);
+    egg.nature = inherit_nature(generation, a, b, rng);
+    egg.personality = rng.gen();
+    egg.shiny = encounter::is_shiny(generation, trainer_id, egg.personality);
+    egg.ability = inherit_ability(generation, mother, offspring.pokemon, rng);
+
+    let mut moves = encounter::level_up_moves(offspring.pokemon, level, version_group);
+    for move_ in egg_moves(generation, offspring.pokemon, a, b, version_group) {
+        if !moves.contains(&move_) {
+            moves.push(move_);
+        }
+    }
+    let skip = moves.len().saturating_sub(4);
+    egg.moves = moves.into_iter().skip(skip).collect();
+
+    Ok(Egg {
+        pokemon: egg,
+        steps: steps_to_hatch(generation, offspring.species, flame_body),
+    })
+}
+
+impl Egg {
+    /// Walks some steps. Returns whether the egg has hatched.
+    pub fn walk(&mut self, steps: i64) -> bool {
+        self.steps = (self.steps - steps).max(0);
+        self.steps == 0
+    }
+}
+
+/// The ability of the hatchling. Abilities are not passed down before Generation V, and in
+/// Generation V only a female passes down her hidden ability, three times out of five. From
+/// Generation VI the mother passes down a hidden ability three times out of five and any other
+/// four times out of five. Otherwise the hatchling has a random ability that is not hidden.
+/// See [Bulbapedia](http://bulbapedia.bulbagarden.net/wiki/Ability#Inheritance) for greater detail.
+fn inherit_ability(
+    generation: Generation,
+    mother: &Parent,
+    offspring: &Pokemon,
+    rng: &mut impl Rng,
+) -> Option<String> {
+    let abilities: Vec<_> = offspring.abilities.iter().flatten().collect();
+    let mother_data = abilities.iter().find(|ability| {
+        mother.pokemon.ability.is_some()
+            && name(&ability.ability) == mother.pokemon.ability.as_deref()
+    });
+    let inherited = mother_data
+        .filter(|ability| {
+            let hidden = ability.is_hidden == Some(true);
+            match generation {
+                Generation::I | Generation::II | Generation::III | Generation::IV => false,
+                Generation::V if mother.pokemon.gender != Gender::Female => false,
+                Generation::V => hidden && rng.gen_range(0..5) < 3,
+                _ if hidden => rng.gen_range(0..5) < 3,
+                _ => rng.gen_range(0..5) < 4,
+            }
+        })
+        .and_then(|ability| name(&ability.ability));
+    let not_hidden: Vec<&str> = abilities
+        .iter()
+        .filter(|ability| ability.is_hidden != Some(true))
+        .filter_map(|ability| name(&ability.ability))
+        .collect();
+    inherited
+        .or_else(|| not_hidden.choose(rng).copied())
+        .map(String::from)
+}
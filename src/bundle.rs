@@ -0,0 +1,247 @@
+//! Offline data bundle
+//!
+//! Crawls PokeAPI through its list endpoints into an SQLite file, and serves the endpoint functions from
+//! that file once it is installed with [`set_source`](crate::source::set_source), so the crate works
+//! without a network.
+
+use std::{
+    collections::BTreeMap,
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+use rusqlite::{params, Connection, OptionalExtension};
+use tokio::task::JoinSet;
+
+use crate::{error::Error, model::resource::ApiResource, source::DataSource};
+
+/// How many requests a sync has in flight at once.
+pub const CONCURRENT_REQUESTS: usize = 8;
+/// How many downloaded resources a sync keeps in memory before writing them to the bundle.
+pub const BATCH_SIZE: usize = 100;
+
+const SCHEMA: &str = "CREATE TABLE IF NOT EXISTS resource (
+        endpoint TEXT NOT NULL,
+        id INTEGER NOT NULL,
+        name TEXT,
+        json TEXT NOT NULL,
+        PRIMARY KEY (endpoint, id)
+    );
+    CREATE INDEX IF NOT EXISTS resource_name ON resource (endpoint, name);";
+
+/// A bundle of PokeAPI resources in an SQLite file, stored as the JSON PokeAPI serves.
+#[derive(Debug)]
+pub struct LocalSource {
+    connection: Mutex<Connection>,
+}
+
+/// The columns a resource is looked up by.
+#[derive(serde::Deserialize)]
+struct Key {
+    id: i64,
+    name: Option<String>,
+}
+
+/// A page of a list endpoint, named or not.
+#[derive(serde::Deserialize)]
+struct Page {
+    next: Option<String>,
+    results: Vec<ApiResource>,
+}
+
+impl LocalSource {
+    /// Opens a bundle, creating an empty one if the file does not exist.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::with_connection(Connection::open(path)?)
+    }
+
+    /// Opens an empty bundle that only lives in memory.
+    pub fn in_memory() -> Result<Self, Error> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    fn with_connection(connection: Connection) -> Result<Self, Error> {
+        connection.execute_batch(SCHEMA)?;
+        Ok(Self {
+            connection: Mutex::new(connection),
+        })
+    }
+
+    /// The JSON of a resource by its path, e.g. `pokemon/25` or `pokemon/pikachu`.
+    /// Only resources reached through the list endpoints are bundled, so sub-resources such as
+    /// `pokemon/25/encounters` are never found.
+    pub fn get(&self, path: &str) -> Result<String, Error> {
+        let not_found = || Error::NotFound(path.to_string());
+        let (endpoint, key) = path
+            .trim_matches('/')
+            .split_once('/')
+            .ok_or_else(not_found)?;
+        if key.contains('/') {
+            return Err(not_found());
+        }
+        let connection = self.connection.lock().unwrap();
+        let json = match key.parse::<i64>() {
+            Ok(id) => connection
+                .query_row(
+                    "SELECT json FROM resource WHERE endpoint = ?1 AND id = ?2",
+                    params![endpoint, id],
+                    |row| row.get(0),
+                )
+                .optional()?,
+            Err(_) => connection
+                .query_row(
+                    "SELECT json FROM resource WHERE endpoint = ?1 AND name = ?2",
+                    params![endpoint, key],
+                    |row| row.get(0),
+                )
+                .optional()?,
+        };
+        json.ok_or_else(not_found)
+    }
+
+    /// How many resources of each endpoint the bundle holds.
+    pub fn counts(&self) -> Result<BTreeMap<String, usize>, Error> {
+        let connection = self.connection.lock().unwrap();
+        let mut statement =
+            connection.prepare("SELECT endpoint, COUNT(*) FROM resource GROUP BY endpoint")?;
+        let counts = statement.query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)? as usize))
+        })?;
+        Ok(counts.collect::<Result<_, _>>()?)
+    }
+
+    /// Downloads every endpoint listed at the root of a source, usually [`Http`](crate::source::Http).
+    pub async fn sync(&self, from: Arc<dyn DataSource>) -> Result<SyncReport, Error> {
+        let mut report = SyncReport::default();
+        for endpoint in endpoints(from.as_ref()).await? {
+            let synced = self.sync_endpoint(from.clone(), &endpoint).await?;
+            report.stored += synced.stored;
+            report.failed.extend(synced.failed);
+        }
+        Ok(report)
+    }
+
+    /// Downloads every resource of an endpoint, e.g. `pokemon`, replacing those already bundled.
+    ///
+    /// Resources are written [`BATCH_SIZE`] at a time as they arrive, in one transaction for the
+    /// endpoint. A resource that cannot be downloaded or decoded is left out and reported, while
+    /// failing to list the endpoint or to write the bundle leaves the bundle as it was.
+    pub async fn sync_endpoint(
+        &self,
+        from: Arc<dyn DataSource>,
+        endpoint: &str,
+    ) -> Result<SyncReport, Error> {
+        let mut paths = Vec::new();
+        let mut next = Some(format!("{}/?limit=1000", endpoint));
+        while let Some(page) = next {
+            let page: Page = serde_json::from_str(&from.fetch(&page).await?)?;
+            for resource in page.results {
+                paths.push(relative_path(resource.url.as_deref().unwrap_or_default()));
+            }
+            next = page.next.as_deref().map(relative_path);
+        }
+
+        self.connection.lock().unwrap().execute_batch("BEGIN")?;
+        let mut report = SyncReport::default();
+        let downloaded = self.download(from, endpoint, paths, &mut report).await;
+        let connection = self.connection.lock().unwrap();
+        match downloaded {
+            Ok(()) => connection.execute_batch("COMMIT")?,
+            Err(error) => {
+                connection.execute_batch("ROLLBACK")?;
+                return Err(error);
+            }
+        }
+        Ok(report)
+    }
+
+    /// Replaces the resources of an endpoint, fetching them [`CONCURRENT_REQUESTS`] at a time
+    /// and writing them in batches.
+    async fn download(
+        &self,
+        from: Arc<dyn DataSource>,
+        endpoint: &str,
+        paths: Vec<String>,
+        report: &mut SyncReport,
+    ) -> Result<(), Error> {
+        self.connection
+            .lock()
+            .unwrap()
+            .execute("DELETE FROM resource WHERE endpoint = ?1", [endpoint])?;
+        let mut paths = paths.into_iter();
+        let mut requests = JoinSet::new();
+        let mut batch = Vec::with_capacity(BATCH_SIZE);
+        loop {
+            while requests.len() < CONCURRENT_REQUESTS {
+                let Some(path) = paths.next() else {
+                    break;
+                };
+                let from = from.clone();
+                requests.spawn(async move {
+                    let json = from.fetch(&path).await;
+                    (path, json)
+                });
+            }
+            let Some(joined) = requests.join_next().await else {
+                break;
+            };
+            let (path, json) = joined.expect("a request panicked");
+            match json.and_then(|json| Ok((serde_json::from_str::<Key>(&json)?, json))) {
+                Ok(resource) => batch.push(resource),
+                Err(error) => report.failed.push((path, error)),
+            }
+            if batch.len() == BATCH_SIZE {
+                report.stored += self.insert(endpoint, &mut batch)?;
+            }
+        }
+        report.stored += self.insert(endpoint, &mut batch)?;
+        Ok(())
+    }
+
+    /// Writes a batch of resources and empties it. Returns how many were written.
+    fn insert(&self, endpoint: &str, batch: &mut Vec<(Key, String)>) -> Result<usize, Error> {
+        let connection = self.connection.lock().unwrap();
+        let mut statement = connection.prepare_cached(
+            "INSERT OR REPLACE INTO resource (endpoint, id, name, json) VALUES (?1, ?2, ?3, ?4)",
+        )?;
+        for (key, json) in batch.iter() {
+            statement.execute(params![endpoint, key.id, key.name, json])?;
+        }
+        let written = batch.len();
+        batch.clear();
+        Ok(written)
+    }
+}
+
+/// What a sync downloaded.
+#[derive(Debug, Default)]
+pub struct SyncReport {
+    /// How many resources were stored.
+    pub stored: usize,
+    /// The path of each resource that could not be downloaded or decoded, with the reason.
+    pub failed: Vec<(String, Error)>,
+}
+
+#[async_trait]
+impl DataSource for LocalSource {
+    async fn fetch(&self, path: &str) -> Result<String, Error> {
+        self.get(path)
+    }
+}
+
+/// The names of every endpoint listed at the root of a source, e.g. `pokemon`.
+pub async fn endpoints(from: &dyn DataSource) -> Result<Vec<String>, Error> {
+    let root: BTreeMap<String, String> = serde_json::from_str(&from.fetch("").await?)?;
+    Ok(root.into_keys().collect())
+}
+
+/// The path of a URL listed by PokeAPI relative to its root, e.g. `pokemon/25/` for
+/// `https://pokeapi.co/api/v2/pokemon/25/`.
+fn relative_path(url: &str) -> String {
+    const ROOT: &str = "/api/v2/";
+    match url.find(ROOT) {
+        Some(start) => url[start + ROOT.len()..].to_string(),
+        None => url.to_string(),
+    }
+}
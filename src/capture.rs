@@ -0,0 +1,267 @@
+//! Catching wild Pokémon
+//!
+//! Implements the capture formula of Generations III to IX from the `capture_rate` of a
+//! [`PokemonSpecies`]. Earlier generations are treated like Generation III.
+//! See [Bulbapedia](http://bulbapedia.bulbagarden.net/wiki/Catch_rate) for greater detail.
+
+use rand::Rng;
+
+use crate::{
+    battle::{status::Status, Battler},
+    generation::Generation,
+    model::pokemon::PokemonSpecies,
+    names::PokemonTypeName,
+};
+
+/// An attempt to catch a Pokémon by throwing a ball at it.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Attempt<'a> {
+    pub generation: Generation,
+    /// The PokeAPI name of the ball thrown, e.g. `ultra-ball`.
+    pub ball: &'a str,
+    /// The `capture_rate` of the species, up to 255.
+    pub capture_rate: i64,
+    pub max_hp: i64,
+    pub hp: i64,
+    pub status: Option<Status>,
+    pub level: i64,
+    /// The types of the Pokémon.
+    pub types: Vec<PokemonTypeName>,
+    /// The base Speed of the Pokémon, for the Fast Ball.
+    pub base_speed: i64,
+    /// Whether the Pokémon is an Ultra Beast, for the Beast Ball.
+    pub ultra_beast: bool,
+    /// The level of the trainer's Pokémon in battle, for the Level Ball.
+    pub user_level: i64,
+    /// The turn of the battle, starting from 1, for the Quick Ball and Timer Ball.
+    pub turn: i64,
+    /// Whether it is night or the battle takes place in a cave, for the Dusk Ball.
+    pub dark: bool,
+    /// Whether the Pokémon was found while surfing or diving, for the Dive Ball.
+    pub underwater: bool,
+    /// Whether the Pokémon was hooked with a fishing rod, for the Dive Ball and Lure Ball.
+    pub fishing: bool,
+    /// Whether the species has already been caught, for the Repeat Ball.
+    pub caught_before: bool,
+    /// How many species the trainer has caught, which makes critical captures more likely.
+    pub species_caught: i64,
+    /// Whether the trainer has the Catching Charm, which doubles the chance of a critical capture.
+    pub catching_charm: bool,
+}
+
+impl<'a> Attempt<'a> {
+    /// An attempt at a wild battler of a species, with the turn set to the first.
+    /// Conditions for specific balls and critical captures are left for the caller to fill in.
+    pub fn new(
+        generation: Generation,
+        ball: &'a str,
+        species: &PokemonSpecies,
+        target: &Battler,
+    ) -> Self {
+        Self {
+            generation,
+            ball,
+            capture_rate: species.capture_rate.unwrap_or_default(),
+            max_hp: target.max_hp(),
+            hp: target.hp,
+            status: target.status,
+            level: target.level,
+            types: target.types.clone(),
+            turn: 1,
+            ..Self::default()
+        }
+    }
+
+    fn has_type(&self, type_: PokemonTypeName) -> bool {
+        self.types.contains(&type_)
+    }
+}
+
+/// The outcome of throwing a ball.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Capture {
+    pub caught: bool,
+    /// How many times the ball shook, up to 3, or 1 for a critical capture.
+    pub shakes: i64,
+    pub critical: bool,
+}
+
+/// The multiplier a ball applies to the catch rate. Balls without any effect on it,
+/// such as the Poké Ball, Premier Ball or Heal Ball, and unknown balls multiply it by 1.
+/// The Master Ball is handled by [`capture`] and multiplies it by 255 here.
+pub fn ball_modifier(attempt: &Attempt) -> f64 {
+    let generation = attempt.generation;
+    let condition = |met: bool, modifier: f64| if met { modifier } else { 1.0 };
+    match attempt.ball {
+        "master-ball" => 255.0,
+        "great-ball" | "safari-ball" | "sport-ball" => 1.5,
+        "ultra-ball" => 2.0,
+        "net-ball" => condition(
+            attempt.has_type(PokemonTypeName::Water) || attempt.has_type(PokemonTypeName::Bug),
+            if generation >= Generation::VII {
+                3.5
+            } else {
+                3.0
+            },
+        ),
+        "dive-ball" => condition(
+            attempt.underwater || (attempt.fishing && generation >= Generation::IV),
+            3.5,
+        ),
+        "nest-ball" if generation >= Generation::V => ((41 - attempt.level) as f64 / 10.0).max(1.0),
+        "nest-ball" => ((40 - attempt.level) as f64 / 10.0).max(1.0),
+        "repeat-ball" => condition(
+            attempt.caught_before,
+            if generation >= Generation::VII {
+                3.5
+            } else {
+                3.0
+            },
+        ),
+        "timer-ball" if generation >= Generation::V => {
+            (1.0 + (attempt.turn - 1) as f64 * 1229.0 / 4096.0).min(4.0)
+        }
+        "timer-ball" => ((attempt.turn - 1 + 10) as f64 / 10.0).min(4.0),
+        "quick-ball" => condition(
+            attempt.turn <= 1,
+            if generation >= Generation::V {
+                5.0
+            } else {
+                4.0
+            },
+        ),
+        "dusk-ball" => condition(
+            attempt.dark,
+            if generation >= Generation::VII {
+                3.0
+            } else {
+                3.5
+            },
+        ),
+        "fast-ball" => condition(attempt.base_speed >= 100, 4.0),
+        "lure-ball" => condition(
+            attempt.fishing,
+            if generation >= Generation::VII {
+                5.0
+            } else {
+                3.0
+            },
+        ),
+        "level-ball" => match attempt.user_level {
+            level if level > 4 * attempt.level => 8.0,
+            level if level > 2 * attempt.level => 4.0,
+            level if level > attempt.level => 2.0,
+            _ => 1.0,
+        },
+        "beast-ball" if attempt.ultra_beast => 5.0,
+        "beast-ball" => 0.1,
+        _ => condition(attempt.ultra_beast && generation >= Generation::VII, 0.1),
+    }
+}
+
+/// The multiplier a status condition applies to the catch rate.
+pub fn status_modifier(generation: Generation, status: Option<Status>) -> f64 {
+    match status {
+        Some(Status::Sleep { .. } | Status::Freeze) if generation >= Generation::V => 2.5,
+        Some(Status::Sleep { .. } | Status::Freeze) => 2.0,
+        Some(_) => 1.5,
+        None => 1.0,
+    }
+}
+
+/// The modified catch rate, which catches the Pokémon outright from 255.
+pub fn modified_catch_rate(attempt: &Attempt) -> f64 {
+    let max_hp = attempt.max_hp.max(1) as f64;
+    let hp = attempt.hp.clamp(1, attempt.max_hp.max(1)) as f64;
+    let rate = attempt.capture_rate.clamp(0, 255) as f64;
+    let hp_factor = (3.0 * max_hp - 2.0 * hp) / (3.0 * max_hp);
+    let modified = if attempt.generation >= Generation::V {
+        (hp_factor * 4096.0 * rate * ball_modifier(attempt)).floor() / 4096.0
+    } else {
+        (hp_factor * (rate * ball_modifier(attempt)).floor()).floor()
+    };
+    (modified * status_modifier(attempt.generation, attempt.status)).floor()
+}
+
+/// The threshold a random number from 0 to 65535 must stay below to pass one shake check.
+pub fn shake_probability(generation: Generation, modified_catch_rate: f64) -> i64 {
+    if modified_catch_rate <= 0.0 {
+        return 0;
+    }
+    if generation >= Generation::V {
+        // Generation V makes 3 shake checks and later generations make 4, so that both catch
+        // with a chance of about (a / 255)^¾.
+        let exponent = if generation == Generation::V {
+            0.25
+        } else {
+            0.1875
+        };
+        (65536.0 / (255.0 / modified_catch_rate).powf(exponent)).floor() as i64
+    } else {
+        let root = (16711680.0 / modified_catch_rate)
+            .floor()
+            .sqrt()
+            .floor()
+            .sqrt()
+            .floor();
+        (1048560.0 / root).floor() as i64
+    }
+}
+
+/// The threshold a random number from 0 to 255 must stay below for a critical capture,
+/// which only needs to pass one shake check. Critical captures exist from Generation V.
+pub fn critical_capture_probability(attempt: &Attempt, modified_catch_rate: f64) -> i64 {
+    if attempt.generation < Generation::V {
+        return 0;
+    }
+    let multiplier = match attempt.species_caught {
+        caught if caught > 600 => 2.5,
+        caught if caught > 450 => 2.0,
+        caught if caught > 300 => 1.5,
+        caught if caught > 150 => 1.0,
+        caught if caught > 30 => 0.5,
+        _ => 0.0,
+    };
+    let charm = if attempt.catching_charm && attempt.generation >= Generation::VI {
+        2.0
+    } else {
+        1.0
+    };
+    (modified_catch_rate.min(255.0) * multiplier * charm / 6.0).floor() as i64
+}
+
+/// Throws a ball. The same random number generator state always gives the same outcome,
+/// so seeding it makes catches deterministic.
+pub fn capture(attempt: &Attempt, rng: &mut impl Rng) -> Capture {
+    if attempt.ball == "master-ball" {
+        return Capture {
+            caught: true,
+            shakes: 3,
+            critical: false,
+        };
+    }
+    let rate = modified_catch_rate(attempt);
+    let critical = rng.gen_range(0..256) < critical_capture_probability(attempt, rate);
+    if rate >= 255.0 {
+        return Capture {
+            caught: true,
+            shakes: if critical { 1 } else { 3 },
+            critical,
+        };
+    }
+
+    let checks = match (critical, attempt.generation) {
+        (true, _) => 1,
+        (false, Generation::V) => 3,
+        (false, _) => 4,
+    };
+    let probability = shake_probability(attempt.generation, rate);
+    let passed = (0..checks)
+        .take_while(|_| rng.gen_range(0..65536) < probability)
+        .count() as i64;
+    Capture {
+        caught: passed == checks,
+        shakes: passed.min(if critical { 1 } else { 3 }),
+        critical,
+    }
+}
@@ -0,0 +1,143 @@
+//! Characteristics
+//!
+//! A [`Characteristic`] tells which IV of a Pokémon is highest and what that IV is modulo 5.
+//! See [Bulbapedia](http://bulbapedia.bulbagarden.net/wiki/Characteristic) for greater detail.
+
+use crate::{
+    model::{pokemon::Characteristic, resource::name},
+    owned::OwnedPokemon,
+    stats::{PossibleIvs, Stats},
+};
+
+/// The order in which stats are checked when several IVs tie for the highest,
+/// starting from the personality value modulo 6.
+pub const TIE_BREAK_ORDER: [&str; 6] = [
+    "hp",
+    "attack",
+    "defense",
+    "speed",
+    "special-attack",
+    "special-defense",
+];
+
+/// What selects a characteristic: the PokeAPI name of the stat with the highest IV
+/// and the remainder of that IV divided by 5.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Key {
+    pub highest_stat: &'static str,
+    pub gene_modulo: i64,
+}
+
+impl Key {
+    /// Works out the key from IVs, breaking ties with the personality value.
+    pub fn of(iv: &Stats, personality: u32) -> Self {
+        let value = |stat: &str| iv.get(stat).unwrap_or_default();
+        let highest = TIE_BREAK_ORDER
+            .iter()
+            .map(|stat| value(stat))
+            .max()
+            .unwrap_or_default();
+        let highest_stat = tie_break_order(personality)
+            .find(|stat| value(stat) == highest)
+            .unwrap_or(TIE_BREAK_ORDER[0]);
+        Self {
+            highest_stat,
+            gene_modulo: highest % 5,
+        }
+    }
+
+    /// Whether a characteristic resource is the one this key selects.
+    pub fn matches(&self, characteristic: &Characteristic) -> bool {
+        name(&characteristic.highest_stat) == Some(self.highest_stat)
+            && characteristic.gene_modulo == Some(self.gene_modulo)
+    }
+}
+
+/// Finds the characteristic a key selects among characteristic resources.
+pub fn find(characteristics: &[Characteristic], key: Key) -> Option<&Characteristic> {
+    characteristics
+        .iter()
+        .find(|characteristic| key.matches(characteristic))
+}
+
+/// The description of a characteristic in a language, by PokeAPI language name, e.g. `en`.
+pub fn description<'a>(characteristic: &'a Characteristic, language: &str) -> Option<&'a str> {
+    characteristic
+        .descriptions
+        .iter()
+        .flatten()
+        .find(|description| name(&description.language) == Some(language))
+        .and_then(|description| description.description.as_deref())
+}
+
+impl OwnedPokemon {
+    /// The characteristic of this Pokémon among characteristic resources.
+    pub fn characteristic<'a>(
+        &self,
+        characteristics: &'a [Characteristic],
+    ) -> Option<&'a Characteristic> {
+        find(characteristics, Key::of(&self.iv, self.personality))
+    }
+
+    /// The description of the characteristic of this Pokémon in a language, e.g. `en`.
+    pub fn characteristic_description<'a>(
+        &self,
+        characteristics: &'a [Characteristic],
+        language: &str,
+    ) -> Option<&'a str> {
+        self.characteristic(characteristics)
+            .and_then(|characteristic| description(characteristic, language))
+    }
+}
+
+/// Narrows down possible IVs from the characteristic a Pokémon is shown to have.
+///
+/// The highest IV must leave the characteristic's remainder and no other IV may exceed it.
+/// When the personality value is known, the IVs checked before the highest one on a tie
+/// must also be strictly lower.
+pub fn narrow(
+    possible: &mut PossibleIvs,
+    characteristic: &Characteristic,
+    personality: Option<u32>,
+) {
+    let Some(highest) = name(&characteristic.highest_stat) else {
+        return;
+    };
+    let gene_modulo = characteristic.gene_modulo;
+    let listed = characteristic.possible_values.as_deref();
+    possible.retain(highest, |value| {
+        gene_modulo.is_none_or(|modulo| value % 5 == modulo)
+            && listed.is_none_or(|listed| listed.contains(value))
+    });
+
+    let Some(&ceiling) = possible.get(highest).and_then(|values| values.iter().max()) else {
+        return;
+    };
+    let checked_first: Vec<&str> = match personality {
+        Some(personality) => tie_break_order(personality)
+            .take_while(|stat| *stat != highest)
+            .collect(),
+        None => Vec::new(),
+    };
+    let mut floor = 0;
+    for stat in Stats::NAMES.into_iter().filter(|stat| *stat != highest) {
+        if checked_first.contains(&stat) {
+            possible.retain(stat, |value| *value < ceiling);
+            floor = floor.max(min_of(possible, stat).map_or(0, |min| min + 1));
+        } else {
+            possible.retain(stat, |value| *value <= ceiling);
+            floor = floor.max(min_of(possible, stat).unwrap_or(0));
+        }
+    }
+    possible.retain(highest, |value| *value >= floor);
+}
+
+/// The stats in the order they are checked on a tie for a personality value.
+fn tie_break_order(personality: u32) -> impl Iterator<Item = &'static str> {
+    let start = (personality % 6) as usize;
+    (0..6).map(move |offset| TIE_BREAK_ORDER[(start + offset) % 6])
+}
+
+fn min_of(possible: &PossibleIvs, stat: &str) -> Option<i64> {
+    possible.get(stat)?.iter().min().copied()
+}
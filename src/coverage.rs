@@ -0,0 +1,293 @@
+//! Team type coverage
+//!
+//! Analyses which types a team hits super effectively and which types threaten several of its members,
+//! from the [`TypeRelations`](crate::model::pokemon::TypeRelations) gathered in a [`TypeChart`].
+
+use std::fmt;
+
+use crate::{
+    model::{moves::Move, pokemon::Pokemon, resource::name},
+    type_chart::TypeChart,
+};
+
+/// How many suggested types a report lists at most.
+pub const MAX_SUGGESTIONS: usize = 3;
+
+/// A member of a team with the moves it knows.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Member<'a> {
+    pub pokemon: &'a Pokemon,
+    pub moves: &'a [Move],
+}
+
+/// How well a team's moves hit one defending type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Offence {
+    pub defending: String,
+    /// The highest multiplier among the team's damaging moves, or `None` if the team has none.
+    pub best: Option<f64>,
+    /// The moves that hit the type super effectively.
+    pub super_effective: Vec<String>,
+}
+
+/// How one attacking type fares against each member of a team.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Defence {
+    pub attacking: String,
+    /// The multiplier against each member, in team order.
+    pub multipliers: Vec<f64>,
+}
+
+impl Defence {
+    /// How many members take more than normal damage.
+    pub fn weak(&self) -> usize {
+        self.multipliers
+            .iter()
+            .filter(|value| **value > 1.0)
+            .count()
+    }
+
+    /// How many members take less than normal damage, immunities included.
+    pub fn resistant(&self) -> usize {
+        self.multipliers
+            .iter()
+            .filter(|value| **value < 1.0)
+            .count()
+    }
+}
+
+/// A type that would fill gaps in a team, either as a move type or as a member's type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Suggestion {
+    pub type_: String,
+    /// Uncovered types a move of this type hits super effectively.
+    pub covers: Vec<String>,
+    /// Shared weaknesses a member of this type resists.
+    pub resists: Vec<String>,
+}
+
+/// The coverage of a team.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Coverage {
+    /// The names of the members, in team order.
+    pub members: Vec<String>,
+    pub offence: Vec<Offence>,
+    pub defence: Vec<Defence>,
+    /// Types that no damaging move of the team hits super effectively.
+    pub uncovered: Vec<String>,
+    /// Attacking types more than one member is weak to.
+    pub shared_weaknesses: Vec<String>,
+    /// The types that fill the most gaps, best first.
+    pub suggestions: Vec<Suggestion>,
+}
+
+impl Coverage {
+    /// Analyses a team against every type in the chart.
+    pub fn of(chart: &TypeChart, team: &[Member]) -> Self {
+        let mut types: Vec<&str> = chart.types().collect();
+        types.sort_unstable();
+
+        let damaging: Vec<&Move> = team
+            .iter()
+            .flat_map(|member| member.moves.iter())
+            .filter(|move_| is_damaging(move_))
+            .collect();
+        let offence: Vec<Offence> = types
+            .iter()
+            .map(|defending| {
+                let multiplier = |move_: &Move| {
+                    name(&move_.type_)
+                        .map_or(1.0, |attacking| chart.multiplier(attacking, defending))
+                };
+                Offence {
+                    defending: defending.to_string(),
+                    best: damaging
+                        .iter()
+                        .map(|move_| multiplier(move_))
+                        .reduce(f64::max),
+                    super_effective: damaging
+                        .iter()
+                        .filter(|move_| multiplier(move_) > 1.0)
+                        .filter_map(|move_| move_.name.clone())
+                        .collect(),
+                }
+            })
+            .collect();
+
+        let member_types: Vec<Vec<&str>> = team
+            .iter()
+            .map(|member| {
+                member
+                    .pokemon
+                    .types
+                    .iter()
+                    .flatten()
+                    .filter_map(|type_| name(&type_.type_))
+                    .collect()
+            })
+            .collect();
+        let defence: Vec<Defence> = types
+            .iter()
+            .map(|attacking| Defence {
+                attacking: attacking.to_string(),
+                multipliers: member_types
+                    .iter()
+                    .map(|defending| chart.effectiveness(attacking, defending))
+                    .collect(),
+            })
+            .collect();
+
+        let uncovered: Vec<String> = offence
+            .iter()
+            .filter(|entry| entry.super_effective.is_empty())
+            .map(|entry| entry.defending.clone())
+            .collect();
+        let shared_weaknesses: Vec<String> = defence
+            .iter()
+            .filter(|entry| entry.weak() > 1)
+            .map(|entry| entry.attacking.clone())
+            .collect();
+
+        let mut suggestions: Vec<Suggestion> = types
+            .iter()
+            .map(|type_| Suggestion {
+                type_: type_.to_string(),
+                covers: uncovered
+                    .iter()
+                    .filter(|defending| chart.multiplier(type_, defending) > 1.0)
+                    .cloned()
+                    .collect(),
+                resists: shared_weaknesses
+                    .iter()
+                    .filter(|attacking| chart.multiplier(attacking, type_) < 1.0)
+                    .cloned()
+                    .collect(),
+            })
+            .filter(|suggestion| !suggestion.covers.is_empty() || !suggestion.resists.is_empty())
+            .collect();
+        suggestions.sort_by_key(|suggestion| {
+            std::cmp::Reverse(suggestion.covers.len() + suggestion.resists.len())
+        });
+        suggestions.truncate(MAX_SUGGESTIONS);
+
+        Self {
+            members: team
+                .iter()
+                .map(|member| member.pokemon.name.clone().unwrap_or_default())
+                .collect(),
+            offence,
+            defence,
+            uncovered,
+            shared_weaknesses,
+            suggestions,
+        }
+    }
+}
+
+impl fmt::Display for Coverage {
+    /// Renders the report as plain text tables.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self
+            .offence
+            .iter()
+            .map(|entry| entry.defending.len())
+            .chain(["Type".len()])
+            .max()
+            .unwrap_or_default();
+        // Multipliers such as `0.25×` are wider than the headers of their columns.
+        let multiplier_width = self
+            .offence
+            .iter()
+            .map(|entry| format_best(entry.best))
+            .chain(
+                self.defence
+                    .iter()
+                    .flat_map(|entry| entry.multipliers.iter().copied())
+                    .map(format_multiplier),
+            )
+            .map(|multiplier| multiplier.chars().count())
+            .chain(["Best".len()])
+            .max()
+            .unwrap_or_default();
+
+        writeln!(f, "Offence")?;
+        writeln!(
+            f,
+            "{:<width$}  {:>multiplier_width$}  Super effective moves",
+            "Type", "Best"
+        )?;
+        for entry in &self.offence {
+            writeln!(
+                f,
+                "{:<width$}  {:>multiplier_width$}  {}",
+                entry.defending,
+                format_best(entry.best),
+                entry.super_effective.join(", ")
+            )?;
+        }
+
+        writeln!(f)?;
+        writeln!(f, "Defence")?;
+        write!(f, "{:<width$}", "Type")?;
+        for member in &self.members {
+            let w = member.chars().count().max(multiplier_width);
+            write!(f, "  {:>w$}", member)?;
+        }
+        writeln!(f, "  Weak  Resist")?;
+        for entry in &self.defence {
+            write!(f, "{:<width$}", entry.attacking)?;
+            for (member, multiplier) in self.members.iter().zip(&entry.multipliers) {
+                write!(
+                    f,
+                    "  {:>w$}",
+                    format_multiplier(*multiplier),
+                    w = member.chars().count().max(multiplier_width)
+                )?;
+            }
+            writeln!(f, "  {:>4}  {:>6}", entry.weak(), entry.resistant())?;
+        }
+
+        writeln!(f)?;
+        writeln!(f, "Uncovered types: {}", list(&self.uncovered))?;
+        writeln!(f, "Shared weaknesses: {}", list(&self.shared_weaknesses))?;
+        write!(f, "Suggested types:")?;
+        if self.suggestions.is_empty() {
+            writeln!(f, " none")?;
+        } else {
+            writeln!(f)?;
+        }
+        for suggestion in &self.suggestions {
+            writeln!(
+                f,
+                "  {}: covers {}; resists {}",
+                suggestion.type_,
+                list(&suggestion.covers),
+                list(&suggestion.resists)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Whether a move deals damage: any physical or special move, including those without a set power
+/// such as [Seismic Toss](http://bulbapedia.bulbagarden.net/wiki/Seismic_Toss_(move)).
+fn is_damaging(move_: &Move) -> bool {
+    matches!(name(&move_.damage_class), Some(class) if class != "status")
+}
+
+fn format_multiplier(multiplier: f64) -> String {
+    format!("{}×", multiplier)
+}
+
+/// A best multiplier, or `-` when there is no damaging move to take it from.
+fn format_best(best: Option<f64>) -> String {
+    best.map_or_else(|| "-".to_string(), format_multiplier)
+}
+
+fn list(names: &[String]) -> String {
+    if names.is_empty() {
+        "none".to_string()
+    } else {
+        names.join(", ")
+    }
+}
@@ -0,0 +1,376 @@
+//! Validated domain types
+//!
+//! The models mirror PokeAPI, so every field is an `Option`. The types here hold the data the rest of the
+//! crate relies on without `Option`s, and are made from the models with `TryFrom`, which reports the first
+//! piece of missing or invalid data.
+
+use std::{fmt, str::FromStr};
+
+use crate::{
+    model::{
+        moves::Move,
+        pokemon::{Pokemon, PokemonSpecies},
+        resource,
+    },
+    names::{DamageClass, PokemonTypeName},
+    stats::Stats,
+};
+
+/// Why a model could not be turned into a domain type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DataError {
+    /// A field is missing or null. Fields of list elements are indexed, e.g. `stats[2].base_stat`.
+    Missing { model: &'static str, field: String },
+    /// A number does not fit the domain type, e.g. a base stat above 255.
+    OutOfRange {
+        model: &'static str,
+        field: String,
+        value: i64,
+    },
+    /// A name is not one the domain type knows, e.g. a stat named `accuracy` among base stats.
+    UnknownName {
+        model: &'static str,
+        field: String,
+        name: String,
+    },
+    /// One of the six permanent stats is not among the base stats.
+    MissingStat {
+        model: &'static str,
+        stat: &'static str,
+    },
+}
+
+impl fmt::Display for DataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DataError::Missing { model, field } => write!(f, "{} has no {}", model, field),
+            DataError::OutOfRange {
+                model,
+                field,
+                value,
+            } => write!(f, "{} has {} out of range: {}", model, field, value),
+            DataError::UnknownName { model, field, name } => {
+                write!(f, "{} has an unknown {}: {}", model, field, name)
+            }
+            DataError::MissingStat { model, stat } => {
+                write!(f, "{} has no base {} stat", model, stat)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DataError {}
+
+/// A Pokémon with the data needed to battle, breed and check it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PokemonData {
+    pub id: i64,
+    /// The PokeAPI name, e.g. `pikachu`.
+    pub name: String,
+    /// The name of the species, e.g. `pikachu` for `pikachu-rock-star`.
+    pub species: String,
+    /// The base experience for defeating it. PokeAPI has none for some forms.
+    pub base_experience: Option<i64>,
+    /// The base stats, in the order of [`Stats::NAMES`].
+    pub base_stats: [u8; 6],
+    /// The effort values gained for defeating it, in the order of [`Stats::NAMES`].
+    pub ev_yield: [u8; 6],
+    /// Its types, in slot order.
+    pub types: Vec<PokemonTypeName>,
+    /// Its abilities, in slot order.
+    pub abilities: Vec<AbilitySlot>,
+    /// Height in decimetres.
+    pub height: i64,
+    /// Weight in hectograms.
+    pub weight: i64,
+}
+
+/// An ability a Pokémon can have.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbilitySlot {
+    /// The PokeAPI name, e.g. `static`.
+    pub name: String,
+    pub slot: i64,
+    pub hidden: bool,
+}
+
+impl PokemonData {
+    /// The base stats as [`Stats`], for stat calculations.
+    pub fn base_stats(&self) -> Stats {
+        to_stats(self.base_stats)
+    }
+
+    /// The effort values gained for defeating it as [`Stats`].
+    pub fn ev_yield(&self) -> Stats {
+        to_stats(self.ev_yield)
+    }
+}
+
+impl TryFrom<&Pokemon> for PokemonData {
+    type Error = DataError;
+
+    fn try_from(pokemon: &Pokemon) -> Result<Self, DataError> {
+        const MODEL: &str = "Pokemon";
+        let stats = required(pokemon.stats.as_ref(), MODEL, "stats")?;
+        let mut base_stats = [None; 6];
+        let mut ev_yield = [0; 6];
+        for (index, stat) in stats.iter().enumerate() {
+            let field = |name: &str| format!("stats[{}].{}", index, name);
+            let name = required(resource::name(&stat.stat), MODEL, &field("stat.name"))?;
+            let position = Stats::NAMES
+                .iter()
+                .position(|known| *known == name)
+                .ok_or_else(|| DataError::UnknownName {
+                    model: MODEL,
+                    field: field("stat.name"),
+                    name: name.to_string(),
+                })?;
+            let base_stat = *required(stat.base_stat.as_ref(), MODEL, &field("base_stat"))?;
+            base_stats[position] = Some(byte(base_stat, MODEL, &field("base_stat"))?);
+            let effort = *required(stat.effort.as_ref(), MODEL, &field("effort"))?;
+            ev_yield[position] = byte(effort, MODEL, &field("effort"))?;
+        }
+        let mut checked = [0; 6];
+        for (position, value) in base_stats.into_iter().enumerate() {
+            checked[position] = value.ok_or(DataError::MissingStat {
+                model: MODEL,
+                stat: Stats::NAMES[position],
+            })?;
+        }
+
+        let mut types: Vec<_> = required(pokemon.types.as_ref(), MODEL, "types")?
+            .iter()
+            .enumerate()
+            .map(|(index, type_)| {
+                let slot = *required(
+                    type_.slot.as_ref(),
+                    MODEL,
+                    &format!("types[{}].slot", index),
+                )?;
+                let field = format!("types[{}].type.name", index);
+                let name = required(resource::name(&type_.type_), MODEL, &field)?;
+                Ok((slot, parse(name, MODEL, &field)?))
+            })
+            .collect::<Result<_, DataError>>()?;
+        types.sort_by_key(|(slot, _)| *slot);
+
+        let mut abilities = required(pokemon.abilities.as_ref(), MODEL, "abilities")?
+            .iter()
+            .enumerate()
+            .map(|(index, ability)| {
+                let field = |name: &str| format!("abilities[{}].{}", index, name);
+                Ok(AbilitySlot {
+                    name: required(
+                        resource::name(&ability.ability),
+                        MODEL,
+                        &field("ability.name"),
+                    )?
+                    .to_string(),
+                    slot: *required(ability.slot.as_ref(), MODEL, &field("slot"))?,
+                    hidden: *required(ability.is_hidden.as_ref(), MODEL, &field("is_hidden"))?,
+                })
+            })
+            .collect::<Result<Vec<_>, DataError>>()?;
+        abilities.sort_by_key(|ability| ability.slot);
+
+        Ok(Self {
+            id: *required(pokemon.id.as_ref(), MODEL, "id")?,
+            name: required(pokemon.name.as_ref(), MODEL, "name")?.clone(),
+            species: required(resource::name(&pokemon.species), MODEL, "species.name")?.to_string(),
+            base_experience: pokemon.base_experience,
+            base_stats: checked,
+            ev_yield,
+            types: types.into_iter().map(|(_, name)| name).collect(),
+            abilities,
+            height: *required(pokemon.height.as_ref(), MODEL, "height")?,
+            weight: *required(pokemon.weight.as_ref(), MODEL, "weight")?,
+        })
+    }
+}
+
+impl TryFrom<Pokemon> for PokemonData {
+    type Error = DataError;
+
+    fn try_from(pokemon: Pokemon) -> Result<Self, DataError> {
+        Self::try_from(&pokemon)
+    }
+}
+
+/// A species with the data needed to catch, breed and evolve its Pokémon.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpeciesData {
+    pub id: i64,
+    /// The PokeAPI name, e.g. `pikachu`.
+    pub name: String,
+    /// The chance of being female, in eighths; or -1 for genderless.
+    pub gender_rate: i64,
+    /// The base capture rate, up to 255.
+    pub capture_rate: u8,
+    /// The name of the growth rate, e.g. `medium`.
+    pub growth_rate: String,
+    /// The names of its egg groups, e.g. `ground`.
+    pub egg_groups: Vec<String>,
+    /// The species it evolves from, if any.
+    pub evolves_from: Option<String>,
+    pub is_baby: bool,
+    pub is_legendary: bool,
+    pub is_mythical: bool,
+}
+
+impl TryFrom<&PokemonSpecies> for SpeciesData {
+    type Error = DataError;
+
+    fn try_from(species: &PokemonSpecies) -> Result<Self, DataError> {
+        const MODEL: &str = "PokemonSpecies";
+        let gender_rate = *required(species.gender_rate.as_ref(), MODEL, "gender_rate")?;
+        if !(-1..=8).contains(&gender_rate) {
+            return Err(DataError::OutOfRange {
+                model: MODEL,
+                field: "gender_rate".to_string(),
+                value: gender_rate,
+            });
+        }
+        let capture_rate = *required(species.capture_rate.as_ref(), MODEL, "capture_rate")?;
+        let egg_groups = required(species.egg_groups.as_ref(), MODEL, "egg_groups")?
+            .iter()
+            .enumerate()
+            .map(|(index, group)| {
+                required(
+                    group.name.clone(),
+                    MODEL,
+                    &format!("egg_groups[{}].name", index),
+                )
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            id: *required(species.id.as_ref(), MODEL, "id")?,
+            name: required(species.name.as_ref(), MODEL, "name")?.clone(),
+            gender_rate,
+            capture_rate: byte(capture_rate, MODEL, "capture_rate")?,
+            growth_rate: required(
+                resource::name(&species.growth_rate),
+                MODEL,
+                "growth_rate.name",
+            )?
+            .to_string(),
+            egg_groups,
+            evolves_from: resource::name(&species.evolves_from_species).map(str::to_string),
+            is_baby: *required(species.is_baby.as_ref(), MODEL, "is_baby")?,
+            is_legendary: *required(species.is_legendary.as_ref(), MODEL, "is_legendary")?,
+            is_mythical: *required(species.is_mythical.as_ref(), MODEL, "is_mythical")?,
+        })
+    }
+}
+
+impl TryFrom<PokemonSpecies> for SpeciesData {
+    type Error = DataError;
+
+    fn try_from(species: PokemonSpecies) -> Result<Self, DataError> {
+        Self::try_from(&species)
+    }
+}
+
+/// A move with the data needed to use it in battle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MoveData {
+    pub id: i64,
+    /// The PokeAPI name, e.g. `thunderbolt`.
+    pub name: String,
+    pub type_: PokemonTypeName,
+    pub damage_class: DamageClass,
+    /// The base power. `None` for moves without one, such as status moves.
+    pub power: Option<i64>,
+    /// The accuracy in percent. `None` for moves that never miss.
+    pub accuracy: Option<i64>,
+    pub pp: i64,
+    /// Between -8 and 8; moves of higher priority go first.
+    pub priority: i64,
+    /// The name of what the move targets, e.g. `selected-pokemon`.
+    pub target: String,
+    /// The chance in percent of its secondary effect. `None` if it has none or it always happens.
+    pub effect_chance: Option<i64>,
+}
+
+impl TryFrom<&Move> for MoveData {
+    type Error = DataError;
+
+    fn try_from(move_: &Move) -> Result<Self, DataError> {
+        const MODEL: &str = "Move";
+        let priority = *required(move_.priority.as_ref(), MODEL, "priority")?;
+        if !(-8..=8).contains(&priority) {
+            return Err(DataError::OutOfRange {
+                model: MODEL,
+                field: "priority".to_string(),
+                value: priority,
+            });
+        }
+        Ok(Self {
+            id: *required(move_.id.as_ref(), MODEL, "id")?,
+            name: required(move_.name.as_ref(), MODEL, "name")?.clone(),
+            type_: parse(
+                required(resource::name(&move_.type_), MODEL, "type.name")?,
+                MODEL,
+                "type.name",
+            )?,
+            damage_class: parse(
+                required(
+                    resource::name(&move_.damage_class),
+                    MODEL,
+                    "damage_class.name",
+                )?,
+                MODEL,
+                "damage_class.name",
+            )?,
+            power: move_.power,
+            accuracy: move_.accuracy,
+            pp: *required(move_.pp.as_ref(), MODEL, "pp")?,
+            priority,
+            target: required(resource::name(&move_.target), MODEL, "target.name")?.to_string(),
+            effect_chance: move_.effect_chance,
+        })
+    }
+}
+
+impl TryFrom<Move> for MoveData {
+    type Error = DataError;
+
+    fn try_from(move_: Move) -> Result<Self, DataError> {
+        Self::try_from(&move_)
+    }
+}
+
+fn required<T>(value: Option<T>, model: &'static str, field: &str) -> Result<T, DataError> {
+    value.ok_or_else(|| DataError::Missing {
+        model,
+        field: field.to_string(),
+    })
+}
+
+fn byte(value: i64, model: &'static str, field: &str) -> Result<u8, DataError> {
+    u8::try_from(value).map_err(|_| DataError::OutOfRange {
+        model,
+        field: field.to_string(),
+        value,
+    })
+}
+
+fn parse<T: FromStr>(name: &str, model: &'static str, field: &str) -> Result<T, DataError> {
+    name.parse().map_err(|_| DataError::UnknownName {
+        model,
+        field: field.to_string(),
+        name: name.to_string(),
+    })
+}
+
+fn to_stats(values: [u8; 6]) -> Stats {
+    let [hp, attack, defence, special_attack, special_defence, speed] = values.map(i64::from);
+    Stats {
+        hp,
+        attack,
+        defence,
+        special_attack,
+        special_defence,
+        speed,
+    }
+}
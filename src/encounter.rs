@@ -0,0 +1,266 @@
+//! Wild encounters
+//!
+//! Rolls wild Pokémon from the encounters of a [`LocationArea`], as listed by its `pokemon_encounters`.
+//! See [Bulbapedia](http://bulbapedia.bulbagarden.net/wiki/Wild_Pokémon) for greater detail.
+
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{
+    experience,
+    generation::Generation,
+    model::{
+        location::LocationArea,
+        pokemon::{Gender as GenderResource, GrowthRate, Pokemon, PokemonSpecies},
+        resource::{name, Encounter},
+    },
+    owned::{Gender, OwnedPokemon, BASE_FRIENDSHIP},
+    stats::Stats,
+};
+
+/// The PokeAPI names of the natures, in game order.
+pub const NATURES: [&str; 25] = [
+    "hardy", "lonely", "brave", "adamant", "naughty", "bold", "docile", "relaxed", "impish", "lax",
+    "timid", "hasty", "serious", "jolly", "naive", "modest", "mild", "quiet", "bashful", "rash",
+    "calm", "gentle", "sassy", "careful", "quirky",
+];
+
+/// Where and how a wild Pokémon is looked for. Names are PokeAPI names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Search<'a> {
+    /// The game version, e.g. `heartgold`.
+    pub version: &'a str,
+    /// The encounter method, e.g. `walk`, `surf` or `old-rod`.
+    pub method: &'a str,
+    /// The encounter condition values in effect, e.g. `time-night` or `season-winter`.
+    ///
+    /// An encounter only happens when each of its condition values is in effect. Conditions
+    /// left out are taken to be in their default state, such as `swarm-no` or `radar-off`.
+    pub conditions: &'a [&'a str],
+}
+
+impl Search<'_> {
+    fn allows(&self, value: &str) -> bool {
+        let is_default = ["-no", "-off", "-none"]
+            .iter()
+            .any(|default| value.ends_with(default));
+        let overridden = self
+            .conditions
+            .iter()
+            .any(|condition| same_condition(condition, value));
+        self.conditions.contains(&value) || (is_default && !overridden)
+    }
+}
+
+/// An encounter of a Pokémon that can happen for a search.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Slot<'a> {
+    /// The PokeAPI name of the Pokémon encountered.
+    pub pokemon: &'a str,
+    pub encounter: &'a Encounter,
+}
+
+impl Slot<'_> {
+    /// Rolls the level of the Pokémon within the range of the encounter.
+    pub fn roll_level(&self, rng: &mut impl Rng) -> i64 {
+        let min = self.encounter.min_level.unwrap_or(1);
+        let max = self.encounter.max_level.unwrap_or(min).max(min);
+        rng.gen_range(min..=max)
+    }
+}
+
+/// Every encounter of a location area that can happen for a search.
+pub fn slots<'a>(area: &'a LocationArea, search: &Search) -> Vec<Slot<'a>> {
+    let mut slots = Vec::new();
+    for pokemon_encounter in area.pokemon_encounters.iter().flatten() {
+        let Some(pokemon) = name(&pokemon_encounter.pokemon) else {
+            continue;
+        };
+        let details = pokemon_encounter
+            .version_details
+            .iter()
+            .flatten()
+            .filter(|details| name(&details.version) == Some(search.version));
+        for encounter in details.flat_map(|details| details.encounter_details.iter().flatten()) {
+            let conditions_met = encounter
+                .condition_values
+                .iter()
+                .flatten()
+                .filter_map(name)
+                .all(|value| search.allows(value));
+            if name(&encounter.method) == Some(search.method) && conditions_met {
+                slots.push(Slot { pokemon, encounter });
+            }
+        }
+    }
+    slots
+}
+
+/// Rolls one of the encounters of a location area for a search, weighted by their chances.
+/// `None` if no Pokémon can be found that way.
+pub fn roll_slot<'a>(
+    area: &'a LocationArea,
+    search: &Search,
+    rng: &mut impl Rng,
+) -> Option<Slot<'a>> {
+    slots(area, search)
+        .choose_weighted(rng, |slot| slot.encounter.chance.unwrap_or_default().max(0))
+        .ok()
+        .copied()
+}
+
+/// Rolls the gender of a Pokémon from the chance of it being female, in eighths, or -1 for genderless;
+/// the `gender_rate` of a [`PokemonSpecies`] or the `rate` of a
+/// [`PokemonSpeciesGender`](crate::model::pokemon::PokemonSpeciesGender).
+pub fn roll_gender(rate: i64, rng: &mut impl Rng) -> Gender {
+    match rate {
+        rate if rate < 0 => Gender::Genderless,
+        rate if rng.gen_range(0..8) < rate => Gender::Female,
+        _ => Gender::Male,
+    }
+}
+
+/// The chance of a species being female, in eighths, from the details of a `gender` resource.
+pub fn gender_rate(gender: &GenderResource, species: &str) -> Option<i64> {
+    gender
+        .pokemon_species_details
+        .iter()
+        .flatten()
+        .find(|details| name(&details.pokemon_species) == Some(species))
+        .and_then(|details| details.rate)
+}
+
+/// Whether a personality value is shiny for a trainer, whose public ID is in the lower 16 bits
+/// of `trainer_id` and secret ID in the upper 16 bits. The odds are 1 in 8192 up to
+/// Generation V and 1 in 4096 from Generation VI.
+pub fn is_shiny(generation: Generation, trainer_id: u32, personality: u32) -> bool {
+    let value = (trainer_id ^ (trainer_id >> 16) ^ personality ^ (personality >> 16)) & 0xFFFF;
+    let threshold = if generation >= Generation::VI { 16 } else { 8 };
+    value < threshold
+}
+
+/// The data a wild Pokémon is generated from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Wild<'a> {
+    pub generation: Generation,
+    pub pokemon: &'a Pokemon,
+    pub species: &'a PokemonSpecies,
+    /// The growth rate of the species, to give the Pokémon the experience of its level.
+    pub growth_rate: Option<&'a GrowthRate>,
+    /// The PokeAPI name of the version group of the game the Pokémon is met in, e.g.
+    /// `heartgold-soulsilver`, whose learnset gives the Pokémon its moves.
+    pub version_group: &'a str,
+    /// The IDs of the trainer meeting the Pokémon, for shininess. See [`is_shiny`].
+    pub trainer_id: u32,
+}
+
+impl Wild<'_> {
+    /// Generates a wild Pokémon of a level with random IVs, nature, gender, personality value
+    /// and one of the abilities that are not hidden, without EVs and with its latest level-up moves.
+    pub fn generate(&self, level: i64, rng: &mut impl Rng) -> OwnedPokemon {
+        let mut wild = OwnedPokemon::new(self.pokemon.name.clone().unwrap_or_default(), level);
+        wild.experience = self
+            .growth_rate
+            .and_then(|growth_rate| experience::experience_for_level(growth_rate, level))
+            .unwrap_or_default();
+        wild.friendship = self.species.base_happiness.unwrap_or(BASE_FRIENDSHIP);
+        wild.gender = roll_gender(self.species.gender_rate.unwrap_or(-1), rng);
+        wild.nature = NATURES.choose(rng).map(|nature| nature.to_string());
+        wild.iv = roll_ivs(rng);
+        wild.personality = rng.gen();
+        wild.shiny = is_shiny(self.generation, self.trainer_id, wild.personality);
+
+        let abilities: Vec<_> = self
+            .pokemon
+            .abilities
+            .iter()
+            .flatten()
+            .filter(|ability| ability.is_hidden != Some(true))
+            .filter_map(|ability| name(&ability.ability))
+            .collect();
+        wild.ability = abilities.choose(rng).map(|ability| ability.to_string());
+        wild.moves = level_up_moves(self.pokemon, level, self.version_group);
+        wild
+    }
+}
+
+/// Rolls individual values from 0 to 31.
+pub fn roll_ivs(rng: &mut impl Rng) -> Stats {
+    Stats {
+        hp: rng.gen_range(0..=31),
+        attack: rng.gen_range(0..=31),
+        defence: rng.gen_range(0..=31),
+        special_attack: rng.gen_range(0..=31),
+        special_defence: rng.gen_range(0..=31),
+        speed: rng.gen_range(0..=31),
+    }
+}
+
+/// The last four moves a Pokémon learns by level-up up to a level in a version group,
+/// in the order they are learned.
+pub fn level_up_moves(pokemon: &Pokemon, level: i64, version_group: &str) -> Vec<String> {
+    let mut learned: Vec<(i64, &str)> = pokemon
+        .moves
+        .iter()
+        .flatten()
+        .filter_map(|move_| {
+            let learned_at = move_
+                .version_group_details
+                .iter()
+                .flatten()
+                .filter(|detail| name(&detail.version_group) == Some(version_group))
+                .filter(|detail| name(&detail.move_learn_method) == Some("level-up"))
+                .filter_map(|detail| detail.level_learned_at)
+                .filter(|learned_at| *learned_at <= level)
+                .min()?;
+            Some((learned_at, name(&move_.move_)?))
+        })
+        .collect();
+    learned.sort();
+    let skip = learned.len().saturating_sub(4);
+    learned
+        .into_iter()
+        .skip(skip)
+        .map(|(_, move_)| move_.to_string())
+        .collect()
+}
+
+/// The PokeAPI names of the encounter conditions, which prefix the names of their values,
+/// e.g. `story-progress` for `story-progress-beat-red`.
+pub const ENCOUNTER_CONDITIONS: [&str; 12] = [
+    "swarm",
+    "time",
+    "radar",
+    "slot2",
+    "radio",
+    "season",
+    "starter",
+    "tv-option",
+    "story-progress",
+    "other",
+    "item",
+    "weather",
+];
+
+/// The condition a condition value belongs to, e.g. `time` for `time-night`. Values of conditions
+/// missing from [`ENCOUNTER_CONDITIONS`] are taken to end with a single word after their condition.
+fn condition_of(value: &str) -> &str {
+    ENCOUNTER_CONDITIONS
+        .iter()
+        .copied()
+        .filter(|condition| {
+            value
+                .strip_prefix(condition)
+                .is_some_and(|rest| rest.starts_with('-'))
+        })
+        .max_by_key(|condition| condition.len())
+        .unwrap_or_else(|| {
+            value
+                .rsplit_once('-')
+                .map_or(value, |(condition, _)| condition)
+        })
+}
+
+/// Whether two condition values belong to the same condition, e.g. `time-day` and `time-night`.
+fn same_condition(a: &str, b: &str) -> bool {
+    condition_of(a) == condition_of(b)
+}
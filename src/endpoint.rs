@@ -1,20 +1,148 @@
+use std::{collections::BTreeMap, future::Future, sync::Mutex};
+
+use serde::de::DeserializeOwned;
+use tokio::{sync::watch, task::JoinSet};
+
+use crate::error::Error;
+
+/// What a request in flight resolved to: `Some(json)`, or `None` if it failed.
+type Landed = Option<Option<String>>;
+
+/// Requests in flight by the generation of their source and their path, which requests for the
+/// same resource wait for instead of fetching it again.
+static IN_FLIGHT: Mutex<BTreeMap<(u64, String), watch::Receiver<Landed>>> =
+    Mutex::new(BTreeMap::new());
+
+/// Fetches a resource by its path, e.g. `pokemon/25`, from the installed [`DataSource`](crate::source::DataSource),
+/// deserializing it strictly if [`set_strict`](crate::model::strict::set_strict) asks to.
+pub(crate) async fn fetch<T: DeserializeOwned>(path: &str) -> Result<T, Error> {
+    let json = fetch_json(path).await?;
+    if crate::model::strict::is_strict() {
+        Ok(crate::model::strict::from_str(&json)?)
+    } else {
+        Ok(serde_json::from_str(&json)?)
+    }
+}
+
+/// Fetches the JSON of a resource, sharing the response of a request for it already in flight.
+/// If that request fails, the resource is fetched again so that each caller gets its own error.
+async fn fetch_json(path: &str) -> Result<String, Error> {
+    let key = (crate::source::generation(), path.to_string());
+    let flight = {
+        let mut in_flight = IN_FLIGHT.lock().unwrap();
+        match in_flight.get(&key) {
+            Some(receiver) => Err(receiver.clone()),
+            None => {
+                let (sender, receiver) = watch::channel(None);
+                in_flight.insert(key.clone(), receiver);
+                Ok(sender)
+            }
+        }
+    };
+
+    match flight {
+        Ok(sender) => {
+            let _flight = Flight(key);
+            let result = crate::source::source().fetch(path).await;
+            sender.send_replace(Some(result.as_ref().ok().cloned()));
+            result
+        }
+        Err(mut receiver) => {
+            let landed = match receiver.wait_for(Option::is_some).await {
+                Ok(landed) => landed.clone().flatten(),
+                // The request was dropped before it landed.
+                Err(_) => None,
+            };
+            match landed {
+                Some(json) => Ok(json),
+                None => crate::source::source().fetch(path).await,
+            }
+        }
+    }
+}
+
+/// Removes a request from those in flight once it lands or is dropped.
+struct Flight((u64, String));
+
+impl Drop for Flight {
+    fn drop(&mut self) {
+        IN_FLIGHT.lock().unwrap().remove(&self.0);
+    }
+}
+
+/// Fetches a resource for each key with at most `concurrency` requests in flight at once, or
+/// [`DEFAULT_CONCURRENCY`](crate::source::DEFAULT_CONCURRENCY) if it is 0. The results are in the
+/// order of the keys.
+pub(crate) async fn fetch_many<K, T, F, R>(
+    keys: Vec<K>,
+    concurrency: usize,
+    fetch: F,
+) -> Vec<Result<T, Error>>
+where
+    T: Send + 'static,
+    F: Fn(K) -> R,
+    R: Future<Output = Result<T, Error>> + Send + 'static,
+{
+    let concurrency = if concurrency == 0 {
+        crate::source::DEFAULT_CONCURRENCY
+    } else {
+        concurrency
+    };
+    let mut results: Vec<_> = keys.iter().map(|_| None).collect();
+    let mut requests = JoinSet::new();
+    for (index, key) in keys.into_iter().enumerate() {
+        if requests.len() == concurrency {
+            let (index, result) = joined(requests.join_next().await);
+            results[index] = Some(result);
+        }
+        let request = fetch(key);
+        requests.spawn(async move { (index, request.await) });
+    }
+    while let Some(result) = requests.join_next().await {
+        let (index, result) = joined(Some(result));
+        results[index] = Some(result);
+    }
+    results
+        .into_iter()
+        .map(|result| result.expect("every request was joined"))
+        .collect()
+}
+
+fn joined<T>(result: Option<Result<T, tokio::task::JoinError>>) -> T {
+    result
+        .expect("a request is in flight")
+        .expect("a request panicked")
+}
+
+// Cache keys include the generation of the data source, so that installing another source
+// does not serve results fetched from the previous one.
+// Results expire after 3 days and are fetched again, which the default `Http` source does with a
+// conditional request.
 macro_rules! endpoint {
     ($type:ty; for $name:literal) => {
         use cached::proc_macro::cached;
-        use reqwest::Url;
 
-        const ENDPOINT: &str = concat!("https:///pokeapi.co/api/v2/", $name, "/");
+        #[cached(result = true, time = 259200, key = "(u64, i64)", convert = r#"{ (crate::source::generation(), id) }"#)]
+        pub async fn get_by_id(id: i64) -> Result<$type, crate::error::Error> {
+            crate::endpoint::fetch(&format!("{}/{}", $name, id)).await
+        }
+
+        #[cached(result = true, time = 259200, key = "(u64, String)", convert = r#"{ (crate::source::generation(), name.to_string()) }"#)]
+        pub async fn get_by_name(name: &str) -> Result<$type, crate::error::Error> {
+            crate::endpoint::fetch(&format!("{}/{}", $name, name)).await
+        }
 
-        #[cached(result = true, time = 259200)]
-        pub async fn get_by_id(id: i64) -> Result<$type, reqwest::Error> {
-            let url = Url::parse(ENDPOINT).unwrap().join(&id.to_string()).unwrap();
-            reqwest::get(url).await?.json::<$type>().await
+        /// Fetches several resources at once, with at most `concurrency` requests in flight.
+        /// Returns a result for each ID, in order.
+        pub async fn get_many_by_id(ids: &[i64], concurrency: usize) -> Vec<Result<$type, crate::error::Error>> {
+            crate::endpoint::fetch_many(ids.to_vec(), concurrency, get_by_id).await
         }
 
-        #[cached(result = true, time = 259200)]
-        pub async fn get_by_name(name: &'static str) -> Result<$type, reqwest::Error> {
-            let url = Url::parse(ENDPOINT).unwrap().join(name).unwrap();
-            reqwest::get(url).await?.json::<$type>().await
+        /// Fetches several resources at once, with at most `concurrency` requests in flight.
+        /// Returns a result for each name, in order.
+        pub async fn get_many_by_name(names: &[impl AsRef<str>], concurrency: usize) -> Vec<Result<$type, crate::error::Error>> {
+            let names = names.iter().map(|name| name.as_ref().to_string()).collect();
+            crate::endpoint::fetch_many(names, concurrency, |name: String| async move { get_by_name(&name).await }).await
         }
     };
 
@@ -26,28 +154,34 @@ macro_rules! endpoint {
             pub mod $sub {
 
                 use cached::proc_macro::cached;
-                use reqwest::Url;
-
-                use super::ENDPOINT;
 
                 const SUB_ENDPOINT: &str = stringify!($sub);
 
-                #[cached(result = true, time = 259200)]
-                pub async fn get_by_id(id: i64) -> Result<$type, reqwest::Error> {
-                    let sub_path = format!("{}/{}", id, SUB_ENDPOINT);
-                    let url = Url::parse(ENDPOINT).unwrap().join(&sub_path).unwrap();
-                    reqwest::get(url).await?.json::<$type>().await
+                #[cached(result = true, time = 259200, key = "(u64, i64)", convert = r#"{ (crate::source::generation(), id) }"#)]
+                pub async fn get_by_id(id: i64) -> Result<$sub_type, crate::error::Error> {
+                    crate::endpoint::fetch(&format!("{}/{}/{}", $name, id, SUB_ENDPOINT)).await
+                }
+
+                #[cached(result = true, time = 259200, key = "(u64, String)", convert = r#"{ (crate::source::generation(), name.to_string()) }"#)]
+                pub async fn get_by_name(name: &str) -> Result<$sub_type, crate::error::Error> {
+                    crate::endpoint::fetch(&format!("{}/{}/{}", $name, name, SUB_ENDPOINT)).await
+                }
+
+                /// Fetches several resources at once, with at most `concurrency` requests in flight.
+                /// Returns a result for each ID, in order.
+                pub async fn get_many_by_id(ids: &[i64], concurrency: usize) -> Vec<Result<$sub_type, crate::error::Error>> {
+                    crate::endpoint::fetch_many(ids.to_vec(), concurrency, get_by_id).await
                 }
 
-                #[cached(result = true, time = 259200)]
-                pub async fn get_by_name(name: &'static str) -> Result<$type, reqwest::Error> {
-                    let sub_path = format!("{}/{}", name, SUB_ENDPOINT);
-                    let url = Url::parse(ENDPOINT).unwrap().join(&sub_path).unwrap();
-                    reqwest::get(url).await?.json::<$type>().await
+                /// Fetches several resources at once, with at most `concurrency` requests in flight.
+                /// Returns a result for each name, in order.
+                pub async fn get_many_by_name(names: &[impl AsRef<str>], concurrency: usize) -> Vec<Result<$sub_type, crate::error::Error>> {
+                    let names = names.iter().map(|name| name.as_ref().to_string()).collect();
+                    crate::endpoint::fetch_many(names, concurrency, |name: String| async move { get_by_name(&name).await }).await
                 }
             }
         )+
     };
 }
 
-pub(crate) use endpoint;
\ No newline at end of file
+pub(crate) use endpoint;
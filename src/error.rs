@@ -0,0 +1,56 @@
+//! Errors of the endpoint functions
+
+use std::fmt;
+
+/// Why a resource could not be fetched.
+#[derive(Debug)]
+pub enum Error {
+    /// The request to PokeAPI failed or it answered with an error status.
+    Request(reqwest::Error),
+    /// There is no resource at the path or URL, e.g. `pokemon/not-a-pokemon`.
+    NotFound(String),
+    /// The resource is not the JSON of the expected model.
+    Decode(serde_json::Error),
+    /// The offline bundle could not be read or written.
+    Bundle(rusqlite::Error),
+    /// A file of a data source could not be read or written.
+    Io(std::io::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Request(error) => write!(f, "{}", error),
+            Error::NotFound(path) => write!(f, "no resource at {}", path),
+            Error::Decode(error) => write!(f, "unexpected resource: {}", error),
+            Error::Bundle(error) => write!(f, "offline bundle: {}", error),
+            Error::Io(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<reqwest::Error> for Error {
+    fn from(error: reqwest::Error) -> Self {
+        Error::Request(error)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(error: serde_json::Error) -> Self {
+        Error::Decode(error)
+    }
+}
+
+impl From<rusqlite::Error> for Error {
+    fn from(error: rusqlite::Error) -> Self {
+        Error::Bundle(error)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Error::Io(error)
+    }
+}
@@ -0,0 +1,266 @@
+//! Evolution of owned Pokémon
+//!
+//! Evolution conditions come from the [`EvolutionChain`] of a species, reached through the
+//! `evolution_chain` of its [`PokemonSpecies`].
+//! See [Bulbapedia](http://bulbapedia.bulbagarden.net/wiki/Methods_of_evolution) for greater detail.
+
+use crate::{
+    model::{
+        evolution::{ChainLink, EvolutionChain, EvolutionDetail},
+        pokemon::{Nature, Pokemon, PokemonSpecies},
+        resource::{name, NamedApiResource},
+    },
+    owned::{Gender, OwnedPokemon},
+    stats::Stats,
+};
+
+/// The event that gives a Pokémon the chance to evolve.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Trigger {
+    /// The Pokémon gained a level.
+    LevelUp,
+    /// An item was used on the Pokémon, named by its PokeAPI name, e.g. `thunder-stone`.
+    UseItem(String),
+    /// The Pokémon was traded.
+    Trade,
+    /// The friendship or affection of the Pokémon changed. Only evolutions that require
+    /// a minimum friendship or affection are considered.
+    FriendshipChange,
+}
+
+impl Trigger {
+    /// The PokeAPI name of the `evolution-trigger` this event corresponds to.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Trigger::LevelUp | Trigger::FriendshipChange => "level-up",
+            Trigger::UseItem(_) => "use-item",
+            Trigger::Trade => "trade",
+        }
+    }
+}
+
+/// What surrounds the Pokémon when an evolution is triggered. Names are PokeAPI names.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct Surroundings {
+    /// `day` or `night`.
+    pub time_of_day: Option<String>,
+    pub location: Option<String>,
+    pub overworld_rain: bool,
+    /// Whether the console is held upside-down.
+    pub upside_down: bool,
+    /// The species of the other Pokémon in the party.
+    pub party_species: Vec<String>,
+    /// The types of the other Pokémon in the party.
+    pub party_types: Vec<String>,
+    /// The types of the moves known by the evolving Pokémon.
+    pub known_move_types: Vec<String>,
+    /// The species the Pokémon is traded for.
+    pub trade_species: Option<String>,
+    pub beauty: i64,
+    pub affection: i64,
+}
+
+/// An evolution whose conditions are met.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Candidate<'a> {
+    /// The PokeAPI name of the species evolved into.
+    pub species: &'a str,
+    /// The conditions that were met.
+    pub detail: &'a EvolutionDetail,
+}
+
+/// The result of evolving a Pokémon.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Evolved {
+    /// The PokeAPI name of the Pokémon before evolving.
+    pub from: String,
+    /// The stats of the evolved Pokémon.
+    pub stats: Stats,
+    /// Moves learned upon evolving.
+    pub learned: Vec<String>,
+    /// Moves that could be learned upon evolving but did not fit among the four known moves.
+    pub pending: Vec<String>,
+}
+
+/// The id of the evolution chain of a species, read from the URL it refers to.
+pub fn chain_id(species: &PokemonSpecies) -> Option<i64> {
+    let url = species.evolution_chain.as_ref()?.url.as_deref()?;
+    url.trim_end_matches('/').rsplit('/').next()?.parse().ok()
+}
+
+/// Finds the link of a species in an evolution chain.
+pub fn find_link<'a>(chain: &'a EvolutionChain, species: &str) -> Option<&'a ChainLink> {
+    find_in(chain.chain.as_ref()?, species)
+}
+
+/// Finds the link of a species among a link and the links that evolve from it.
+pub fn find_in<'a>(link: &'a ChainLink, species: &str) -> Option<&'a ChainLink> {
+    if name(&link.species) == Some(species) {
+        return Some(link);
+    }
+    link.evolves_to
+        .iter()
+        .flatten()
+        .find_map(|next| find_in(next, species))
+}
+
+/// The first evolution of a species whose conditions the Pokémon meets for a trigger.
+/// `stats` are the current stats of the Pokémon, compared for evolutions such as Tyrogue's.
+pub fn find_evolution<'a>(
+    chain: &'a EvolutionChain,
+    species: &str,
+    pokemon: &OwnedPokemon,
+    stats: &Stats,
+    trigger: &Trigger,
+    surroundings: &Surroundings,
+) -> Option<Candidate<'a>> {
+    find_link(chain, species)?
+        .evolves_to
+        .iter()
+        .flatten()
+        .find_map(|next| {
+            let species = name(&next.species)?;
+            next.evolution_details
+                .iter()
+                .flatten()
+                .find(|detail| meets(detail, pokemon, stats, trigger, surroundings))
+                .map(|detail| Candidate { species, detail })
+        })
+}
+
+/// Whether the Pokémon meets the conditions of an evolution detail for a trigger.
+pub fn meets(
+    detail: &EvolutionDetail,
+    pokemon: &OwnedPokemon,
+    stats: &Stats,
+    trigger: &Trigger,
+    surroundings: &Surroundings,
+) -> bool {
+    if name(&detail.trigger) != Some(trigger.name()) {
+        return false;
+    }
+    match trigger {
+        Trigger::UseItem(item) if name(&detail.item) != Some(item.as_str()) => return false,
+        Trigger::FriendshipChange
+            if detail.min_happiness.is_none() && detail.min_affection.is_none() =>
+        {
+            return false
+        }
+        _ => {}
+    }
+
+    let gender = match detail.gender {
+        Some(1) => pokemon.gender == Gender::Female,
+        Some(2) => pokemon.gender == Gender::Male,
+        _ => true,
+    };
+    let relative_physical_stats = match detail.relative_physical_stats {
+        Some(1) => stats.attack > stats.defence,
+        Some(0) => stats.attack == stats.defence,
+        Some(-1) => stats.attack < stats.defence,
+        _ => true,
+    };
+    let at_least =
+        |minimum: Option<i64>, value: i64| minimum.is_none_or(|minimum| value >= minimum);
+    let matches = |required: &Option<NamedApiResource>, actual: Option<&str>| {
+        name(required).is_none_or(|required| actual == Some(required))
+    };
+    let contains = |required: &Option<NamedApiResource>, actual: &[String]| {
+        name(required).is_none_or(|required| actual.iter().any(|name| name == required))
+    };
+    let time_of_day = match detail.time_of_day.as_deref() {
+        None | Some("") => true,
+        Some(time) => surroundings.time_of_day.as_deref() == Some(time),
+    };
+
+    gender
+        && relative_physical_stats
+        && time_of_day
+        && at_least(detail.min_level, pokemon.level)
+        && at_least(detail.min_happiness, pokemon.friendship)
+        && at_least(detail.min_beauty, surroundings.beauty)
+        && at_least(detail.min_affection, surroundings.affection)
+        && matches(&detail.held_item, pokemon.held_item.as_deref())
+        && matches(&detail.location, surroundings.location.as_deref())
+        && matches(&detail.trade_species, surroundings.trade_species.as_deref())
+        && contains(&detail.known_move, &pokemon.moves)
+        && contains(&detail.known_move_type, &surroundings.known_move_types)
+        && contains(&detail.party_species, &surroundings.party_species)
+        && contains(&detail.party_type, &surroundings.party_types)
+        && (!detail.needs_overworld_rain.unwrap_or_default() || surroundings.overworld_rain)
+        && (!detail.turn_upside_down.unwrap_or_default() || surroundings.upside_down)
+}
+
+/// Moves a Pokémon learns upon evolving into `evolved` in a version group, e.g. `sword-shield`:
+/// those learned by level-up at level 0, which marks evolution moves, or at its current level.
+pub fn evolution_moves(evolved: &Pokemon, level: i64, version_group: &str) -> Vec<String> {
+    evolved
+        .moves
+        .iter()
+        .flatten()
+        .filter(|move_| {
+            move_.version_group_details.iter().flatten().any(|detail| {
+                name(&detail.version_group) == Some(version_group)
+                    && name(&detail.move_learn_method) == Some("level-up")
+                    && matches!(detail.level_learned_at, Some(learned) if learned == 0 || learned == level)
+            })
+        })
+        .filter_map(|move_| name(&move_.move_).map(String::from))
+        .collect()
+}
+
+impl OwnedPokemon {
+    /// Evolves this Pokémon from its `current` form into `evolved`, keeping its nickname, level, IVs and EVs.
+    ///
+    /// The ability keeps its slot, a held item required by the evolution is consumed,
+    /// and evolution moves are learned while fewer than four moves are known.
+    pub fn evolve(
+        &mut self,
+        current: &Pokemon,
+        evolved: &Pokemon,
+        detail: &EvolutionDetail,
+        nature: Option<&Nature>,
+        version_group: &str,
+    ) -> Evolved {
+        let from = std::mem::replace(&mut self.pokemon, evolved.name.clone().unwrap_or_default());
+
+        let slot = current.abilities.iter().flatten().find(|ability| {
+            name(&ability.ability).is_some() && name(&ability.ability) == self.ability.as_deref()
+        });
+        let evolved_abilities = || evolved.abilities.iter().flatten();
+        let ability = slot
+            .and_then(|slot| {
+                evolved_abilities().find(|ability| {
+                    ability.slot == slot.slot && ability.is_hidden == slot.is_hidden
+                })
+            })
+            .or_else(|| evolved_abilities().find(|ability| ability.is_hidden != Some(true)));
+        if let Some(ability) = ability.and_then(|ability| name(&ability.ability)) {
+            self.ability = Some(ability.to_string());
+        }
+
+        if detail.held_item.is_some() {
+            self.held_item = None;
+        }
+
+        let (mut learned, mut pending) = (Vec::new(), Vec::new());
+        for move_ in evolution_moves(evolved, self.level, version_group) {
+            if self.moves.contains(&move_) {
+                continue;
+            }
+            if self.moves.len() < 4 {
+                self.moves.push(move_.clone());
+                learned.push(move_);
+            } else {
+                pending.push(move_);
+            }
+        }
+
+        Evolved {
+            from,
+            stats: self.stats(evolved, nature),
+            learned,
+            pending,
+        }
+    }
+}
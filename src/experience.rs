@@ -0,0 +1,231 @@
+//! Experience and levels
+//!
+//! Experience thresholds come from the `levels` of a [`GrowthRate`] when it has been fetched,
+//! or from the closed-form formula of its family otherwise.
+//! See [Bulbapedia](http://bulbapedia.bulbagarden.net/wiki/Experience) for greater detail.
+
+use crate::{generation::Generation, model::pokemon::GrowthRate, owned::OwnedPokemon};
+
+/// The highest level a Pokémon can reach.
+pub const MAX_LEVEL: i64 = 100;
+
+/// The families of growth rate formulas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GrowthFormula {
+    /// Also known as Erratic.
+    SlowThenVeryFast,
+    Fast,
+    /// Also known as Medium Fast.
+    Medium,
+    MediumSlow,
+    Slow,
+    /// Also known as Fluctuating.
+    FastThenVerySlow,
+}
+
+impl GrowthFormula {
+    /// Looks up a formula by the PokeAPI name of its growth rate, e.g. `medium-slow`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "slow-then-very-fast" => GrowthFormula::SlowThenVeryFast,
+            "fast" => GrowthFormula::Fast,
+            "medium" => GrowthFormula::Medium,
+            "medium-slow" => GrowthFormula::MediumSlow,
+            "slow" => GrowthFormula::Slow,
+            "fast-then-very-slow" => GrowthFormula::FastThenVerySlow,
+            _ => return None,
+        })
+    }
+
+    /// The formula family of a growth rate.
+    pub fn of(growth_rate: &GrowthRate) -> Option<Self> {
+        growth_rate.name.as_deref().and_then(Self::from_name)
+    }
+
+    /// The total experience needed to reach a level.
+    pub fn experience_for_level(self, level: i64) -> i64 {
+        let n = level.clamp(1, MAX_LEVEL);
+        if n == 1 {
+            return 0;
+        }
+        let cube = n * n * n;
+        match self {
+            GrowthFormula::SlowThenVeryFast => match n {
+                ..=49 => cube * (100 - n) / 50,
+                50..=67 => cube * (150 - n) / 100,
+                68..=97 => cube * ((1911 - 10 * n) / 3) / 500,
+                _ => cube * (160 - n) / 100,
+            },
+            GrowthFormula::Fast => 4 * cube / 5,
+            GrowthFormula::Medium => cube,
+            GrowthFormula::MediumSlow => 6 * cube / 5 - 15 * n * n + 100 * n - 140,
+            GrowthFormula::Slow => 5 * cube / 4,
+            GrowthFormula::FastThenVerySlow => match n {
+                ..=14 => cube * ((n + 1) / 3 + 24) / 50,
+                15..=35 => cube * (n + 14) / 50,
+                _ => cube * (n / 2 + 32) / 50,
+            },
+        }
+    }
+
+    /// The level reached with a total amount of experience.
+    pub fn level_for_experience(self, experience: i64) -> i64 {
+        (2..=MAX_LEVEL)
+            .take_while(|level| self.experience_for_level(*level) <= experience)
+            .last()
+            .unwrap_or(1)
+    }
+}
+
+/// The total experience needed to reach a level, using the fetched `levels` table of the growth rate
+/// and falling back to its formula. `None` if the growth rate has neither.
+pub fn experience_for_level(growth_rate: &GrowthRate, level: i64) -> Option<i64> {
+    let listed = growth_rate
+        .levels
+        .iter()
+        .flatten()
+        .find(|entry| entry.level == Some(level))
+        .and_then(|entry| entry.experience);
+    listed.or_else(|| {
+        GrowthFormula::of(growth_rate).map(|formula| formula.experience_for_level(level))
+    })
+}
+
+/// The level reached with a total amount of experience. `None` if the growth rate has neither
+/// a `levels` table nor a known formula.
+pub fn level_for_experience(growth_rate: &GrowthRate, experience: i64) -> Option<i64> {
+    experience_for_level(growth_rate, 1)?;
+    Some(
+        (2..=MAX_LEVEL)
+            .take_while(|level| {
+                experience_for_level(growth_rate, *level).is_some_and(|needed| needed <= experience)
+            })
+            .last()
+            .unwrap_or(1),
+    )
+}
+
+/// A Pokémon that has been defeated and yields experience.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Defeated {
+    /// The `base_experience` of the defeated [`Pokemon`](crate::model::pokemon::Pokemon).
+    pub base_experience: i64,
+    pub level: i64,
+    /// Whether it belonged to a trainer rather than being wild.
+    pub trainer: bool,
+}
+
+/// A Pokémon in the party that may receive experience from a defeated Pokémon.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Recipient {
+    pub level: i64,
+    /// Whether it battled the defeated Pokémon.
+    pub participated: bool,
+    /// Whether it benefits from an Exp. Share: the held item up to Generation V,
+    /// the key item switched on from Generation VI.
+    pub exp_share: bool,
+    /// Whether it was received in a trade.
+    pub traded: bool,
+    /// Whether it was received in a trade from a game of another language.
+    pub international: bool,
+    /// Whether it holds a Lucky Egg.
+    pub lucky_egg: bool,
+}
+
+/// The experience each recipient gains from a defeated Pokémon, in the same order as `recipients`.
+///
+/// Up to Generation V participants split the experience between them, and when someone holds an Exp. Share
+/// participants and holders split half of it each. From Generation VI every participant gains the full amount
+/// and the others gain half through the Exp. Share. Generations V and VII onward scale the experience by the
+/// difference in levels, and Generation VII onward no longer gives a bonus for trainers' Pokémon.
+pub fn experience_gain(
+    generation: Generation,
+    defeated: &Defeated,
+    recipients: &[Recipient],
+) -> Vec<i64> {
+    let participants = recipients
+        .iter()
+        .filter(|recipient| recipient.participated)
+        .count() as f64;
+    let holders = recipients
+        .iter()
+        .filter(|recipient| recipient.exp_share)
+        .count() as f64;
+    let splits = generation <= Generation::V;
+
+    recipients
+        .iter()
+        .map(|recipient| {
+            // The share of the experience the recipient gets before any bonus.
+            let share = if splits {
+                match (recipient.participated, recipient.exp_share, holders > 0.0) {
+                    (true, true, _) => 0.5 / participants + 0.5 / holders,
+                    (true, false, true) => 0.5 / participants,
+                    (true, false, false) => 1.0 / participants,
+                    (false, true, _) => 0.5 / holders,
+                    (false, false, _) => 0.0,
+                }
+            } else {
+                match (recipient.participated, recipient.exp_share) {
+                    (true, _) => 1.0,
+                    (false, true) => 0.5,
+                    (false, false) => 0.0,
+                }
+            };
+            if share == 0.0 {
+                return 0;
+            }
+            gain(generation, defeated, recipient, share)
+        })
+        .collect()
+}
+
+fn gain(generation: Generation, defeated: &Defeated, recipient: &Recipient, share: f64) -> i64 {
+    let trainer = if defeated.trainer && generation <= Generation::VI {
+        1.5
+    } else {
+        1.0
+    };
+    let traded = match (recipient.traded, recipient.international) {
+        (true, true) if generation >= Generation::IV => 1.7,
+        (true, _) => 1.5,
+        (false, _) => 1.0,
+    };
+    let lucky_egg = if recipient.lucky_egg { 1.5 } else { 1.0 };
+    let base = defeated.base_experience as f64 * defeated.level as f64;
+
+    let experience = if generation == Generation::V || generation >= Generation::VII {
+        let level = defeated.level as f64;
+        let scale = ((2.0 * level + 10.0) / (level + recipient.level as f64 + 10.0)).powf(2.5);
+        (trainer * base / 5.0 * share * scale + 1.0) * traded * lucky_egg
+    } else {
+        trainer * base / 7.0 * share * traded * lucky_egg
+    };
+    (experience as i64).max(1)
+}
+
+/// A change of level caused by gaining experience.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LevelUp {
+    pub from: i64,
+    pub to: i64,
+}
+
+impl OwnedPokemon {
+    /// Adds experience and raises the level to match, up to level 100.
+    /// Returns the change of level, if any. Levels do not change if the growth rate cannot be evaluated.
+    pub fn gain_experience(&mut self, amount: i64, growth_rate: &GrowthRate) -> Option<LevelUp> {
+        let cap = experience_for_level(growth_rate, MAX_LEVEL).unwrap_or(i64::MAX);
+        self.experience = (self.experience + amount).min(cap);
+        let level = level_for_experience(growth_rate, self.experience)?;
+        if level <= self.level {
+            return None;
+        }
+        let level_up = LevelUp {
+            from: self.level,
+            to: level,
+        };
+        self.level = level;
+        Some(level_up)
+    }
+}
@@ -0,0 +1,79 @@
+//! Game generations
+
+/// A generation is a grouping of the Pokémon games that separates them based on the Pokémon they include.
+/// Many battle mechanics, such as status damage or the duration of sleep, changed between generations.
+/// See [Bulbapedia](http://bulbapedia.bulbagarden.net/wiki/Generation) for greater detail.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Generation {
+    I,
+    II,
+    III,
+    IV,
+    V,
+    VI,
+    VII,
+    VIII,
+    #[default]
+    IX,
+}
+
+impl Generation {
+    /// Every generation, oldest first.
+    pub const ALL: [Generation; 9] = [
+        Generation::I,
+        Generation::II,
+        Generation::III,
+        Generation::IV,
+        Generation::V,
+        Generation::VI,
+        Generation::VII,
+        Generation::VIII,
+        Generation::IX,
+    ];
+
+    /// Looks up a generation by its PokeAPI resource name, e.g. `generation-iv`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|generation| generation.name() == name)
+    }
+
+    /// The generation of a version group by its PokeAPI name, e.g. `generation-viii` for `sword-shield`.
+    pub fn of_version_group(version_group: &str) -> Option<Self> {
+        Some(match version_group {
+            "red-blue" | "yellow" | "red-green-japan" | "blue-japan" => Generation::I,
+            "gold-silver" | "crystal" => Generation::II,
+            "ruby-sapphire" | "emerald" | "firered-leafgreen" | "colosseum" | "xd" => {
+                Generation::III
+            }
+            "diamond-pearl" | "platinum" | "heartgold-soulsilver" => Generation::IV,
+            "black-white" | "black-2-white-2" => Generation::V,
+            "x-y" | "omega-ruby-alpha-sapphire" => Generation::VI,
+            "sun-moon" | "ultra-sun-ultra-moon" | "lets-go-pikachu-lets-go-eevee" => {
+                Generation::VII
+            }
+            "sword-shield"
+            | "the-isle-of-armor"
+            | "the-crown-tundra"
+            | "brilliant-diamond-and-shining-pearl"
+            | "legends-arceus" => Generation::VIII,
+            "scarlet-violet" | "the-teal-mask" | "the-indigo-disk" => Generation::IX,
+            _ => return None,
+        })
+    }
+
+    /// The PokeAPI resource name of this generation.
+    pub fn name(self) -> &'static str {
+        match self {
+            Generation::I => "generation-i",
+            Generation::II => "generation-ii",
+            Generation::III => "generation-iii",
+            Generation::IV => "generation-iv",
+            Generation::V => "generation-v",
+            Generation::VI => "generation-vi",
+            Generation::VII => "generation-vii",
+            Generation::VIII => "generation-viii",
+            Generation::IX => "generation-ix",
+        }
+    }
+}
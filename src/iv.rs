@@ -0,0 +1,139 @@
+//! Working out individual values
+//!
+//! Narrows down the IVs of a Pokémon by running the [stat formulas](crate::stats::calculate)
+//! backwards from its observed stats, then with hints such as its characteristic or Hidden Power type.
+//! See [Bulbapedia](http://bulbapedia.bulbagarden.net/wiki/Individual_values) for greater detail.
+
+use crate::{
+    characteristic,
+    model::pokemon::{Characteristic, Nature, Pokemon},
+    stats::{self, PossibleIvs, Stats},
+};
+
+/// The types Hidden Power can have, in the order of its type formula.
+pub const HIDDEN_POWER_TYPES: [&str; 16] = [
+    "fighting", "flying", "poison", "ground", "rock", "bug", "ghost", "steel", "fire", "water",
+    "grass", "electric", "psychic", "ice", "dragon", "dark",
+];
+
+/// The order in which the lowest bit of each IV enters the Hidden Power type formula.
+const HIDDEN_POWER_ORDER: [&str; 6] = [
+    "hp",
+    "attack",
+    "defense",
+    "speed",
+    "special-attack",
+    "special-defense",
+];
+
+/// The stats of a Pokémon seen at some level, along with its EVs at the time.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Observation {
+    pub level: i64,
+    pub ev: Stats,
+    pub stats: Stats,
+}
+
+/// What else is known of a Pokémon besides its stats.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct Hints<'a> {
+    /// The characteristic shown in its summary.
+    pub characteristic: Option<&'a Characteristic>,
+    /// Its personality value, which settles ties between IVs for the characteristic.
+    pub personality: Option<u32>,
+    /// The PokeAPI name of the type of its Hidden Power, e.g. `ice`.
+    pub hidden_power: Option<&'a str>,
+}
+
+/// Works out the possible IVs of a Pokémon from observed stats and hints.
+/// An IV without any possible value means the observations contradict each other.
+pub fn solve(
+    pokemon: &Pokemon,
+    nature: Option<&Nature>,
+    observations: &[Observation],
+    hints: &Hints,
+) -> PossibleIvs {
+    let base = Stats::from_base_stats(pokemon.stats.as_deref().unwrap_or_default());
+    let mut possible = PossibleIvs::default();
+    for observation in observations {
+        narrow_by_stats(&mut possible, &base, nature, observation);
+    }
+    if let Some(characteristic) = hints.characteristic {
+        characteristic::narrow(&mut possible, characteristic, hints.personality);
+    }
+    if let Some(type_) = hints.hidden_power {
+        narrow_by_hidden_power(&mut possible, type_);
+    }
+    possible
+}
+
+/// Keeps the IVs that give the observed stats.
+pub fn narrow_by_stats(
+    possible: &mut PossibleIvs,
+    base: &Stats,
+    nature: Option<&Nature>,
+    observation: &Observation,
+) {
+    // Every stat with the same IV at once, so one calculation checks a value for all six.
+    let by_iv: Vec<Stats> = (0..=31)
+        .map(|iv| {
+            let iv = Stats {
+                hp: iv,
+                attack: iv,
+                defence: iv,
+                special_attack: iv,
+                special_defence: iv,
+                speed: iv,
+            };
+            stats::calculate(base, &iv, &observation.ev, observation.level, nature)
+        })
+        .collect();
+    for stat in Stats::NAMES {
+        let observed = observation.stats.get(stat);
+        possible.retain(stat, |iv| by_iv[*iv as usize].get(stat) == observed);
+    }
+}
+
+/// The type of Hidden Power from IVs, set by the lowest bit of each IV.
+pub fn hidden_power_type(iv: &Stats) -> &'static str {
+    let bits = HIDDEN_POWER_ORDER
+        .iter()
+        .enumerate()
+        .map(|(index, stat)| (iv.get(stat).unwrap_or_default() & 1) << index)
+        .sum();
+    hidden_power_type_of_bits(bits)
+}
+
+/// Keeps the IVs that can give Hidden Power the given type.
+pub fn narrow_by_hidden_power(possible: &mut PossibleIvs, type_: &str) {
+    let parities = |stat: &str| -> [bool; 2] {
+        let values = possible.get(stat).unwrap_or_default();
+        [
+            values.iter().any(|value| value % 2 == 0),
+            values.iter().any(|value| value % 2 == 1),
+        ]
+    };
+    let available: Vec<[bool; 2]> = HIDDEN_POWER_ORDER
+        .iter()
+        .map(|stat| parities(stat))
+        .collect();
+
+    // The parities each IV may have in some combination of bits giving the type.
+    let mut allowed = [[false; 2]; 6];
+    for bits in 0..64 {
+        let bit = |index: usize| ((bits >> index) & 1) as usize;
+        let feasible = (0..6).all(|index| available[index][bit(index)]);
+        if feasible && hidden_power_type_of_bits(bits) == type_ {
+            for (index, parities) in allowed.iter_mut().enumerate() {
+                parities[bit(index)] = true;
+            }
+        }
+    }
+    for (index, stat) in HIDDEN_POWER_ORDER.iter().enumerate() {
+        possible.retain(stat, |value| allowed[index][(value % 2) as usize]);
+    }
+}
+
+fn hidden_power_type_of_bits(bits: i64) -> &'static str {
+    HIDDEN_POWER_TYPES[(bits * 15 / 63) as usize]
+}
@@ -0,0 +1,366 @@
+//! Legality checks
+//!
+//! Checks that an [`OwnedPokemon`] could exist in a version group, reporting every rule it breaks.
+//! See [Bulbapedia](http://bulbapedia.bulbagarden.net/wiki/Legality) for greater detail.
+
+use std::fmt;
+
+use crate::{
+    experience,
+    generation::Generation,
+    model::{
+        evolution::{ChainLink, EvolutionChain},
+        pokemon::{GrowthRate, Pokemon, PokemonSpecies},
+        resource::name,
+    },
+    owned::{Gender, OwnedPokemon},
+    stats::Stats,
+};
+
+/// The highest value of an IV.
+pub const MAX_IV: i64 = 31;
+/// The highest value of an EV in a single stat from Generation VI. See [`max_ev`].
+pub const MAX_EV: i64 = 252;
+/// The highest total of the EVs, from Generation III.
+pub const MAX_EV_TOTAL: i64 = 510;
+/// The highest friendship.
+pub const MAX_FRIENDSHIP: i64 = 255;
+
+/// A rule broken by a Pokémon.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Violation {
+    /// The Pokémon data checked against is for another Pokémon.
+    WrongPokemon {
+        expected: String,
+        found: String,
+    },
+    LevelOutOfRange(i64),
+    /// The experience does not match the level for the growth rate.
+    ExperienceMismatch {
+        experience: i64,
+        level: i64,
+    },
+    FriendshipOutOfRange(i64),
+    /// The gender is impossible for the species.
+    ImpossibleGender(Gender),
+    /// The Pokémon has no ability.
+    MissingAbility,
+    /// The ability is not among the abilities of the Pokémon.
+    UnavailableAbility(String),
+    /// The ability is a hidden ability, which the checks were told to disallow.
+    HiddenAbility(String),
+    TooManyMoves(usize),
+    DuplicateMove(String),
+    /// The move cannot be learned in the version group by the Pokémon or its pre-evolutions.
+    UnlearnableMove(String),
+    /// The move is only learned by level-up at a level above that of the Pokémon.
+    MoveAboveLevel {
+        move_: String,
+        level: i64,
+    },
+    IvOutOfRange {
+        stat: &'static str,
+        value: i64,
+    },
+    EvOutOfRange {
+        stat: &'static str,
+        value: i64,
+        max: i64,
+    },
+    EvTotalTooHigh(i64),
+    /// The Pokémon is below the level its species is reached by evolving at.
+    BelowEvolutionLevel {
+        species: String,
+        min_level: i64,
+    },
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Violation::WrongPokemon { expected, found } => {
+                write!(f, "checked as {} but is {}", expected, found)
+            }
+            Violation::LevelOutOfRange(level) => {
+                write!(f, "level {} is not between 1 and 100", level)
+            }
+            Violation::ExperienceMismatch { experience, level } => write!(
+                f,
+                "{} experience points do not match level {}",
+                experience, level
+            ),
+            Violation::FriendshipOutOfRange(friendship) => write!(
+                f,
+                "friendship {} is not between 0 and {}",
+                friendship, MAX_FRIENDSHIP
+            ),
+            Violation::ImpossibleGender(gender) => {
+                write!(f, "the species cannot be {:?}", gender)
+            }
+            Violation::MissingAbility => write!(f, "no ability is set"),
+            Violation::UnavailableAbility(ability) => {
+                write!(f, "the Pokémon cannot have the ability {}", ability)
+            }
+            Violation::HiddenAbility(ability) => {
+                write!(f, "{} is a hidden ability, which is not allowed", ability)
+            }
+            Violation::TooManyMoves(count) => {
+                write!(f, "{} moves are known but at most 4 can be", count)
+            }
+            Violation::DuplicateMove(move_) => write!(f, "{} is known more than once", move_),
+            Violation::UnlearnableMove(move_) => {
+                write!(f, "{} cannot be learned in this version group", move_)
+            }
+            Violation::MoveAboveLevel { move_, level } => {
+                write!(f, "{} is only learned from level {}", move_, level)
+            }
+            Violation::IvOutOfRange { stat, value } => write!(
+                f,
+                "the {} IV {} is not between 0 and {}",
+                stat, value, MAX_IV
+            ),
+            Violation::EvOutOfRange { stat, value, max } => {
+                write!(f, "the {} EV {} is not between 0 and {}", stat, value, max)
+            }
+            Violation::EvTotalTooHigh(total) => {
+                write!(f, "the EVs total {}, above {}", total, MAX_EV_TOTAL)
+            }
+            Violation::BelowEvolutionLevel { species, min_level } => write!(
+                f,
+                "{} is reached by evolving at level {} at the earliest",
+                species, min_level
+            ),
+        }
+    }
+}
+
+/// The data a Pokémon is checked against. Checks whose data is missing are skipped.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rules<'a> {
+    /// The version group, e.g. `sword-shield`.
+    pub version_group: &'a str,
+    /// The generation of the version group, for the limits of EVs.
+    pub generation: Generation,
+    pub pokemon: &'a Pokemon,
+    /// The Pokémon it evolves from, whose moves it may have learned before evolving.
+    pub pre_evolutions: &'a [&'a Pokemon],
+    /// The species of the Pokémon, for its gender.
+    pub species: Option<&'a PokemonSpecies>,
+    /// The evolution chain of the species, for evolution levels.
+    pub chain: Option<&'a EvolutionChain>,
+    /// The growth rate of the species, for experience.
+    pub growth_rate: Option<&'a GrowthRate>,
+    /// Whether hidden abilities are allowed, as they are not in every game or format.
+    pub hidden_abilities: bool,
+}
+
+impl<'a> Rules<'a> {
+    /// Rules for a Pokémon in a version group, allowing hidden abilities. The generation is that of
+    /// the version group, or the latest one for a version group it does not know.
+    pub fn new(version_group: &'a str, pokemon: &'a Pokemon) -> Self {
+        Self {
+            version_group,
+            generation: Generation::of_version_group(version_group).unwrap_or_default(),
+            pokemon,
+            pre_evolutions: &[],
+            species: None,
+            chain: None,
+            growth_rate: None,
+            hidden_abilities: true,
+        }
+    }
+}
+
+/// Checks a Pokémon, returning every rule it breaks. An empty list means it is legal.
+pub fn validate(owned: &OwnedPokemon, rules: &Rules) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    if let Some(expected) = rules.pokemon.name.as_deref() {
+        if expected != owned.pokemon {
+            violations.push(Violation::WrongPokemon {
+                expected: expected.to_string(),
+                found: owned.pokemon.clone(),
+            });
+        }
+    }
+    check_level(owned, rules, &mut violations);
+    check_gender(owned, rules, &mut violations);
+    check_ability(owned, rules, &mut violations);
+    check_moves(owned, rules, &mut violations);
+    check_values(owned, rules, &mut violations);
+    violations
+}
+
+impl OwnedPokemon {
+    /// Checks this Pokémon, returning every rule it breaks. See [`validate`].
+    pub fn validate(&self, rules: &Rules) -> Vec<Violation> {
+        validate(self, rules)
+    }
+}
+
+fn check_level(owned: &OwnedPokemon, rules: &Rules, violations: &mut Vec<Violation>) {
+    if !(1..=experience::MAX_LEVEL).contains(&owned.level) {
+        violations.push(Violation::LevelOutOfRange(owned.level));
+    }
+    if let Some(growth_rate) = rules.growth_rate {
+        let level = experience::level_for_experience(growth_rate, owned.experience);
+        if level.is_some_and(|level| level != owned.level) {
+            violations.push(Violation::ExperienceMismatch {
+                experience: owned.experience,
+                level: owned.level,
+            });
+        }
+    }
+    if !(0..=MAX_FRIENDSHIP).contains(&owned.friendship) {
+        violations.push(Violation::FriendshipOutOfRange(owned.friendship));
+    }
+
+    // Every evolution by level-up on the way to the species sets a lowest level.
+    let species = rules
+        .species
+        .and_then(|species| species.name.as_deref())
+        .or(name(&rules.pokemon.species));
+    let (Some(chain), Some(species)) =
+        (rules.chain.and_then(|chain| chain.chain.as_ref()), species)
+    else {
+        return;
+    };
+    let mut path = Vec::new();
+    if !path_to(chain, species, &mut path) {
+        return;
+    }
+    for link in path {
+        let details: Vec<_> = link.evolution_details.iter().flatten().collect();
+        let min_level = details
+            .iter()
+            .map(|detail| detail.min_level)
+            .collect::<Option<Vec<_>>>()
+            .and_then(|levels| levels.into_iter().min());
+        if let Some(min_level) = min_level.filter(|min_level| owned.level < *min_level) {
+            violations.push(Violation::BelowEvolutionLevel {
+                species: name(&link.species).unwrap_or_default().to_string(),
+                min_level,
+            });
+        }
+    }
+}
+
+fn check_gender(owned: &OwnedPokemon, rules: &Rules, violations: &mut Vec<Violation>) {
+    let Some(rate) = rules.species.and_then(|species| species.gender_rate) else {
+        return;
+    };
+    let possible = match owned.gender {
+        Gender::Genderless => rate < 0,
+        Gender::Male => (0..8).contains(&rate),
+        Gender::Female => (1..=8).contains(&rate),
+    };
+    if !possible {
+        violations.push(Violation::ImpossibleGender(owned.gender));
+    }
+}
+
+fn check_ability(owned: &OwnedPokemon, rules: &Rules, violations: &mut Vec<Violation>) {
+    let Some(ability) = owned.ability.as_deref() else {
+        violations.push(Violation::MissingAbility);
+        return;
+    };
+    let found = rules
+        .pokemon
+        .abilities
+        .iter()
+        .flatten()
+        .find(|candidate| name(&candidate.ability) == Some(ability));
+    match found {
+        None => violations.push(Violation::UnavailableAbility(ability.to_string())),
+        Some(found) if found.is_hidden == Some(true) && !rules.hidden_abilities => {
+            violations.push(Violation::HiddenAbility(ability.to_string()))
+        }
+        Some(_) => {}
+    }
+}
+
+fn check_moves(owned: &OwnedPokemon, rules: &Rules, violations: &mut Vec<Violation>) {
+    if owned.moves.len() > 4 {
+        violations.push(Violation::TooManyMoves(owned.moves.len()));
+    }
+    for (index, move_) in owned.moves.iter().enumerate() {
+        if owned.moves[..index].contains(move_) {
+            violations.push(Violation::DuplicateMove(move_.clone()));
+            continue;
+        }
+        let learnsets = std::iter::once(rules.pokemon).chain(rules.pre_evolutions.iter().copied());
+        let details: Vec<_> = learnsets
+            .flat_map(|pokemon| pokemon.moves.iter().flatten())
+            .filter(|learnable| name(&learnable.move_) == Some(move_.as_str()))
+            .flat_map(|learnable| learnable.version_group_details.iter().flatten())
+            .filter(|detail| name(&detail.version_group) == Some(rules.version_group))
+            .collect();
+        if details.is_empty() {
+            violations.push(Violation::UnlearnableMove(move_.clone()));
+            continue;
+        }
+        let lowest_level = details
+            .iter()
+            .map(|detail| match name(&detail.move_learn_method) {
+                Some("level-up") => detail.level_learned_at.unwrap_or_default(),
+                _ => 0,
+            })
+            .min()
+            .unwrap_or_default();
+        if lowest_level > owned.level {
+            violations.push(Violation::MoveAboveLevel {
+                move_: move_.clone(),
+                level: lowest_level,
+            });
+        }
+    }
+}
+
+/// The highest value of an EV in a single stat in a generation: 255 until Generation V and 252 since.
+/// Generations I and II have stat experience instead, up to 65535 in each stat.
+/// See [Bulbapedia](http://bulbapedia.bulbagarden.net/wiki/Effort_values) for greater detail.
+pub fn max_ev(generation: Generation) -> i64 {
+    match generation {
+        Generation::I | Generation::II => 65535,
+        Generation::III | Generation::IV | Generation::V => 255,
+        _ => MAX_EV,
+    }
+}
+
+fn check_values(owned: &OwnedPokemon, rules: &Rules, violations: &mut Vec<Violation>) {
+    let max = max_ev(rules.generation);
+    let mut total = 0;
+    for stat in Stats::NAMES {
+        let iv = owned.iv.get(stat).unwrap_or_default();
+        if !(0..=MAX_IV).contains(&iv) {
+            violations.push(Violation::IvOutOfRange { stat, value: iv });
+        }
+        let ev = owned.ev.get(stat).unwrap_or_default();
+        if !(0..=max).contains(&ev) {
+            violations.push(Violation::EvOutOfRange {
+                stat,
+                value: ev,
+                max,
+            });
+        }
+        total += ev;
+    }
+    // Stat experience has no limit on its total.
+    if total > MAX_EV_TOTAL && rules.generation >= Generation::III {
+        violations.push(Violation::EvTotalTooHigh(total));
+    }
+}
+
+/// Collects the links from the base of a chain down to a species, without the base itself.
+fn path_to<'a>(link: &'a ChainLink, species: &str, path: &mut Vec<&'a ChainLink>) -> bool {
+    if name(&link.species) == Some(species) {
+        return true;
+    }
+    for next in link.evolves_to.iter().flatten() {
+        path.push(next);
+        if path_to(next, species, path) {
+            return true;
+        }
+        path.pop();
+    }
+    false
+}
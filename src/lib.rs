@@ -1,7 +1,32 @@
+pub mod evolution;
+pub mod locations;
 pub mod moves;
 pub mod pokemon;
 
 mod endpoint;
 use endpoint::endpoint;
+pub mod error;
 
-pub mod model;
\ No newline at end of file
+pub mod model;
+
+pub mod battle;
+pub mod breeding;
+pub mod bundle;
+pub mod capture;
+pub mod characteristic;
+pub mod coverage;
+pub mod domain;
+pub mod encounter;
+pub mod evolve;
+pub mod experience;
+pub mod generation;
+pub mod iv;
+pub mod legality;
+pub mod names;
+pub mod owned;
+pub mod policy;
+pub mod save;
+pub mod showdown;
+pub mod source;
+pub mod stats;
+pub mod type_chart;
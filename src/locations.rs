@@ -0,0 +1,7 @@
+//! Locations endpoints group
+
+/// Location areas are sections of areas, such as floors in a building or cave.
+/// Each area has its own set of possible Pokémon encounters.
+pub mod location_area {
+    crate::endpoint!(crate::model::location::LocationArea; for "location-area");
+}
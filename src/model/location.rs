@@ -0,0 +1,49 @@
+//! Locations group models
+
+use super::resource::{Name, NamedApiResource, VersionEncounterDetail};
+
+/// [LocationArea official documentation](https://pokeapi.co/docs/v2#locationarea)
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct LocationArea {
+    /// The identifier for this resource.
+    pub id: Option<i64>,
+    /// The name for this resource.
+    pub name: Option<String>,
+    /// The internal id of an API resource within game data.
+    pub game_index: Option<i64>,
+    /// A list of methods in which Pokémon may be encountered in this area and how likely the method will occur depending on the version of the game.
+    pub encounter_method_rates: Option<Vec<EncounterMethodRate>>,
+    /// The region this location area can be found in.
+    pub location: Option<NamedApiResource>,
+    /// The name of this resource listed in different languages.
+    pub names: Option<Vec<Name>>,
+    /// A list of Pokémon that can be encountered in this area along with version specific details about the encounter.
+    pub pokemon_encounters: Option<Vec<PokemonEncounter>>,
+}
+
+/// [EncounterMethodRate official documentation](https://pokeapi.co/docs/v2#encountermethodrate)
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct EncounterMethodRate {
+    /// The method in which Pokémon may be encountered in an area.
+    pub encounter_method: Option<NamedApiResource>,
+    /// The chance of the encounter to occur on a version of the game.
+    pub version_details: Option<Vec<EncounterVersionDetails>>,
+}
+
+/// [EncounterVersionDetails official documentation](https://pokeapi.co/docs/v2#encounterversiondetails)
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct EncounterVersionDetails {
+    /// The chance of an encounter to occur.
+    pub rate: Option<i64>,
+    /// The version of the game in which the encounter can occur with the given chance.
+    pub version: Option<NamedApiResource>,
+}
+
+/// [PokemonEncounter official documentation](https://pokeapi.co/docs/v2#pokemonencounter)
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct PokemonEncounter {
+    /// The Pokémon being encountered.
+    pub pokemon: Option<NamedApiResource>,
+    /// A list of versions and encounters with Pokémon that might happen in the referenced location area.
+    pub version_details: Option<Vec<VersionEncounterDetail>>,
+}
@@ -1,3 +1,15 @@
+pub mod evolution;
+pub mod location;
 pub mod moves;
 pub mod pokemon;
-pub mod resource;
\ No newline at end of file
+pub mod resource;
+pub mod strict;
+
+use serde::Serialize;
+
+/// Serializes a model to canonical JSON: compact, with the keys of every object sorted and every field
+/// present, `null` when `None`. Equal models always give the same text, e.g. to store or compare it.
+pub fn to_canonical_json<T: Serialize>(model: &T) -> Result<String, serde_json::Error> {
+    // Objects of `Value` keep their keys sorted.
+    serde_json::to_string(&serde_json::to_value(model)?)
+}
@@ -1,13 +1,14 @@
 //! Moves group models
 
 use super::{
+    pokemon::AbilityEffectChange,
     resource::{
         ApiResource, Description, MachineVersionDetail, Name, NamedApiResource, VerboseEffect,
     },
 };
 
 /// [Move official documentation](https://pokeapi.co/docs/v2#move)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct Move {
     /// The identifier for this resource.
     pub id: Option<i64>,
@@ -24,6 +25,8 @@ pub struct Move {
     pub priority: Option<i64>,
     /// The base power of this move with a value of 0 if it does not have a base power.
     pub power: Option<i64>,
+    /// A detail of normal and super contest combos that require this move.
+    pub contest_combos: Option<ContestComboSets>,
     /// The type of appeal this move gives a Pokémon when used in a contest.
     pub contest_type: Option<NamedApiResource>,
     /// The effect the move has when used in a contest.
@@ -32,6 +35,8 @@ pub struct Move {
     pub damage_class: Option<NamedApiResource>,
     /// The effect of this move listed in different languages.
     pub effect_entries: Option<Vec<VerboseEffect>>,
+    /// The list of previous effects this move has had across version groups of the games.
+    pub effect_changes: Option<Vec<AbilityEffectChange>>,
     /// List of Pokemon that can learn the move
     pub learned_by_pokemon: Option<Vec<NamedApiResource>>,
     /// The flavor text of this move listed in different languages.
@@ -44,6 +49,8 @@ pub struct Move {
     pub meta: Option<MoveMetaData>,
     /// The name of this resource listed in different languages.
     pub names: Option<Vec<Name>>,
+    /// A list of move resource value changes across version groups of the game.
+    pub past_values: Option<Vec<PastMoveStatValues>>,
     /// A list of stats this moves effects and how much it effects them.
     pub stat_changes: Option<Vec<MoveStatChange>>,
     /// The effect the move has when used in a super contest.
@@ -55,8 +62,27 @@ pub struct Move {
     pub type_: Option<NamedApiResource>,
 }
 
+/// [ContestComboSets official documentation](https://pokeapi.co/docs/v2#contestcombosets)
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct ContestComboSets {
+    /// A detail of moves this move can be used before or after, granting additional appeal points in contests.
+    pub normal: Option<ContestComboDetail>,
+    /// A detail of moves this move can be used before or after, granting additional appeal points in super contests.
+    #[serde(rename = "super")]
+    pub super_: Option<ContestComboDetail>,
+}
+
+/// [ContestComboDetail official documentation](https://pokeapi.co/docs/v2#contestcombodetail)
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct ContestComboDetail {
+    /// A list of moves to use before this move.
+    pub use_before: Option<Vec<NamedApiResource>>,
+    /// A list of moves to use after this move.
+    pub use_after: Option<Vec<NamedApiResource>>,
+}
+
 /// [MoveFlavorText official documentation](https://pokeapi.co/docs/v2#moveflavortext)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct MoveFlavorText {
     /// The localized flavor text for an api resource in a specific language.
     pub flavor_text: Option<String>,
@@ -67,7 +93,7 @@ pub struct MoveFlavorText {
 }
 
 /// [MoveMetaData official documentation](https://pokeapi.co/docs/v2#movemetadata)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct MoveMetaData {
     /// The status ailment this move inflicts on its target.
     pub ailment: Option<NamedApiResource>,
@@ -96,7 +122,7 @@ pub struct MoveMetaData {
 }
 
 /// [MoveStatChange official documentation](https://pokeapi.co/docs/v2#movestatchange)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct MoveStatChange {
     /// The amount of change.
     pub change: Option<i64>,
@@ -104,8 +130,28 @@ pub struct MoveStatChange {
     pub stat: Option<NamedApiResource>,
 }
 
+/// [PastMoveStatValues official documentation](https://pokeapi.co/docs/v2#pastmovestatvalues)
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct PastMoveStatValues {
+    /// The percent value of how likely this move is to be successful.
+    pub accuracy: Option<i64>,
+    /// The percent value of how likely it is this moves effect will take effect.
+    pub effect_chance: Option<i64>,
+    /// The base power of this move with a value of 0 if it does not have a base power.
+    pub power: Option<i64>,
+    /// Power points. The number of times this move can be used.
+    pub pp: Option<i64>,
+    /// The effect of this move listed in different languages.
+    pub effect_entries: Option<Vec<VerboseEffect>>,
+    /// The elemental type of this move.
+    #[serde(rename = "type")]
+    pub type_: Option<NamedApiResource>,
+    /// The version group in which these move stat values were in effect.
+    pub version_group: Option<NamedApiResource>,
+}
+
 /// [MoveAilment official documentation](https://pokeapi.co/docs/v2#moveailment)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct MoveAilment {
     /// The identifier for this resource.
     pub id: Option<i64>,
@@ -118,7 +164,7 @@ pub struct MoveAilment {
 }
 
 /// [MoveDamageClass official documentation](https://pokeapi.co/docs/v2#movedamageclass)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct MoveDamageClass {
     /// The identifier for this resource.
     pub id: Option<i64>,
@@ -133,7 +179,7 @@ pub struct MoveDamageClass {
 }
 
 /// [MoveLearnMethod official documentation](https://pokeapi.co/docs/v2#movelearnmethod)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct MoveLearnMethod {
     /// The identifier for this resource.
     pub id: Option<i64>,
@@ -148,7 +194,7 @@ pub struct MoveLearnMethod {
 }
 
 /// [MoveTarget official documentation](https://pokeapi.co/docs/v2#movetarget)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct MoveTarget {
     /// The identifier for this resource.
     pub id: Option<i64>,
@@ -157,7 +203,7 @@ pub struct MoveTarget {
     /// The description of this resource listed in different languages.
     pub descriptions: Option<Vec<Description>>,
     /// A list of moves that that are directed at this target.
-    pub moves: Option<Vec<Name>>,
+    pub moves: Option<Vec<NamedApiResource>>,
     /// The name of this resource listed in different languages.ƒ
     pub names: Option<Vec<Name>>,
-}
\ No newline at end of file
+}
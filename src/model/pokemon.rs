@@ -1,31 +1,44 @@
 //! Pokemon group models
 
 use super::resource::{
-    ApiResource, Description, Name, NamedApiResource,
-    VerboseEffect,
+    ApiResource, Description, Effect, FlavorText, GenerationGameIndex, Name, NamedApiResource,
+    VerboseEffect, VersionGameIndex,
 };
 
 /// [Ability official documentation](https://pokeapi.co/docs/v2#ability)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct Ability {
     /// The identifier for this resource.
     pub id: Option<i64>,
     /// The name for this resource.
     pub name: Option<String>,
+    /// Whether or not this ability originated in the main series of the video games.
+    pub is_main_series: Option<bool>,
     /// The generation this ability originated in.
     pub generation: Option<NamedApiResource>,
     /// The name of this resource listed in different languages.
     pub names: Option<Vec<Name>>,
     /// The effect of this ability listed in different languages.
     pub effect_entries: Option<Vec<VerboseEffect>>,
+    /// The list of previous effects this ability has had across version groups.
+    pub effect_changes: Option<Vec<AbilityEffectChange>>,
     /// The flavor text of this ability listed in different languages.
     pub flavor_text_entries: Option<Vec<AbilityFlavorText>>,
     /// A list of Pokémon that could potentially have this ability.
     pub pokemon: Option<Vec<AbilityPokemon>>,
 }
 
+/// [AbilityEffectChange official documentation](https://pokeapi.co/docs/v2#abilityeffectchange)
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct AbilityEffectChange {
+    /// The previous effect of this ability listed in different languages.
+    pub effect_entries: Option<Vec<Effect>>,
+    /// The version group in which the previous effect of this ability originated.
+    pub version_group: Option<NamedApiResource>,
+}
+
 /// [AbilityFlavorText official documentation](https://pokeapi.co/docs/v2#abilityflavortext)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct AbilityFlavorText {
     /// The localized name for an API resource in a specific language.
     pub flavor_text: Option<String>,
@@ -36,7 +49,7 @@ pub struct AbilityFlavorText {
 }
 
 /// [AbilityPokemon official documentation](https://pokeapi.co/docs/v2#abilitypokemon)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct AbilityPokemon {
     /// Whether or not this a hidden ability for the referenced Pokémon.
     pub is_hidden: Option<bool>,
@@ -48,7 +61,7 @@ pub struct AbilityPokemon {
 }
 
 /// [Characteristic official documentation](https://pokeapi.co/docs/v2#characteristic)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct Characteristic {
     /// The identifier for this resource.
     pub id: Option<i64>,
@@ -64,7 +77,7 @@ pub struct Characteristic {
 }
 
 /// [Gender official documentation](https://pokeapi.co/docs/v2#gender)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct Gender {
     /// The identifier for this resource.
     pub id: Option<i64>,
@@ -77,7 +90,7 @@ pub struct Gender {
 }
 
 /// [PokemonSpeciesGender official documentation](https://pokeapi.co/docs/v2#pokemonspeciesgender)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct PokemonSpeciesGender {
     /// The chance of this Pokémon being female, in eighths; or -1 for genderless.
     pub rate: Option<i64>,
@@ -86,7 +99,7 @@ pub struct PokemonSpeciesGender {
 }
 
 /// [GrowthRate official documentation](https://pokeapi.co/docs/v2#growthrate)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct GrowthRate {
     /// The identifier for this resource.
     pub id: Option<i64>,
@@ -103,7 +116,7 @@ pub struct GrowthRate {
 }
 
 /// [GrowthRateExperienceLevel official documentation](https://pokeapi.co/docs/v2#growthrateexperiencelevel)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct GrowthRateExperienceLevel {
     /// The level gained.
     pub level: Option<i64>,
@@ -112,7 +125,7 @@ pub struct GrowthRateExperienceLevel {
 }
 
 /// [Nature official documentation](https://pokeapi.co/docs/v2#nature)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct Nature {
     /// The identifier for this resource.
     pub id: Option<i64>,
@@ -126,12 +139,36 @@ pub struct Nature {
     pub hates_flavor: Option<NamedApiResource>,
     /// The flavor liked by Pokémon with this nature.
     pub likes_flavor: Option<NamedApiResource>,
+    /// A list of Pokéathlon stats this nature effects and how much it effects them.
+    pub pokeathlon_stat_changes: Option<Vec<NatureStatChange>>,
+    /// A list of battle styles and how likely a Pokémon with this nature is to use them in the Battle Palace or Battle Tent.
+    pub move_battle_style_preferences: Option<Vec<MoveBattleStylePreference>>,
     /// The name of this resource listed in different languages.
     pub names: Option<Vec<Name>>,
 }
 
+/// [NatureStatChange official documentation](https://pokeapi.co/docs/v2#naturestatchange)
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct NatureStatChange {
+    /// The amount of change.
+    pub max_change: Option<i64>,
+    /// The stat being affected.
+    pub pokeathlon_stat: Option<NamedApiResource>,
+}
+
+/// [MoveBattleStylePreference official documentation](https://pokeapi.co/docs/v2#movebattlestylepreference)
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct MoveBattleStylePreference {
+    /// Chance of using the move, in percent, if HP is under one half.
+    pub low_hp_preference: Option<i64>,
+    /// Chance of using the move, in percent, if HP is over one half.
+    pub high_hp_preference: Option<i64>,
+    /// The move battle style.
+    pub move_battle_style: Option<NamedApiResource>,
+}
+
 /// [Pokemon official documentation](https://pokeapi.co/docs/v2#pokemon)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct Pokemon {
     /// The identifier for this resource.
     pub id: Option<i64>,
@@ -139,15 +176,35 @@ pub struct Pokemon {
     pub name: Option<String>,
     /// The base experience gained for defeating this Pokémon.
     pub base_experience: Option<i64>,
+    /// The height of this Pokémon in decimetres.
+    pub height: Option<i64>,
+    /// Set for exactly one Pokémon used as the default for each species.
+    pub is_default: Option<bool>,
+    /// Order for sorting. Almost national order, except families are grouped together.
+    pub order: Option<i64>,
+    /// The weight of this Pokémon in hectograms.
+    pub weight: Option<i64>,
     /// A list of abilities this Pokémon could potentially have.
     pub abilities: Option<Vec<PokemonAbility>>,
+    /// A list of forms this Pokémon can take on.
+    pub forms: Option<Vec<NamedApiResource>>,
+    /// A list of game indices relevent to Pokémon item by generation.
+    pub game_indices: Option<Vec<VersionGameIndex>>,
     /// A list of items this Pokémon may be holding when encountered.
     pub held_items: Option<Vec<PokemonHeldItem>>,
+    /// A link to a list of location areas, as well as encounter details pertaining to specific versions.
+    pub location_area_encounters: Option<String>,
     /// A list of moves along with learn methods and level details pertaining to specific version groups.
     pub moves: Option<Vec<PokemonMove>>,
+    /// A list of details showing types this Pokémon had in previous generations.
+    pub past_types: Option<Vec<PokemonTypePast>>,
+    /// A list of details showing abilities this Pokémon had in previous generations.
+    pub past_abilities: Option<Vec<PokemonAbilityPast>>,
     /// A set of sprites used to depict this Pokémon in the game.
     /// A visual representation of the various sprites can be found at [PokeAPI/sprites](https://github.com/PokeAPI/sprites).
     pub sprites: Option<PokemonSprites>,
+    /// A set of cries used to depict this Pokémon in the game.
+    pub cries: Option<PokemonCries>,
     /// The species this Pokémon belongs to.
     pub species: Option<NamedApiResource>,
     /// A list of base stat values for this Pokémon.
@@ -157,7 +214,7 @@ pub struct Pokemon {
 }
 
 /// [PokemonAbility official documentation](https://pokeapi.co/docs/v2#pokemonability)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct PokemonAbility {
     /// Whether or not this is a hidden ability.
     pub is_hidden: Option<bool>,
@@ -168,7 +225,7 @@ pub struct PokemonAbility {
 }
 
 /// [PokemonType official documentation](https://pokeapi.co/docs/v2#pokemontype)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct PokemonType {
     /// The order the Pokémon's types are listed in.
     pub slot: Option<i64>,
@@ -177,23 +234,66 @@ pub struct PokemonType {
     pub type_: Option<NamedApiResource>,
 }
 
+/// [PokemonTypePast official documentation](https://pokeapi.co/docs/v2#pokemontypepast)
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct PokemonTypePast {
+    /// The last generation in which the referenced Pokémon had the listed types.
+    pub generation: Option<NamedApiResource>,
+    /// The types the referenced Pokémon had up to and including the listed generation.
+    pub types: Option<Vec<PokemonType>>,
+}
+
+/// [PokemonAbilityPast official documentation](https://pokeapi.co/docs/v2#pokemonabilitypast)
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct PokemonAbilityPast {
+    /// The last generation in which the referenced Pokémon had the listed abilities.
+    pub generation: Option<NamedApiResource>,
+    /// The abilities the referenced Pokémon had up to and including the listed generation.
+    /// An ability is `None` where the slot was empty.
+    pub abilities: Option<Vec<PokemonAbility>>,
+}
+
 /// [PokemonHeldItem official documentation](https://pokeapi.co/docs/v2#pokemonhelditem)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct PokemonHeldItem {
     /// The item the referenced Pokémon holds.
     pub item: Option<NamedApiResource>,
+    /// The details of the different versions in which the item is held.
+    pub version_details: Option<Vec<PokemonHeldItemVersion>>,
+}
+
+/// [PokemonHeldItemVersion official documentation](https://pokeapi.co/docs/v2#pokemonhelditemversion)
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct PokemonHeldItemVersion {
+    /// The version in which the item is held.
+    pub version: Option<NamedApiResource>,
+    /// How often the item is held.
+    pub rarity: Option<i64>,
 }
 
 /// [PokemonMove official documentation](https://pokeapi.co/docs/v2#pokemonmove)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct PokemonMove {
     /// The move the Pokémon can learn.
     #[serde(rename = "move")]
     pub move_: Option<NamedApiResource>,
+    /// The details of the version in which the Pokémon can learn the move.
+    pub version_group_details: Option<Vec<PokemonMoveVersion>>,
+}
+
+/// [PokemonMoveVersion official documentation](https://pokeapi.co/docs/v2#pokemonmoveversion)
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct PokemonMoveVersion {
+    /// The method by which the move is learned.
+    pub move_learn_method: Option<NamedApiResource>,
+    /// The version group in which the move is learned.
+    pub version_group: Option<NamedApiResource>,
+    /// The minimum level to learn the move.
+    pub level_learned_at: Option<i64>,
 }
 
 /// [PokemonStat official documentation](https://pokeapi.co/docs/v2#pokemonstat)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct PokemonStat {
     /// The stat the Pokémon has.
     pub stat: Option<NamedApiResource>,
@@ -204,7 +304,7 @@ pub struct PokemonStat {
 }
 
 /// [PokemonSprites official documentation](https://pokeapi.co/docs/v2#pokemonsprites)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct PokemonSprites {
     /// The default depiction of this Pokémon from the front in battle.
     pub front_default: Option<String>,
@@ -222,10 +322,23 @@ pub struct PokemonSprites {
     pub back_female: Option<String>,
     /// The shiny female depiction of this Pokémon from the back in battle.
     pub back_shiny_female: Option<String>,
+    /// Artwork and models outside of the games' battle sprites, e.g. `official-artwork`.
+    pub other: Option<::serde_json::Value>,
+    /// The sprites of each version, by generation.
+    pub versions: Option<::serde_json::Value>,
+}
+
+/// [PokemonCries official documentation](https://pokeapi.co/docs/v2#pokemoncries)
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct PokemonCries {
+    /// The latest depiction of this Pokémon's cry.
+    pub latest: Option<String>,
+    /// The legacy depiction of this Pokémon's cry.
+    pub legacy: Option<String>,
 }
 
 /// [PokemonSpecies official documentation](https://pokeapi.co/docs/v2#pokemonspecies)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct PokemonSpecies {
     /// The identifier for this resource.
     pub id: Option<i64>,
@@ -239,7 +352,7 @@ pub struct PokemonSpecies {
     /// The base capture rate; up to 255. The higher the number, the easier the catch.
     pub capture_rate: Option<i64>,
     /// The happiness when caught by a normal Pokéball; up to 255. The higher the number, the happier the Pokémon.
-    pub base_hapiness: Option<i64>,
+    pub base_happiness: Option<i64>,
     /// Whether or not this is a baby Pokémon.
     pub is_baby: Option<bool>,
     /// Whether or not this is a legendary Pokémon.
@@ -255,6 +368,8 @@ pub struct PokemonSpecies {
     pub forms_switchable: Option<bool>,
     /// The rate at which this Pokémon species gains levels.
     pub growth_rate: Option<NamedApiResource>,
+    /// A list of Pokedexes and the indexes reserved within them for this Pokémon species.
+    pub pokedex_numbers: Option<Vec<PokemonSpeciesDexEntry>>,
     /// A list of egg groups this Pokémon species is a member of.
     pub egg_groups: Option<Vec<NamedApiResource>>,
     /// The color of this Pokémon for Pokédex search.
@@ -271,12 +386,58 @@ pub struct PokemonSpecies {
     pub generation: Option<NamedApiResource>,
     /// The name of this resource listed in different languages.
     pub names: Option<Vec<Name>>,
+    /// A list of encounters that can be had with this Pokémon species in pal park.
+    pub pal_park_encounters: Option<Vec<PalParkEncounterArea>>,
+    /// A list of flavor text entries for this Pokémon species.
+    pub flavor_text_entries: Option<Vec<FlavorText>>,
     /// Descriptions of different forms Pokémon take on within the Pokémon species.
     pub form_descriptions: Option<Vec<Description>>,
+    /// The genus of this Pokémon species listed in multiple languages.
+    pub genera: Option<Vec<Genus>>,
+    /// A list of the Pokémon that exist within this Pokémon species.
+    pub varieties: Option<Vec<PokemonSpeciesVariety>>,
+}
+
+/// [Genus official documentation](https://pokeapi.co/docs/v2#genus)
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct Genus {
+    /// The localized genus for the referenced Pokémon species.
+    pub genus: Option<String>,
+    /// The language this genus is in.
+    pub language: Option<NamedApiResource>,
+}
+
+/// [PokemonSpeciesDexEntry official documentation](https://pokeapi.co/docs/v2#pokemonspeciesdexentry)
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct PokemonSpeciesDexEntry {
+    /// The index number within the Pokédex.
+    pub entry_number: Option<i64>,
+    /// The Pokédex the referenced Pokémon species can be found in.
+    pub pokedex: Option<NamedApiResource>,
+}
+
+/// [PalParkEncounterArea official documentation](https://pokeapi.co/docs/v2#palparkencounterarea)
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct PalParkEncounterArea {
+    /// The base score given to the player when the referenced Pokémon is caught during a pal park run.
+    pub base_score: Option<i64>,
+    /// The base rate for encountering the referenced Pokémon in this pal park area.
+    pub rate: Option<i64>,
+    /// The pal park area where this encounter happens.
+    pub area: Option<NamedApiResource>,
+}
+
+/// [PokemonSpeciesVariety official documentation](https://pokeapi.co/docs/v2#pokemonspeciesvariety)
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct PokemonSpeciesVariety {
+    /// Whether this variety is the default variety.
+    pub is_default: Option<bool>,
+    /// The Pokémon variety.
+    pub pokemon: Option<NamedApiResource>,
 }
 
 /// [Stat official documentation](https://pokeapi.co/docs/v2#stat)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct Stat {
     /// The identifier for this resource.
     pub id: Option<i64>,
@@ -299,7 +460,7 @@ pub struct Stat {
 }
 
 /// [MoveStatAffectSets official documentation](https://pokeapi.co/docs/v2#movestataffectsets)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct MoveStatAffectSets {
     /// A list of moves and how they change the referenced stat.
     pub increase: Option<Vec<MoveStatAffect>>,
@@ -308,7 +469,7 @@ pub struct MoveStatAffectSets {
 }
 
 /// [MoveStatAffect official documentation](https://pokeapi.co/docs/v2#movestataffect)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct MoveStatAffect {
     /// The maximum amount of change to the referenced stat.
     pub change: Option<i64>,
@@ -318,7 +479,7 @@ pub struct MoveStatAffect {
 }
 
 /// [NatureStatAffectSets official documentation](https://pokeapi.co/docs/v2#naturestataffectsets)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct NatureStatAffectSets {
     /// A list of natures and how they change the referenced stat.
     pub increase: Option<Vec<NamedApiResource>>,
@@ -327,7 +488,7 @@ pub struct NatureStatAffectSets {
 }
 
 /// [Type official documentation](https://pokeapi.co/docs/v2#type)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct Type {
     /// The identifier for this resource.
     pub id: Option<i64>,
@@ -335,6 +496,10 @@ pub struct Type {
     pub name: Option<String>,
     /// A detail of how effective this type is toward others and vice versa.
     pub damage_relations: Option<TypeRelations>,
+    /// A list of details of how effective this type was toward others and vice versa in previous generations.
+    pub past_damage_relations: Option<Vec<TypeRelationsPast>>,
+    /// A list of game indices relevent to this item by generation.
+    pub game_indices: Option<Vec<GenerationGameIndex>>,
     /// The generation this type was introduced in.
     pub generation: Option<NamedApiResource>,
     /// The class of damage inflicted by this type.
@@ -345,10 +510,12 @@ pub struct Type {
     pub pokemon: Option<Vec<TypePokemon>>,
     /// A list of moves that have this type.
     pub moves: Option<Vec<NamedApiResource>>,
+    /// The icons of this type in each version, by generation.
+    pub sprites: Option<::serde_json::Value>,
 }
 
 /// [TypePokemon official documentation](https://pokeapi.co/docs/v2#typepokemon)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct TypePokemon {
     /// The order the Pokémon's types are listed in.
     pub slot: Option<i64>,
@@ -356,8 +523,17 @@ pub struct TypePokemon {
     pub pokemon: Option<NamedApiResource>,
 }
 
+/// [TypeRelationsPast official documentation](https://pokeapi.co/docs/v2#typerelationspast)
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct TypeRelationsPast {
+    /// The last generation in which the referenced type had the listed damage relations.
+    pub generation: Option<NamedApiResource>,
+    /// The damage relations the referenced type had up to and including the listed generation.
+    pub damage_relations: Option<TypeRelations>,
+}
+
 /// [TypeRelations official documentation](https://pokeapi.co/docs/v2#typerelations)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct TypeRelations {
     /// A list of types this type has no effect on.
     pub no_damage_to: Option<Vec<NamedApiResource>>,
@@ -1,7 +1,7 @@
 //! Common models
 
 /// [NamedApiResourceList official documentation](https:///pokeapi.co/docs/v2#namedapiresourcelist)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct NamedApiResourceList {
     /// The total number of resources available from this API.
     pub count: Option<i64>,
@@ -14,14 +14,21 @@ pub struct NamedApiResourceList {
 }
 
 /// [ApiResource official documentation](https://pokeapi.co/docs/v2#apiresource)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct ApiResource {
     /// The URL of the referenced resource.
     pub url: Option<String>,
 }
 
+/// The name of a reference, e.g. `Pokemon.species`, including one that PokeAPI may leave out.
+pub fn name<'a>(resource: impl Into<Option<&'a NamedApiResource>>) -> Option<&'a str> {
+    resource
+        .into()
+        .and_then(|resource| resource.name.as_deref())
+}
+
 /// [Description official documentation](https://pokeapi.co/docs/v2#description)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct Description {
     /// The localized description for an API resource in a specific language.
     pub description: Option<String>,
@@ -30,7 +37,7 @@ pub struct Description {
 }
 
 /// [Effect official documentation](https://pokeapi.co/docs/v2#effect)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct Effect {
     /// The localized effect text for an API resource in a specific language.
     pub effect: Option<String>,
@@ -39,7 +46,7 @@ pub struct Effect {
 }
 
 /// [Encounter official documentation](https://pokeapi.co/docs/v2#encounter)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct Encounter {
     /// The lowest level the Pokémon could be encountered at.
     pub min_level: Option<i64>,
@@ -54,7 +61,7 @@ pub struct Encounter {
 }
 
 /// [MachineVersionDetail official documentation](https://pokeapi.co/docs/v2#machineversiondetail)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct MachineVersionDetail {
     /// The machine that teaches a move from an item.
     pub machine: Option<ApiResource>,
@@ -62,8 +69,28 @@ pub struct MachineVersionDetail {
     pub version_group: Option<NamedApiResource>,
 }
 
+/// [FlavorText official documentation](https://pokeapi.co/docs/v2#flavortext)
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct FlavorText {
+    /// The localized flavor text for an API resource in a specific language.
+    pub flavor_text: Option<String>,
+    /// The language this name is in.
+    pub language: Option<NamedApiResource>,
+    /// The game version this flavor text is extracted from.
+    pub version: Option<NamedApiResource>,
+}
+
+/// [GenerationGameIndex official documentation](https://pokeapi.co/docs/v2#generationgameindex)
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct GenerationGameIndex {
+    /// The internal id of an API resource within game data.
+    pub game_index: Option<i64>,
+    /// The generation relevent to this game index.
+    pub generation: Option<NamedApiResource>,
+}
+
 /// [Name official documentation](https://pokeapi.co/docs/v2#name)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct Name {
     /// The localized name for an API resource in a specific language.
     pub name: Option<String>,
@@ -72,16 +99,16 @@ pub struct Name {
 }
 
 /// [NamedApiResource official documentation](https://pokeapi.co/docs/v2#namedapiresource)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct NamedApiResource {
     /// The name of the referenced resource.
     pub name: Option<String>,
-    // /// The URL of the referenced resource.
-    // pub url: Option<String>,
+    /// The URL of the referenced resource.
+    pub url: Option<String>,
 }
 
 /// [VerboseEffect official documentation](https://pokeapi.co/docs/v2#verboseeffect)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct VerboseEffect {
     /// The localized effect text for an API resource in a specific language.
     pub effect: Option<String>,
@@ -91,8 +118,17 @@ pub struct VerboseEffect {
     pub language: Option<NamedApiResource>,
 }
 
+/// [VersionGameIndex official documentation](https://pokeapi.co/docs/v2#versiongameindex)
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct VersionGameIndex {
+    /// The internal id of an API resource within game data.
+    pub game_index: Option<i64>,
+    /// The version relevent to this game index.
+    pub version: Option<NamedApiResource>,
+}
+
 /// [VersionEncounterDetail official documentation](https://pokeapi.co/docs/v2#versionencounterdetail)
-#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct VersionEncounterDetail {
     /// The game version this encounter happens in.
     pub version: Option<NamedApiResource>,
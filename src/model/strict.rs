@@ -0,0 +1,247 @@
+//! Strict deserialization
+//!
+//! Every model field is an `Option`, so a field renamed in PokeAPI or misspelled in a model silently
+//! deserializes to `None`. Deserializing strictly compares the fields of each JSON object with those of
+//! the model it is read into and reports every field only one of them has.
+
+use std::{
+    cell::RefCell,
+    fmt,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use serde::{
+    de::{
+        self, value::BorrowedStrDeserializer, DeserializeOwned, DeserializeSeed, MapAccess,
+        SeqAccess, Visitor,
+    },
+    forward_to_deserialize_any, Deserializer,
+};
+use serde_json::Value;
+
+static STRICT: AtomicBool = AtomicBool::new(false);
+
+/// A difference between a JSON object and the model it is read into.
+/// Paths are dotted, with `[]` for the elements of a list, e.g. `moves[].move`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Mismatch {
+    /// The JSON has a field the model does not.
+    Unknown(String),
+    /// The model has a field the JSON does not.
+    Missing(String),
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Mismatch::Unknown(path) => write!(f, "unknown field `{}`", path),
+            Mismatch::Missing(path) => write!(f, "missing field `{}`", path),
+        }
+    }
+}
+
+/// Makes the endpoint functions deserialize strictly, failing with [`Error::Decode`](crate::error::Error::Decode)
+/// on any [`Mismatch`]. Resources already cached are not checked again.
+pub fn set_strict(strict: bool) {
+    STRICT.store(strict, Ordering::Relaxed);
+}
+
+/// Whether the endpoint functions deserialize strictly.
+pub fn is_strict() -> bool {
+    STRICT.load(Ordering::Relaxed)
+}
+
+/// Deserializes a model, failing on any [`Mismatch`].
+pub fn from_str<T: DeserializeOwned>(json: &str) -> Result<T, serde_json::Error> {
+    let (value, mismatches) = check(json)?;
+    if mismatches.is_empty() {
+        return Ok(value);
+    }
+    let mismatches: Vec<String> = mismatches.iter().map(Mismatch::to_string).collect();
+    Err(de::Error::custom(mismatches.join(", ")))
+}
+
+/// Deserializes a model along with every [`Mismatch`] between it and the JSON, sorted by path.
+pub fn check<T: DeserializeOwned>(json: &str) -> Result<(T, Vec<Mismatch>), serde_json::Error> {
+    let value: Value = serde_json::from_str(json)?;
+    let mismatches = RefCell::new(Vec::new());
+    let model = T::deserialize(Strict {
+        value: &value,
+        path: String::new(),
+        mismatches: &mismatches,
+    })?;
+    let mut mismatches = mismatches.into_inner();
+    mismatches.sort();
+    mismatches.dedup();
+    Ok((model, mismatches))
+}
+
+/// A JSON value being deserialized, which records mismatches of the objects read as structs.
+struct Strict<'de> {
+    value: &'de Value,
+    path: String,
+    mismatches: &'de RefCell<Vec<Mismatch>>,
+}
+
+impl<'de> Strict<'de> {
+    fn child(&self, value: &'de Value, path: String) -> Self {
+        Self {
+            value,
+            path,
+            mismatches: self.mismatches,
+        }
+    }
+
+    fn field_path(&self, field: &str) -> String {
+        if self.path.is_empty() {
+            field.to_string()
+        } else {
+            format!("{}.{}", self.path, field)
+        }
+    }
+}
+
+impl<'de> Deserializer<'de> for Strict<'de> {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.value {
+            Value::Null => visitor.visit_unit(),
+            Value::Bool(value) => visitor.visit_bool(*value),
+            Value::Number(number) => match (number.as_u64(), number.as_i64()) {
+                (Some(value), _) => visitor.visit_u64(value),
+                (None, Some(value)) => visitor.visit_i64(value),
+                (None, None) => visitor.visit_f64(number.as_f64().unwrap_or_default()),
+            },
+            Value::String(value) => visitor.visit_borrowed_str(value),
+            Value::Array(items) => visitor.visit_seq(Items {
+                items: items.iter(),
+                parent: &self,
+            }),
+            Value::Object(fields) => visitor.visit_map(Fields {
+                fields: fields.iter(),
+                next: None,
+                parent: &self,
+            }),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.value {
+            Value::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        if let Value::Object(object) = self.value {
+            let mut mismatches = self.mismatches.borrow_mut();
+            for key in object.keys() {
+                if !fields.contains(&key.as_str()) {
+                    mismatches.push(Mismatch::Unknown(self.field_path(key)));
+                }
+            }
+            for field in fields {
+                if !object.contains_key(*field) {
+                    mismatches.push(Mismatch::Missing(self.field_path(field)));
+                }
+            }
+        }
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.value.deserialize_enum(name, variants, visitor)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map identifier
+    }
+}
+
+struct Items<'a, 'de> {
+    items: std::slice::Iter<'de, Value>,
+    parent: &'a Strict<'de>,
+}
+
+impl<'de> SeqAccess<'de> for Items<'_, 'de> {
+    type Error = serde_json::Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Self::Error> {
+        match self.items.next() {
+            Some(item) => {
+                let path = format!("{}[]", self.parent.path);
+                seed.deserialize(self.parent.child(item, path)).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.items.len())
+    }
+}
+
+struct Fields<'a, 'de> {
+    fields: serde_json::map::Iter<'de>,
+    next: Option<(&'de String, &'de Value)>,
+    parent: &'a Strict<'de>,
+}
+
+impl<'de> MapAccess<'de> for Fields<'_, 'de> {
+    type Error = serde_json::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        self.next = self.fields.next();
+        match self.next {
+            Some((key, _)) => seed
+                .deserialize(BorrowedStrDeserializer::new(key.as_str()))
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        let (key, value) = self
+            .next
+            .take()
+            .ok_or_else(|| <serde_json::Error as de::Error>::custom("value without a key"))?;
+        seed.deserialize(self.parent.child(value, self.parent.field_path(key)))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.fields.len())
+    }
+}
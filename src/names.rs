@@ -0,0 +1,197 @@
+//! Names of types, stats, damage classes and ailments
+//!
+//! PokeAPI refers to these by the `name` of a [`NamedApiResource`], e.g. `special-attack`. The enums here
+//! list the names PokeAPI uses, so that matching on them is checked at compile time. They parse from and
+//! display as the PokeAPI name, and are (de)serialized as it.
+
+use std::{fmt, str::FromStr};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::model::resource::NamedApiResource;
+
+/// A name that is not one of the known names of its kind.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownName {
+    /// What the name was read as, e.g. `type`.
+    pub kind: &'static str,
+    /// The name, which is empty for a resource without one.
+    pub name: String,
+}
+
+impl fmt::Display for UnknownName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown {} name `{}`", self.kind, self.name)
+    }
+}
+
+impl std::error::Error for UnknownName {}
+
+macro_rules! names {
+    (
+        $(#[$meta:meta])*
+        pub enum $enum:ident: $kind:literal {
+            $($(#[$variant_meta:meta])* $variant:ident = $name:literal,)+
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub enum $enum {
+            $($(#[$variant_meta])* $variant,)+
+        }
+
+        impl $enum {
+            /// Every name, in the order of their IDs in PokeAPI.
+            pub const ALL: &'static [Self] = &[$(Self::$variant),+];
+
+            /// The PokeAPI name.
+            pub fn name(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $name,)+
+                }
+            }
+        }
+
+        impl FromStr for $enum {
+            type Err = UnknownName;
+
+            fn from_str(name: &str) -> Result<Self, UnknownName> {
+                match name {
+                    $($name => Ok(Self::$variant),)+
+                    _ => Err(UnknownName {
+                        kind: $kind,
+                        name: name.to_string(),
+                    }),
+                }
+            }
+        }
+
+        impl fmt::Display for $enum {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.name())
+            }
+        }
+
+        impl AsRef<str> for $enum {
+            fn as_ref(&self) -> &str {
+                self.name()
+            }
+        }
+
+        impl TryFrom<&NamedApiResource> for $enum {
+            type Error = UnknownName;
+
+            fn try_from(resource: &NamedApiResource) -> Result<Self, UnknownName> {
+                resource.name.as_deref().unwrap_or_default().parse()
+            }
+        }
+
+        impl TryFrom<NamedApiResource> for $enum {
+            type Error = UnknownName;
+
+            fn try_from(resource: NamedApiResource) -> Result<Self, UnknownName> {
+                Self::try_from(&resource)
+            }
+        }
+
+        impl Serialize for $enum {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.name())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $enum {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                String::deserialize(deserializer)?
+                    .parse()
+                    .map_err(de::Error::custom)
+            }
+        }
+    };
+}
+
+names! {
+    /// The type of a Pokémon or a move.
+    /// See [Bulbapedia](http://bulbapedia.bulbagarden.net/wiki/Type) for greater detail.
+    pub enum PokemonTypeName: "type" {
+        Normal = "normal",
+        Fighting = "fighting",
+        Flying = "flying",
+        Poison = "poison",
+        Ground = "ground",
+        Rock = "rock",
+        Bug = "bug",
+        Ghost = "ghost",
+        Steel = "steel",
+        Fire = "fire",
+        Water = "water",
+        Grass = "grass",
+        Electric = "electric",
+        Psychic = "psychic",
+        Ice = "ice",
+        Dragon = "dragon",
+        Dark = "dark",
+        Fairy = "fairy",
+        /// The Tera Type of Generation IX that is not a type of any Pokémon.
+        Stellar = "stellar",
+        /// The type of Curse before Generation V and of glitch Pokémon.
+        Unknown = "unknown",
+        /// The type of the Shadow moves of Pokémon Colosseum and XD.
+        Shadow = "shadow",
+    }
+}
+
+names! {
+    /// A stat, including the accuracy and evasion that only exist in battle.
+    /// See [Bulbapedia](http://bulbapedia.bulbagarden.net/wiki/Stat) for greater detail.
+    pub enum StatName: "stat" {
+        Hp = "hp",
+        Attack = "attack",
+        Defense = "defense",
+        SpecialAttack = "special-attack",
+        SpecialDefense = "special-defense",
+        Speed = "speed",
+        Accuracy = "accuracy",
+        Evasion = "evasion",
+    }
+}
+
+names! {
+    /// Whether a move deals physical or special damage, or none.
+    /// See [Bulbapedia](http://bulbapedia.bulbagarden.net/wiki/Damage_category) for greater detail.
+    pub enum DamageClass: "damage class" {
+        Status = "status",
+        Physical = "physical",
+        Special = "special",
+    }
+}
+
+names! {
+    /// A status condition a move may inflict, as listed by the `move-ailment` endpoint.
+    /// See [Bulbapedia](http://bulbapedia.bulbagarden.net/wiki/Status_condition) for greater detail.
+    pub enum Ailment: "ailment" {
+        /// An ailment PokeAPI has not classified.
+        Unknown = "unknown",
+        None = "none",
+        Paralysis = "paralysis",
+        Sleep = "sleep",
+        Freeze = "freeze",
+        Burn = "burn",
+        Poison = "poison",
+        Confusion = "confusion",
+        Infatuation = "infatuation",
+        Trap = "trap",
+        Nightmare = "nightmare",
+        Torment = "torment",
+        Disable = "disable",
+        Yawn = "yawn",
+        HealBlock = "heal-block",
+        NoTypeImmunity = "no-type-immunity",
+        LeechSeed = "leech-seed",
+        Embargo = "embargo",
+        PerishSong = "perish-song",
+        Ingrain = "ingrain",
+        Silence = "silence",
+        TarShot = "tar-shot",
+    }
+}
@@ -0,0 +1,99 @@
+//! Pokémon owned by a trainer
+
+use crate::{
+    model::pokemon::{Nature, Pokemon},
+    stats::{self, Stats},
+};
+
+/// The friendship most species start with. Each species has its own base happiness.
+pub const BASE_FRIENDSHIP: i64 = 70;
+
+/// The gender of an individual Pokémon.
+/// See [Bulbapedia](http://bulbapedia.bulbagarden.net/wiki/Gender) for greater detail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Gender {
+    Male,
+    Female,
+    Genderless,
+}
+
+impl Gender {
+    /// Whether two Pokémon are of opposite genders, as required by infatuation and breeding.
+    pub fn is_opposite(self, other: Gender) -> bool {
+        matches!(
+            (self, other),
+            (Gender::Male, Gender::Female) | (Gender::Female, Gender::Male)
+        )
+    }
+}
+
+/// An individual Pokémon caught, hatched or received by a trainer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OwnedPokemon {
+    /// The name given by the trainer, if any.
+    pub nickname: Option<String>,
+    /// The PokeAPI name of the Pokémon this individual is, e.g. `pikachu`.
+    pub pokemon: String,
+    pub level: i64,
+    /// The total experience points gained.
+    pub experience: i64,
+    pub gender: Gender,
+    /// The PokeAPI name of the nature, e.g. `timid`.
+    pub nature: Option<String>,
+    /// The PokeAPI name of the ability, e.g. `static`.
+    pub ability: Option<String>,
+    /// The PokeAPI name of the held item, e.g. `light-ball`.
+    pub held_item: Option<String>,
+    /// The PokeAPI names of the known moves, at most four.
+    pub moves: Vec<String>,
+    /// Also known as happiness, from 0 to 255.
+    pub friendship: i64,
+    /// The hidden personality value, which settles characteristics and shininess.
+    /// See [Bulbapedia](http://bulbapedia.bulbagarden.net/wiki/Personality_value) for greater detail.
+    pub personality: u32,
+    pub shiny: bool,
+    /// Individual values, from 0 to 31 each.
+    pub iv: Stats,
+    /// Effort values, from 0 to 252 each and 510 in total.
+    pub ev: Stats,
+}
+
+impl OwnedPokemon {
+    /// Creates a genderless Pokémon of the given level with the usual base friendship
+    /// and without experience, IVs, EVs or moves.
+    pub fn new(pokemon: impl Into<String>, level: i64) -> Self {
+        Self {
+            nickname: None,
+            pokemon: pokemon.into(),
+            level,
+            experience: 0,
+            gender: Gender::Genderless,
+            nature: None,
+            ability: None,
+            held_item: None,
+            moves: Vec::new(),
+            friendship: BASE_FRIENDSHIP,
+            personality: 0,
+            shiny: false,
+            iv: Stats::default(),
+            ev: Stats::default(),
+        }
+    }
+
+    /// The name shown for this Pokémon: its nickname, or else the name of the Pokémon.
+    pub fn display_name(&self) -> &str {
+        self.nickname.as_deref().unwrap_or(&self.pokemon)
+    }
+
+    /// Calculates the stats of this Pokémon from the base stats of its [`Pokemon`] and its [`Nature`].
+    pub fn stats(&self, base: &Pokemon, nature: Option<&Nature>) -> Stats {
+        let base = Stats::from_base_stats(base.stats.as_deref().unwrap_or_default());
+        stats::calculate(&base, &self.iv, &self.ev, self.level, nature)
+    }
+}
+
+/// The most Pokémon a trainer can carry in their party.
+pub const TEAM_SIZE: usize = 6;
+
+/// The Pokémon a trainer carries, at most [`TEAM_SIZE`] of them.
+pub type Team = Vec<OwnedPokemon>;
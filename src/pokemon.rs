@@ -38,10 +38,18 @@ pub mod nature {
 /// Each Pokémon belongs to a specific species but may take on a variant which makes it differ
 /// from other Pokémon of the same species, such as base stats, available abilities and typings.
 /// See [Bulbapedia](http://bulbapedia.bulbagarden.net/wiki/Pokémon_(species)) for greater detail.
+#[allow(clippy::module_inception)]
 pub mod pokemon {
     crate::endpoint!(crate::model::pokemon::Pokemon; for "pokemon");
 }
 
+/// A Pokémon Species forms the basis for at least one Pokémon. Attributes of a Pokémon species are shared
+/// across all varieties of Pokémon within the species. A good example is Wormadam; Wormadam is the species
+/// which can be found in three different varieties, Wormadam-Trash, Wormadam-Sandy and Wormadam-Plant.
+pub mod pokemon_species {
+    crate::endpoint!(crate::model::pokemon::PokemonSpecies; for "pokemon-species");
+}
+
 /// Stats determine certain aspects of battles. Each Pokémon has a value for each stat which
 /// grows as they gain levels and can be altered momentarily by effects in battles.
 pub mod stat {
@@ -0,0 +1,185 @@
+//! Request policy
+//!
+//! How [`Http`](crate::source::Http) treats PokeAPI: failed requests are retried after a growing wait, or
+//! as long as the server asks with `Retry-After`, and a [`RateLimiter`] shared by every endpoint keeps
+//! the request rate polite. See the [fair use policy](https://pokeapi.co/docs/v2#fairuse) of PokeAPI.
+
+use std::{
+    sync::{Arc, Mutex, OnceLock},
+    time::{Duration, SystemTime},
+};
+
+use reqwest::{
+    header::{HeaderMap, RETRY_AFTER},
+    Client, Response, StatusCode, Url,
+};
+use tokio::time::Instant;
+
+/// The request rate of [`RateLimiter::global`], per second.
+pub const DEFAULT_REQUESTS_PER_SECOND: f64 = 20.0;
+
+/// When to give up on a request and how long to wait before trying it again.
+#[derive(Debug, Clone)]
+pub struct RequestPolicy {
+    /// How long a request may take before it fails as timed out.
+    pub timeout: Duration,
+    /// How many times a request is tried again after failing with a 5xx or 429 status, timing out or
+    /// not connecting.
+    pub max_retries: u32,
+    /// The wait before the first retry, doubled for every retry after it.
+    pub initial_backoff: Duration,
+    /// The longest wait before a retry. A request the server asks to retry later than this is not retried.
+    pub max_backoff: Duration,
+    /// Limits the rate of every request made under this policy, retries included.
+    pub rate_limiter: Option<Arc<RateLimiter>>,
+}
+
+impl Default for RequestPolicy {
+    /// Retries 3 times, waiting from half a second, and shares the [global](RateLimiter::global) rate limiter.
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(30),
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            rate_limiter: Some(RateLimiter::global()),
+        }
+    }
+}
+
+impl RequestPolicy {
+    /// A policy that tries every request once, without a rate limit.
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            rate_limiter: None,
+            ..Self::default()
+        }
+    }
+
+    /// The wait before a retry, given how many retries came before it.
+    pub fn backoff(&self, retries: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2u32.saturating_pow(retries))
+            .min(self.max_backoff)
+    }
+
+    /// Sends a GET request, retrying it as long as the policy allows. The last response is returned
+    /// even if its status is an error, for the caller to handle.
+    pub(crate) async fn get(
+        &self,
+        client: &Client,
+        url: Url,
+        headers: HeaderMap,
+    ) -> reqwest::Result<Response> {
+        let mut retries = 0;
+        loop {
+            if let Some(rate_limiter) = &self.rate_limiter {
+                rate_limiter.acquire().await;
+            }
+            let result = client
+                .get(url.clone())
+                .headers(headers.clone())
+                .timeout(self.timeout)
+                .send()
+                .await;
+            let wait = match &result {
+                Ok(response) if is_transient(response.status()) => {
+                    retry_after(response).unwrap_or_else(|| self.backoff(retries))
+                }
+                Err(error) if error.is_timeout() || error.is_connect() => self.backoff(retries),
+                _ => return result,
+            };
+            if retries == self.max_retries || wait > self.max_backoff {
+                return result;
+            }
+            tokio::time::sleep(wait).await;
+            retries += 1;
+        }
+    }
+}
+
+/// A token bucket: requests take a token each, and tokens come back at a steady rate up to a burst.
+/// Requests without a token wait for one in the order they asked.
+#[derive(Debug)]
+pub struct RateLimiter {
+    per_second: f64,
+    burst: f64,
+    bucket: Mutex<Bucket>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    /// Negative when requests are waiting for tokens that have not come back yet.
+    tokens: f64,
+    refilled: Instant,
+}
+
+impl RateLimiter {
+    /// Allows `per_second` requests a second on average, and up to `burst` at once after a pause.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `per_second` is not positive.
+    pub fn new(per_second: f64, burst: u32) -> Self {
+        assert!(per_second > 0.0, "the rate must be positive");
+        let burst = f64::from(burst.max(1));
+        Self {
+            per_second,
+            burst,
+            bucket: Mutex::new(Bucket {
+                tokens: burst,
+                refilled: Instant::now(),
+            }),
+        }
+    }
+
+    /// The rate limiter shared by the default [`RequestPolicy`], and so by every endpoint,
+    /// allowing [`DEFAULT_REQUESTS_PER_SECOND`].
+    pub fn global() -> Arc<Self> {
+        static GLOBAL: OnceLock<Arc<RateLimiter>> = OnceLock::new();
+        GLOBAL
+            .get_or_init(|| {
+                Arc::new(Self::new(
+                    DEFAULT_REQUESTS_PER_SECOND,
+                    DEFAULT_REQUESTS_PER_SECOND as u32,
+                ))
+            })
+            .clone()
+    }
+
+    /// Takes a token, waiting until one comes back if there is none.
+    pub async fn acquire(&self) {
+        let wait = {
+            let mut bucket = self.bucket.lock().unwrap();
+            let now = Instant::now();
+            let refilled = (now - bucket.refilled).as_secs_f64() * self.per_second;
+            bucket.tokens = (bucket.tokens + refilled).min(self.burst);
+            bucket.refilled = now;
+            bucket.tokens -= 1.0;
+            if bucket.tokens >= 0.0 {
+                return;
+            }
+            Duration::from_secs_f64(-bucket.tokens / self.per_second)
+        };
+        tokio::time::sleep(wait).await;
+    }
+}
+
+/// Whether a status means the same request may succeed later.
+pub(crate) fn is_transient(status: StatusCode) -> bool {
+    status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS
+}
+
+/// How long the server asks to wait before retrying, in seconds or until an HTTP date.
+fn retry_after(response: &Response) -> Option<Duration> {
+    let value = response.headers().get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = httpdate::parse_http_date(value).ok()?;
+    Some(
+        date.duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO),
+    )
+}
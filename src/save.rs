@@ -0,0 +1,488 @@
+//! Saving progress
+//!
+//! Stores a trainer's progress in an SQLite file: their profile, party, PC boxes, inventory and badges.
+//! The schema is versioned with `PRAGMA user_version` and older files are migrated when opened.
+
+use std::{fmt, path::Path, time::Duration};
+
+use rusqlite::{params, Connection, OptionalExtension, Row, Transaction};
+
+use crate::{
+    owned::{Gender, OwnedPokemon, Team},
+    stats::Stats,
+};
+
+/// How many Pokémon a PC box holds.
+pub const BOX_SIZE: usize = 30;
+
+/// The migrations of the schema. The version of a file is the number of migrations applied to it.
+const MIGRATIONS: [&str; 2] = [
+    "CREATE TABLE trainer (
+        id INTEGER PRIMARY KEY CHECK (id = 1),
+        name TEXT NOT NULL,
+        trainer_id INTEGER NOT NULL,
+        money INTEGER NOT NULL,
+        play_time INTEGER NOT NULL
+    );
+    CREATE TABLE pc_box (
+        number INTEGER PRIMARY KEY,
+        name TEXT NOT NULL
+    );
+    CREATE TABLE pokemon (
+        id INTEGER PRIMARY KEY,
+        pc_box INTEGER REFERENCES pc_box (number),
+        slot INTEGER NOT NULL,
+        nickname TEXT,
+        pokemon TEXT NOT NULL,
+        level INTEGER NOT NULL,
+        experience INTEGER NOT NULL,
+        gender TEXT NOT NULL,
+        nature TEXT,
+        ability TEXT,
+        held_item TEXT,
+        friendship INTEGER NOT NULL,
+        personality INTEGER NOT NULL,
+        shiny INTEGER NOT NULL,
+        iv_hp INTEGER NOT NULL,
+        iv_attack INTEGER NOT NULL,
+        iv_defence INTEGER NOT NULL,
+        iv_special_attack INTEGER NOT NULL,
+        iv_special_defence INTEGER NOT NULL,
+        iv_speed INTEGER NOT NULL,
+        ev_hp INTEGER NOT NULL,
+        ev_attack INTEGER NOT NULL,
+        ev_defence INTEGER NOT NULL,
+        ev_special_attack INTEGER NOT NULL,
+        ev_special_defence INTEGER NOT NULL,
+        ev_speed INTEGER NOT NULL
+    );
+    CREATE TABLE pokemon_move (
+        pokemon INTEGER NOT NULL REFERENCES pokemon (id) ON DELETE CASCADE,
+        slot INTEGER NOT NULL,
+        move TEXT NOT NULL,
+        PRIMARY KEY (pokemon, slot)
+    );
+    CREATE TABLE inventory (
+        item TEXT PRIMARY KEY,
+        quantity INTEGER NOT NULL
+    );
+    CREATE TABLE badge (
+        name TEXT PRIMARY KEY,
+        position INTEGER NOT NULL
+    );",
+    // The bag keeps the player's order and may hold several stacks of an item,
+    // and the play time is kept in milliseconds rather than seconds.
+    "CREATE TABLE inventory_by_position (
+        position INTEGER PRIMARY KEY,
+        item TEXT NOT NULL,
+        quantity INTEGER NOT NULL
+    );
+    INSERT INTO inventory_by_position (position, item, quantity)
+        SELECT (SELECT COUNT(*) FROM inventory AS earlier WHERE earlier.item < inventory.item),
+               item, quantity
+        FROM inventory;
+    DROP TABLE inventory;
+    ALTER TABLE inventory_by_position RENAME TO inventory;
+    UPDATE trainer SET play_time = play_time * 1000;",
+];
+
+/// The trainer a save file belongs to.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct Profile {
+    pub name: String,
+    /// The public ID in the lower 16 bits and the secret ID in the upper 16 bits.
+    pub trainer_id: u32,
+    pub money: i64,
+    /// Saved to the millisecond.
+    pub play_time: Duration,
+}
+
+/// A PC box, whose slots may be empty.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PcBox {
+    pub name: String,
+    /// [`BOX_SIZE`] slots.
+    pub slots: Vec<Option<OwnedPokemon>>,
+}
+
+impl PcBox {
+    /// Creates an empty box.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            slots: vec![None; BOX_SIZE],
+        }
+    }
+}
+
+/// An item in the bag and how many of it the trainer has.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ItemStack {
+    /// The PokeAPI name of the item, e.g. `potion`.
+    pub item: String,
+    pub quantity: i64,
+}
+
+/// Everything a save file holds.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct SaveData {
+    pub profile: Profile,
+    pub party: Team,
+    pub boxes: Vec<PcBox>,
+    /// The bag in the order the player arranged it. An item may fill several stacks.
+    pub inventory: Vec<ItemStack>,
+    /// The names of the badges earned, in the order they were earned.
+    pub badges: Vec<String>,
+}
+
+/// Why a save file could not be used.
+#[derive(Debug)]
+pub enum SaveError {
+    Sqlite(rusqlite::Error),
+    /// The file was written by a newer version of the schema.
+    UnsupportedVersion(i64),
+    /// A value in the file cannot be read back, e.g. an unknown gender.
+    Corrupt(String),
+    /// The progress cannot be saved as it is, e.g. a box with more than [`BOX_SIZE`] slots.
+    Invalid(String),
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SaveError::Sqlite(error) => write!(f, "{}", error),
+            SaveError::UnsupportedVersion(version) => write!(
+                f,
+                "save file version {} is newer than the supported version {}",
+                version,
+                MIGRATIONS.len()
+            ),
+            SaveError::Corrupt(message) => write!(f, "corrupt save file: {}", message),
+            SaveError::Invalid(message) => write!(f, "cannot save: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for SaveError {}
+
+impl From<rusqlite::Error> for SaveError {
+    fn from(error: rusqlite::Error) -> Self {
+        SaveError::Sqlite(error)
+    }
+}
+
+/// An open save file.
+#[derive(Debug)]
+pub struct SaveFile {
+    connection: Connection,
+}
+
+impl SaveFile {
+    /// Opens a save file, creating it if needed, and migrates it to the latest schema.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, SaveError> {
+        Self::with_connection(Connection::open(path)?)
+    }
+
+    /// Opens a save file that only lives in memory.
+    pub fn in_memory() -> Result<Self, SaveError> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    fn with_connection(mut connection: Connection) -> Result<Self, SaveError> {
+        connection.pragma_update(None, "foreign_keys", true)?;
+        migrate(&mut connection)?;
+        Ok(Self { connection })
+    }
+
+    /// The version of the schema of the file.
+    pub fn version(&self) -> Result<i64, SaveError> {
+        Ok(version(&self.connection)?)
+    }
+
+    /// Replaces the contents of the file with the given progress. Nothing is written if a box has
+    /// more than [`BOX_SIZE`] slots or a badge is listed twice.
+    pub fn save(&mut self, data: &SaveData) -> Result<(), SaveError> {
+        check(data)?;
+        let transaction = self.connection.transaction()?;
+        transaction.execute_batch(
+            "DELETE FROM pokemon_move; DELETE FROM pokemon; DELETE FROM pc_box;
+             DELETE FROM inventory; DELETE FROM badge; DELETE FROM trainer;",
+        )?;
+
+        let profile = &data.profile;
+        transaction.execute(
+            "INSERT INTO trainer (id, name, trainer_id, money, play_time) VALUES (1, ?1, ?2, ?3, ?4)",
+            params![
+                profile.name,
+                profile.trainer_id,
+                profile.money,
+                profile.play_time.as_millis() as i64
+            ],
+        )?;
+        for (slot, pokemon) in data.party.iter().enumerate() {
+            insert_pokemon(&transaction, None, slot, pokemon)?;
+        }
+        for (number, pc_box) in data.boxes.iter().enumerate() {
+            transaction.execute(
+                "INSERT INTO pc_box (number, name) VALUES (?1, ?2)",
+                params![number as i64, pc_box.name],
+            )?;
+            for (slot, pokemon) in pc_box.slots.iter().enumerate() {
+                if let Some(pokemon) = pokemon {
+                    insert_pokemon(&transaction, Some(number), slot, pokemon)?;
+                }
+            }
+        }
+        for (position, stack) in data.inventory.iter().enumerate() {
+            transaction.execute(
+                "INSERT INTO inventory (position, item, quantity) VALUES (?1, ?2, ?3)",
+                params![position as i64, stack.item, stack.quantity],
+            )?;
+        }
+        for (position, badge) in data.badges.iter().enumerate() {
+            transaction.execute(
+                "INSERT INTO badge (name, position) VALUES (?1, ?2)",
+                params![badge, position as i64],
+            )?;
+        }
+        transaction.commit()?;
+        Ok(())
+    }
+
+    /// Reads the progress in the file. `None` if nothing has been saved yet.
+    pub fn load(&self) -> Result<Option<SaveData>, SaveError> {
+        let profile = self
+            .connection
+            .query_row(
+                "SELECT name, trainer_id, money, play_time FROM trainer WHERE id = 1",
+                [],
+                |row| {
+                    Ok(Profile {
+                        name: row.get(0)?,
+                        trainer_id: row.get(1)?,
+                        money: row.get(2)?,
+                        play_time: Duration::from_millis(row.get::<_, i64>(3)?.max(0) as u64),
+                    })
+                },
+            )
+            .optional()?;
+        let Some(profile) = profile else {
+            return Ok(None);
+        };
+
+        let mut data = SaveData {
+            profile,
+            ..SaveData::default()
+        };
+        let mut statement = self
+            .connection
+            .prepare("SELECT number, name FROM pc_box ORDER BY number")?;
+        let boxes = statement.query_map([], |row| Ok((row.get::<_, i64>(0)?, row.get(1)?)))?;
+        let mut numbers = Vec::new();
+        for pc_box in boxes {
+            let (number, name): (i64, String) = pc_box?;
+            numbers.push(number);
+            data.boxes.push(PcBox::new(name));
+        }
+
+        let mut statement = self.connection.prepare(
+            "SELECT id, pc_box, slot, nickname, pokemon, level, experience, gender, nature, ability,
+                    held_item, friendship, personality, shiny,
+                    iv_hp, iv_attack, iv_defence, iv_special_attack, iv_special_defence, iv_speed,
+                    ev_hp, ev_attack, ev_defence, ev_special_attack, ev_special_defence, ev_speed
+             FROM pokemon ORDER BY pc_box, slot",
+        )?;
+        let rows = statement.query_map([], |row| {
+            let stored = read_pokemon(row)?;
+            Ok((
+                row.get::<_, i64>(0)?,
+                row.get::<_, Option<i64>>(1)?,
+                row.get::<_, i64>(2)?,
+                stored,
+            ))
+        })?;
+        for row in rows {
+            let (id, pc_box, slot, (mut pokemon, gender)) = row?;
+            pokemon.gender = parse_gender(&gender)?;
+            pokemon.moves = self.moves(id)?;
+            match pc_box {
+                None => data.party.push(pokemon),
+                Some(number) => {
+                    let index = numbers
+                        .iter()
+                        .position(|known| *known == number)
+                        .ok_or_else(|| SaveError::Corrupt(format!("no box {}", number)))?;
+                    let slot = data.boxes[index]
+                        .slots
+                        .get_mut(slot as usize)
+                        .ok_or_else(|| SaveError::Corrupt(format!("no slot {}", slot)))?;
+                    *slot = Some(pokemon);
+                }
+            }
+        }
+
+        let mut statement = self
+            .connection
+            .prepare("SELECT item, quantity FROM inventory ORDER BY position")?;
+        let inventory = statement.query_map([], |row| {
+            Ok(ItemStack {
+                item: row.get(0)?,
+                quantity: row.get(1)?,
+            })
+        })?;
+        data.inventory = inventory.collect::<Result<_, _>>()?;
+
+        let mut statement = self
+            .connection
+            .prepare("SELECT name FROM badge ORDER BY position")?;
+        let badges = statement.query_map([], |row| row.get(0))?;
+        data.badges = badges.collect::<Result<_, _>>()?;
+        Ok(Some(data))
+    }
+
+    fn moves(&self, pokemon: i64) -> Result<Vec<String>, SaveError> {
+        let mut statement = self
+            .connection
+            .prepare_cached("SELECT move FROM pokemon_move WHERE pokemon = ?1 ORDER BY slot")?;
+        let moves = statement.query_map([pokemon], |row| row.get(0))?;
+        Ok(moves.collect::<Result<_, _>>()?)
+    }
+}
+
+fn version(connection: &Connection) -> rusqlite::Result<i64> {
+    connection.pragma_query_value(None, "user_version", |row| row.get(0))
+}
+
+/// Applies the migrations a file is missing, each in its own transaction.
+fn migrate(connection: &mut Connection) -> Result<(), SaveError> {
+    let current = version(connection)?;
+    if current > MIGRATIONS.len() as i64 {
+        return Err(SaveError::UnsupportedVersion(current));
+    }
+    for (index, migration) in MIGRATIONS.iter().enumerate().skip(current as usize) {
+        let transaction = connection.transaction()?;
+        transaction.execute_batch(migration)?;
+        transaction.pragma_update(None, "user_version", (index + 1) as i64)?;
+        transaction.commit()?;
+    }
+    Ok(())
+}
+
+fn insert_pokemon(
+    transaction: &Transaction,
+    pc_box: Option<usize>,
+    slot: usize,
+    pokemon: &OwnedPokemon,
+) -> Result<(), SaveError> {
+    let (iv, ev) = (&pokemon.iv, &pokemon.ev);
+    transaction.execute(
+        "INSERT INTO pokemon (
+            pc_box, slot, nickname, pokemon, level, experience, gender, nature, ability,
+            held_item, friendship, personality, shiny,
+            iv_hp, iv_attack, iv_defence, iv_special_attack, iv_special_defence, iv_speed,
+            ev_hp, ev_attack, ev_defence, ev_special_attack, ev_special_defence, ev_speed
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13,
+                  ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25)",
+        params![
+            pc_box.map(|number| number as i64),
+            slot as i64,
+            pokemon.nickname,
+            pokemon.pokemon,
+            pokemon.level,
+            pokemon.experience,
+            gender_name(pokemon.gender),
+            pokemon.nature,
+            pokemon.ability,
+            pokemon.held_item,
+            pokemon.friendship,
+            pokemon.personality,
+            pokemon.shiny,
+            iv.hp,
+            iv.attack,
+            iv.defence,
+            iv.special_attack,
+            iv.special_defence,
+            iv.speed,
+            ev.hp,
+            ev.attack,
+            ev.defence,
+            ev.special_attack,
+            ev.special_defence,
+            ev.speed,
+        ],
+    )?;
+    let id = transaction.last_insert_rowid();
+    for (slot, move_) in pokemon.moves.iter().enumerate() {
+        transaction.execute(
+            "INSERT INTO pokemon_move (pokemon, slot, move) VALUES (?1, ?2, ?3)",
+            params![id, slot as i64, move_],
+        )?;
+    }
+    Ok(())
+}
+
+/// Reads a Pokémon row without its moves, along with its stored gender.
+fn read_pokemon(row: &Row) -> rusqlite::Result<(OwnedPokemon, String)> {
+    let stats = |first: usize| -> rusqlite::Result<Stats> {
+        Ok(Stats {
+            hp: row.get(first)?,
+            attack: row.get(first + 1)?,
+            defence: row.get(first + 2)?,
+            special_attack: row.get(first + 3)?,
+            special_defence: row.get(first + 4)?,
+            speed: row.get(first + 5)?,
+        })
+    };
+    let mut pokemon = OwnedPokemon::new(row.get::<_, String>(4)?, row.get(5)?);
+    pokemon.nickname = row.get(3)?;
+    pokemon.experience = row.get(6)?;
+    pokemon.nature = row.get(8)?;
+    pokemon.ability = row.get(9)?;
+    pokemon.held_item = row.get(10)?;
+    pokemon.friendship = row.get(11)?;
+    pokemon.personality = row.get(12)?;
+    pokemon.shiny = row.get(13)?;
+    pokemon.iv = stats(14)?;
+    pokemon.ev = stats(20)?;
+    Ok((pokemon, row.get(7)?))
+}
+
+fn gender_name(gender: Gender) -> &'static str {
+    match gender {
+        Gender::Male => "male",
+        Gender::Female => "female",
+        Gender::Genderless => "genderless",
+    }
+}
+
+/// Checks that progress fits in the schema before anything is written.
+fn check(data: &SaveData) -> Result<(), SaveError> {
+    for (number, pc_box) in data.boxes.iter().enumerate() {
+        if pc_box.slots.len() > BOX_SIZE {
+            return Err(SaveError::Invalid(format!(
+                "box {} has {} slots, more than {}",
+                number,
+                pc_box.slots.len(),
+                BOX_SIZE
+            )));
+        }
+    }
+    for (position, badge) in data.badges.iter().enumerate() {
+        if data.badges[..position].contains(badge) {
+            return Err(SaveError::Invalid(format!(
+                "the {} is listed more than once",
+                badge
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn parse_gender(name: &str) -> Result<Gender, SaveError> {
+    match name {
+        "male" => Ok(Gender::Male),
+        "female" => Ok(Gender::Female),
+        "genderless" => Ok(Gender::Genderless),
+        _ => Err(SaveError::Corrupt(format!("unknown gender {}", name))),
+    }
+}
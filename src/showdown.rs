@@ -0,0 +1,461 @@
+//! Pokémon Showdown team format
+//!
+//! Reads and writes teams in the text format used by [Pokémon Showdown](https://pokemonshowdown.com/):
+//!
+//! ```text
+//! Bolt (Pikachu) (M) @ Light Ball
+//! Ability: Static
+//! EVs: 252 SpA / 4 SpD / 252 Spe
+//! Timid Nature
+//! - Thunderbolt
+//! ```
+//!
+//! Names are written as displayed in the games and turned into PokeAPI names, e.g. `Light Ball` into `light-ball`.
+
+use std::fmt;
+
+use crate::{
+    error::Error,
+    owned::{Gender, OwnedPokemon, Team},
+    pokemon,
+    stats::Stats,
+};
+
+/// The abbreviations Showdown uses for stats, in the order of [`Stats::NAMES`].
+pub const STAT_ABBREVIATIONS: [&str; 6] = ["HP", "Atk", "Def", "SpA", "SpD", "Spe"];
+
+/// One Pokémon of a team as written in Showdown's format, before its names are checked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Set {
+    pub nickname: Option<String>,
+    /// The PokeAPI name of the Pokémon, e.g. `rotom-wash`.
+    pub pokemon: String,
+    /// `None` when the set leaves the gender to the species.
+    pub gender: Option<Gender>,
+    pub item: Option<String>,
+    pub ability: Option<String>,
+    pub level: i64,
+    pub shiny: bool,
+    pub friendship: i64,
+    pub ev: Stats,
+    pub iv: Stats,
+    pub nature: Option<String>,
+    pub moves: Vec<String>,
+}
+
+impl Default for Set {
+    /// The values Showdown assumes for lines left out: level 100, full friendship and perfect IVs.
+    fn default() -> Self {
+        Self {
+            nickname: None,
+            pokemon: String::new(),
+            gender: None,
+            item: None,
+            ability: None,
+            level: 100,
+            shiny: false,
+            friendship: 255,
+            ev: Stats::default(),
+            iv: Stats {
+                hp: 31,
+                attack: 31,
+                defence: 31,
+                special_attack: 31,
+                special_defence: 31,
+                speed: 31,
+            },
+            nature: None,
+            moves: Vec::new(),
+        }
+    }
+}
+
+impl Set {
+    /// The owned Pokémon described by this set, genderless unless the set gives a gender.
+    pub fn to_owned_pokemon(&self) -> OwnedPokemon {
+        let mut owned = OwnedPokemon::new(self.pokemon.clone(), self.level);
+        owned.nickname = self.nickname.clone();
+        owned.gender = self.gender.unwrap_or(Gender::Genderless);
+        owned.held_item = self.item.clone();
+        owned.ability = self.ability.clone();
+        owned.shiny = self.shiny;
+        owned.friendship = self.friendship;
+        owned.ev = self.ev;
+        owned.iv = self.iv;
+        owned.nature = self.nature.clone();
+        owned.moves = self.moves.clone();
+        owned
+    }
+}
+
+impl From<&OwnedPokemon> for Set {
+    fn from(owned: &OwnedPokemon) -> Self {
+        Self {
+            nickname: owned.nickname.clone(),
+            pokemon: owned.pokemon.clone(),
+            gender: Some(owned.gender).filter(|gender| *gender != Gender::Genderless),
+            item: owned.held_item.clone(),
+            ability: owned.ability.clone(),
+            level: owned.level,
+            shiny: owned.shiny,
+            friendship: owned.friendship,
+            ev: owned.ev,
+            iv: owned.iv,
+            nature: owned.nature.clone(),
+            moves: owned.moves.clone(),
+        }
+    }
+}
+
+impl fmt::Display for Set {
+    /// Writes the set in Showdown's format, leaving out lines that hold the default values.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pokemon = display_pokemon_name(&self.pokemon);
+        match &self.nickname {
+            Some(nickname) if *nickname != pokemon => write!(f, "{} ({})", nickname, pokemon)?,
+            _ => write!(f, "{}", pokemon)?,
+        }
+        match self.gender {
+            Some(Gender::Male) => write!(f, " (M)")?,
+            Some(Gender::Female) => write!(f, " (F)")?,
+            _ => {}
+        }
+        if let Some(item) = &self.item {
+            write!(f, " @ {}", display_name(item))?;
+        }
+        writeln!(f)?;
+
+        let defaults = Set::default();
+        if let Some(ability) = &self.ability {
+            writeln!(f, "Ability: {}", display_name(ability))?;
+        }
+        if self.level != defaults.level {
+            writeln!(f, "Level: {}", self.level)?;
+        }
+        if self.shiny {
+            writeln!(f, "Shiny: Yes")?;
+        }
+        if self.friendship != defaults.friendship {
+            writeln!(f, "Happiness: {}", self.friendship)?;
+        }
+        write_spread(f, "EVs", &self.ev, 0)?;
+        if let Some(nature) = &self.nature {
+            writeln!(f, "{} Nature", display_name(nature))?;
+        }
+        write_spread(f, "IVs", &self.iv, 31)?;
+        for move_ in &self.moves {
+            writeln!(f, "- {}", display_name(move_))?;
+        }
+        Ok(())
+    }
+}
+
+/// A line of a team that could not be read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The number of the line, starting from 1.
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Reads the sets of a team. Sets are separated by blank lines; lines Showdown uses
+/// for things this crate does not model, such as `Tera Type:`, are skipped.
+pub fn parse(text: &str) -> Result<Vec<Set>, ParseError> {
+    let mut sets = Vec::new();
+    let mut current: Option<Set> = None;
+    for (index, line) in text.lines().enumerate() {
+        let number = index + 1;
+        let line = line.trim();
+        let error = |message: String| ParseError {
+            line: number,
+            message,
+        };
+        if line.is_empty() || line.starts_with("===") {
+            sets.extend(current.take());
+            continue;
+        }
+        let Some(set) = current.as_mut() else {
+            current = Some(parse_first_line(line).map_err(error)?);
+            continue;
+        };
+        if let Some(move_) = line.strip_prefix('-') {
+            // Hidden Power is written with its type, e.g. `Hidden Power [Fire]`.
+            let move_ = move_.split('[').next().unwrap_or_default();
+            set.moves.push(to_id(move_));
+        } else if let Some(ability) = line.strip_prefix("Ability:") {
+            set.ability = Some(to_id(ability));
+        } else if let Some(level) = line.strip_prefix("Level:") {
+            set.level = parse_number(level).map_err(error)?;
+        } else if let Some(shiny) = line.strip_prefix("Shiny:") {
+            set.shiny = shiny.trim().eq_ignore_ascii_case("yes");
+        } else if let Some(friendship) = line.strip_prefix("Happiness:") {
+            set.friendship = parse_number(friendship).map_err(error)?;
+        } else if let Some(spread) = line.strip_prefix("EVs:") {
+            parse_spread(spread, &mut set.ev).map_err(error)?;
+        } else if let Some(spread) = line.strip_prefix("IVs:") {
+            parse_spread(spread, &mut set.iv).map_err(error)?;
+        } else if let Some(nature) = line.strip_suffix(" Nature") {
+            set.nature = Some(to_id(nature));
+        }
+    }
+    sets.extend(current);
+    Ok(sets)
+}
+
+/// Writes a team in Showdown's format, with a blank line between Pokémon.
+pub fn export(team: &[OwnedPokemon]) -> String {
+    team.iter()
+        .map(|owned| Set::from(owned).to_string())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The kinds of names a set refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NameKind {
+    Pokemon,
+    Ability,
+    Nature,
+    Move,
+}
+
+/// A name in a set that PokeAPI does not know.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Unknown {
+    /// The position of the set in the team, starting from 0.
+    pub member: usize,
+    pub kind: NameKind,
+    /// The PokeAPI name looked up.
+    pub name: String,
+}
+
+impl fmt::Display for Unknown {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "member {}: unknown {:?} {}",
+            self.member + 1,
+            self.kind,
+            self.name
+        )
+    }
+}
+
+/// A team read from Showdown's format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Import {
+    pub team: Team,
+    /// The names PokeAPI does not know. Members are imported with them regardless.
+    pub unknown: Vec<Unknown>,
+}
+
+/// Why a team could not be imported.
+#[derive(Debug)]
+pub enum ImportError {
+    Parse(ParseError),
+    /// A request to PokeAPI failed for another reason than an unknown name.
+    Request(Error),
+}
+
+impl fmt::Display for ImportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImportError::Parse(error) => write!(f, "{}", error),
+            ImportError::Request(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for ImportError {}
+
+impl From<ParseError> for ImportError {
+    fn from(error: ParseError) -> Self {
+        ImportError::Parse(error)
+    }
+}
+
+/// Reads a team and checks its names through the `pokemon`, `ability`, `nature` and `move_` endpoints.
+/// Members without a gender get one their species can have.
+pub async fn import(text: &str) -> Result<Import, ImportError> {
+    let mut import = Import {
+        team: Team::new(),
+        unknown: Vec::new(),
+    };
+    for (member, set) in parse(text)?.into_iter().enumerate() {
+        let mut owned = set.to_owned_pokemon();
+        let mut unknown = |kind: NameKind, name: &str| {
+            import.unknown.push(Unknown {
+                member,
+                kind,
+                name: name.to_string(),
+            })
+        };
+
+        match known(pokemon::pokemon::get_by_name(&set.pokemon).await)? {
+            Some(data) if set.gender.is_none() => {
+                let species = data
+                    .species
+                    .as_ref()
+                    .and_then(|species| species.name.clone());
+                if let Some(species) = species {
+                    let species = known(pokemon::pokemon_species::get_by_name(&species).await)?;
+                    owned.gender = match species.and_then(|species| species.gender_rate) {
+                        Some(8) => Gender::Female,
+                        Some(rate) if rate >= 0 => Gender::Male,
+                        _ => Gender::Genderless,
+                    };
+                }
+            }
+            Some(_) => {}
+            None => unknown(NameKind::Pokemon, &set.pokemon),
+        }
+        if let Some(ability) = &set.ability {
+            if known(pokemon::ability::get_by_name(ability).await)?.is_none() {
+                unknown(NameKind::Ability, ability);
+            }
+        }
+        if let Some(nature) = &set.nature {
+            if known(pokemon::nature::get_by_name(nature).await)?.is_none() {
+                unknown(NameKind::Nature, nature);
+            }
+        }
+        for move_ in &set.moves {
+            if known(crate::moves::move_::get_by_name(move_).await)?.is_none() {
+                unknown(NameKind::Move, move_);
+            }
+        }
+        import.team.push(owned);
+    }
+    Ok(import)
+}
+
+/// Turns a displayed name into a PokeAPI name, e.g. `King's Rock` into `kings-rock`
+/// and `Nidoran♀` into `nidoran-f`.
+pub fn to_id(name: &str) -> String {
+    let name = name.trim().replace('♀', "-f").replace('♂', "-m");
+    let mut id = String::new();
+    for character in name.chars() {
+        match character {
+            'é' | 'É' => id.push('e'),
+            ' ' | '-' | '_' if !id.ends_with('-') => id.push('-'),
+            character if character.is_ascii_alphanumeric() => {
+                id.push(character.to_ascii_lowercase())
+            }
+            _ => {}
+        }
+    }
+    id.trim_matches('-').to_string()
+}
+
+/// Turns a PokeAPI name into a displayed name, e.g. `light-ball` into `Light Ball`.
+pub fn display_name(id: &str) -> String {
+    title_case(id, " ")
+}
+
+/// Turns the PokeAPI name of a Pokémon into the name Showdown gives it, which keeps
+/// the hyphen of forms, e.g. `rotom-wash` into `Rotom-Wash`.
+pub fn display_pokemon_name(id: &str) -> String {
+    title_case(id, "-")
+}
+
+fn title_case(id: &str, separator: &str) -> String {
+    let words: Vec<String> = id
+        .split('-')
+        .map(|word| {
+            let mut characters = word.chars();
+            characters
+                .next()
+                .map(|first| first.to_uppercase().chain(characters).collect())
+                .unwrap_or_default()
+        })
+        .collect();
+    words.join(separator)
+}
+
+fn parse_first_line(line: &str) -> Result<Set, String> {
+    let mut set = Set::default();
+    let (names, item) = match line.split_once(" @ ") {
+        Some((names, item)) => (names.trim(), Some(to_id(item))),
+        None => (line, None),
+    };
+    set.item = item;
+
+    let mut names = names.to_string();
+    for (marker, gender) in [(" (M)", Gender::Male), (" (F)", Gender::Female)] {
+        if let Some(stripped) = names.strip_suffix(marker) {
+            set.gender = Some(gender);
+            names = stripped.to_string();
+        }
+    }
+    match names.rsplit_once(" (") {
+        Some((nickname, species)) if species.ends_with(')') => {
+            set.nickname = Some(nickname.trim().to_string());
+            set.pokemon = to_id(species.trim_end_matches(')'));
+        }
+        _ => set.pokemon = to_id(&names),
+    }
+    if set.pokemon.is_empty() {
+        return Err(format!("no Pokémon is named in \"{}\"", line));
+    }
+    Ok(set)
+}
+
+fn parse_number(text: &str) -> Result<i64, String> {
+    text.trim()
+        .parse()
+        .map_err(|_| format!("\"{}\" is not a number", text.trim()))
+}
+
+fn parse_spread(text: &str, stats: &mut Stats) -> Result<(), String> {
+    for part in text.split('/') {
+        let (value, abbreviation) = part
+            .trim()
+            .split_once(' ')
+            .ok_or_else(|| format!("\"{}\" is not a stat value", part.trim()))?;
+        let index = STAT_ABBREVIATIONS
+            .iter()
+            .position(|known| known.eq_ignore_ascii_case(abbreviation.trim()))
+            .ok_or_else(|| format!("\"{}\" is not a stat", abbreviation.trim()))?;
+        if let Some(stat) = stats.get_mut(Stats::NAMES[index]) {
+            *stat = parse_number(value)?;
+        }
+    }
+    Ok(())
+}
+
+fn write_spread(
+    f: &mut fmt::Formatter<'_>,
+    label: &str,
+    stats: &Stats,
+    default: i64,
+) -> fmt::Result {
+    let parts: Vec<String> = Stats::NAMES
+        .iter()
+        .zip(STAT_ABBREVIATIONS)
+        .filter_map(|(name, abbreviation)| {
+            let value = stats.get(name)?;
+            (value != default).then(|| format!("{} {}", value, abbreviation))
+        })
+        .collect();
+    if parts.is_empty() {
+        return Ok(());
+    }
+    writeln!(f, "{}: {}", label, parts.join(" / "))
+}
+
+/// Treats a missing resource, or one that is not the expected JSON, as an unknown name.
+fn known<T>(result: Result<T, Error>) -> Result<Option<T>, ImportError> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(Error::NotFound(_) | Error::Decode(_)) => Ok(None),
+        Err(error) => Err(ImportError::Request(error)),
+    }
+}
@@ -0,0 +1,435 @@
+//! Data sources
+//!
+//! Where the endpoint functions get the JSON of resources from. PokeAPI over HTTP is used by default and
+//! [`set_source`] installs any other [`DataSource`], e.g. fixture files in tests or an offline
+//! [`LocalSource`](crate::bundle::LocalSource), alone or as a [`Fallback`] chain.
+
+use std::{
+    collections::HashMap,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, OnceLock, RwLock,
+    },
+};
+
+use async_trait::async_trait;
+use cached::{Cached, SizedCache};
+use reqwest::{
+    header::{HeaderMap, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED},
+    Client, StatusCode, Url,
+};
+
+use crate::{
+    error::Error,
+    policy::{self, RequestPolicy},
+};
+
+/// The root of PokeAPI, which resource paths are relative to.
+pub const API: &str = "https://pokeapi.co/api/v2/";
+
+/// How many requests `get_many_by_id` and `get_many_by_name` have in flight at once when asked for 0.
+pub const DEFAULT_CONCURRENCY: usize = 8;
+
+/// How many responses [`Http::with_revalidation`] keeps, dropping the least recently fetched first.
+pub const KEPT_RESPONSES: usize = 1024;
+
+static SOURCE: RwLock<Option<Arc<dyn DataSource>>> = RwLock::new(None);
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Fetches the JSON of resources by their path relative to [`API`], e.g. `pokemon/25`,
+/// `pokemon/pikachu` or `pokemon/25/encounters`.
+#[async_trait]
+pub trait DataSource: fmt::Debug + Send + Sync {
+    /// The JSON of the resource at a path, or [`Error::NotFound`] if there is none.
+    async fn fetch(&self, path: &str) -> Result<String, Error>;
+}
+
+/// Makes the endpoint functions fetch from a source. Resources they returned from earlier sources
+/// are not served from their cache again.
+pub fn set_source(source: impl DataSource + 'static) {
+    *SOURCE.write().unwrap() = Some(Arc::new(source));
+    GENERATION.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Makes the endpoint functions fetch from PokeAPI again.
+pub fn reset_source() {
+    *SOURCE.write().unwrap() = None;
+    GENERATION.fetch_add(1, Ordering::Relaxed);
+}
+
+/// The source the endpoint functions fetch from.
+pub fn source() -> Arc<dyn DataSource> {
+    static DEFAULT: OnceLock<Arc<dyn DataSource>> = OnceLock::new();
+    SOURCE.read().unwrap().clone().unwrap_or_else(|| {
+        DEFAULT
+            .get_or_init(|| Arc::new(Http::new().with_revalidation()))
+            .clone()
+    })
+}
+
+/// Counts the sources installed, so that the endpoint caches can tell results apart by source.
+pub(crate) fn generation() -> u64 {
+    GENERATION.load(Ordering::Relaxed)
+}
+
+/// PokeAPI, or a server mirroring it, over HTTP. Requests follow a [`RequestPolicy`], the default one
+/// unless [`with_policy`](Http::with_policy) gives another.
+///
+/// With [`with_revalidation`](Http::with_revalidation), responses are kept with their `ETag` and
+/// `Last-Modified` validators, so that fetching a resource again, e.g. once the endpoint cache has expired
+/// it, only downloads it if it changed. While the server is down, or keeps failing with a 5xx or 429 status,
+/// the response kept is served instead. Only the responses most recently fetched are kept, and clones share them.
+#[derive(Debug, Clone)]
+pub struct Http {
+    base: Url,
+    client: Client,
+    policy: RequestPolicy,
+    /// The responses most recently fetched, by path, if revalidation is on.
+    responses: Option<Arc<Mutex<SizedCache<String, Stored>>>>,
+}
+
+/// A response kept by [`Http`] with the validators to check it is still current.
+#[derive(Debug, Clone)]
+struct Stored {
+    json: String,
+    etag: Option<String>,
+    last_modified: Option<String>,
+}
+
+impl Stored {
+    /// The headers making a request conditional on the resource having changed.
+    fn conditions(&self) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        if let Some(etag) = self.etag.as_deref().and_then(|etag| etag.parse().ok()) {
+            headers.insert(IF_NONE_MATCH, etag);
+        }
+        if let Some(date) = self
+            .last_modified
+            .as_deref()
+            .and_then(|date| date.parse().ok())
+        {
+            headers.insert(IF_MODIFIED_SINCE, date);
+        }
+        headers
+    }
+}
+
+impl Http {
+    /// Fetches from PokeAPI.
+    pub fn new() -> Self {
+        Self::with_base(Url::parse(API).unwrap())
+    }
+
+    /// Fetches from a server with the same paths as PokeAPI under another root,
+    /// e.g. `http://localhost:8000/api/v2/`.
+    pub fn with_base(base: Url) -> Self {
+        Self {
+            base,
+            client: Client::new(),
+            policy: RequestPolicy::default(),
+            responses: None,
+        }
+    }
+
+    /// Makes requests follow another policy, e.g. to retry more or with a rate limiter of their own.
+    pub fn with_policy(mut self, policy: RequestPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Keeps the last [`KEPT_RESPONSES`] responses to revalidate them when fetched again and to serve
+    /// them while the server fails.
+    pub fn with_revalidation(self) -> Self {
+        self.with_revalidation_of(KEPT_RESPONSES)
+    }
+
+    /// Keeps the last `responses` responses, at least one, to revalidate them when fetched again and
+    /// to serve them while the server fails. The responses are held in memory, so keeping a lot of them
+    /// is for sources fetching the same resources repeatedly rather than for downloading all of PokeAPI.
+    pub fn with_revalidation_of(mut self, responses: usize) -> Self {
+        self.responses = Some(Arc::new(Mutex::new(SizedCache::with_size(
+            responses.max(1),
+        ))));
+        self
+    }
+}
+
+impl Default for Http {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl DataSource for Http {
+    async fn fetch(&self, path: &str) -> Result<String, Error> {
+        let url = self
+            .base
+            .join(path)
+            .map_err(|_| Error::NotFound(path.to_string()))?;
+        let stored = self.responses.as_ref().and_then(|responses| {
+            responses
+                .lock()
+                .unwrap()
+                .cache_get(&path.to_string())
+                .cloned()
+        });
+        let conditions = stored.as_ref().map(Stored::conditions).unwrap_or_default();
+        let response = match self.policy.get(&self.client, url, conditions).await {
+            Ok(response) => response,
+            Err(error) if error.is_timeout() || error.is_connect() => {
+                return stored.map(|stored| stored.json).ok_or(error.into());
+            }
+            Err(error) => return Err(error.into()),
+        };
+
+        let status = response.status();
+        if let (StatusCode::NOT_MODIFIED, Some(stored)) = (status, &stored) {
+            return Ok(stored.json.clone());
+        }
+        if status == StatusCode::NOT_FOUND {
+            if let Some(responses) = &self.responses {
+                responses.lock().unwrap().cache_remove(&path.to_string());
+            }
+            return Err(Error::NotFound(path.to_string()));
+        }
+        if policy::is_transient(status) {
+            if let Some(stored) = stored {
+                return Ok(stored.json);
+            }
+        }
+
+        let response = response.error_for_status()?;
+        let Some(responses) = &self.responses else {
+            return Ok(response.text().await?);
+        };
+        let header = |name| {
+            let value = response.headers().get(name)?.to_str().ok()?;
+            Some(value.to_string())
+        };
+        let (etag, last_modified) = (header(ETAG), header(LAST_MODIFIED));
+        let json = response.text().await?;
+        responses.lock().unwrap().cache_set(
+            path.to_string(),
+            Stored {
+                json: json.clone(),
+                etag,
+                last_modified,
+            },
+        );
+        Ok(json)
+    }
+}
+
+/// A directory of JSON files laid out like the paths of PokeAPI, each resource in an `index.json`,
+/// e.g. `pokemon/25/index.json`. This is the layout of the
+/// [PokeAPI api-data repository](https://github.com/PokeAPI/api-data) under `data/api/v2`.
+///
+/// A page of a list, e.g. `pokemon/?offset=20&limit=20`, is read from a file named after its query,
+/// `pokemon/index.offset=20&limit=20.json`, or from the `index.json` of the list if there is none.
+///
+/// A resource requested by name that has no directory of its own is looked for among the resources
+/// of its endpoint, so fixtures only need to be stored under their ID.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixtureDir {
+    dir: PathBuf,
+}
+
+impl FixtureDir {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    fn read(&self, path: &str) -> Result<String, Error> {
+        let file = file_for(&self.dir, path).ok_or_else(|| Error::NotFound(path.to_string()))?;
+        match fs::read_to_string(&file) {
+            Ok(json) => Ok(json),
+            Err(error) if error.kind() == io::ErrorKind::NotFound && query(path).is_some() => {
+                self.read(resource_path(path))
+            }
+            Err(error) if error.kind() == io::ErrorKind::NotFound => self.find_by_name(path),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    fn find_by_name(&self, path: &str) -> Result<String, Error> {
+        let not_found = || Error::NotFound(path.to_string());
+        let (endpoint, name) = resource_path(path)
+            .split_once('/')
+            .filter(|(_, name)| !name.contains('/') && name.parse::<i64>().is_err())
+            .ok_or_else(not_found)?;
+        let entries = match fs::read_dir(self.dir.join(endpoint)) {
+            Ok(entries) => entries,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Err(not_found()),
+            Err(error) => return Err(error.into()),
+        };
+        for entry in entries {
+            let Ok(json) = fs::read_to_string(entry?.path().join("index.json")) else {
+                continue;
+            };
+            let value: serde_json::Value = serde_json::from_str(&json)?;
+            if value["name"].as_str() == Some(name) {
+                return Ok(json);
+            }
+        }
+        Err(not_found())
+    }
+}
+
+#[async_trait]
+impl DataSource for FixtureDir {
+    async fn fetch(&self, path: &str) -> Result<String, Error> {
+        self.read(path)
+    }
+}
+
+/// Keeps what another source fetched in a directory laid out like a [`FixtureDir`],
+/// and serves it from there afterwards.
+#[derive(Debug)]
+pub struct DiskCache {
+    dir: PathBuf,
+    inner: Box<dyn DataSource>,
+}
+
+impl DiskCache {
+    pub fn new(dir: impl Into<PathBuf>, inner: impl DataSource + 'static) -> Self {
+        Self {
+            dir: dir.into(),
+            inner: Box::new(inner),
+        }
+    }
+}
+
+#[async_trait]
+impl DataSource for DiskCache {
+    async fn fetch(&self, path: &str) -> Result<String, Error> {
+        let file = file_for(&self.dir, path).ok_or_else(|| Error::NotFound(path.to_string()))?;
+        match fs::read_to_string(&file) {
+            Ok(json) => return Ok(json),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+            Err(error) => return Err(error.into()),
+        }
+        let json = self.inner.fetch(path).await?;
+        if let Some(parent) = file.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&file, &json)?;
+        Ok(json)
+    }
+}
+
+/// Resources held in memory by their path, with the query for pages of a list.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct Memory {
+    resources: HashMap<String, String>,
+}
+
+impl Memory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the JSON of a resource, replacing any earlier one at the same path.
+    pub fn insert(&mut self, path: &str, json: impl Into<String>) {
+        self.resources.insert(key(path), json.into());
+    }
+
+    /// Adds a resource under both its ID and its name, as PokeAPI serves it, e.g. `pokemon/25`
+    /// and `pokemon/pikachu` for `endpoint` `pokemon`.
+    pub fn insert_resource(&mut self, endpoint: &str, json: &str) -> Result<(), Error> {
+        let value: serde_json::Value = serde_json::from_str(json)?;
+        if let Some(id) = value["id"].as_i64() {
+            self.insert(&format!("{}/{}", endpoint, id), json);
+        }
+        if let Some(name) = value["name"].as_str() {
+            self.insert(&format!("{}/{}", endpoint, name), json);
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl DataSource for Memory {
+    async fn fetch(&self, path: &str) -> Result<String, Error> {
+        self.resources
+            .get(&key(path))
+            .cloned()
+            .ok_or_else(|| Error::NotFound(path.to_string()))
+    }
+}
+
+/// Tries sources in order, returning the first resource found. If every source fails,
+/// the error of the last one is returned.
+#[derive(Debug, Default)]
+pub struct Fallback {
+    sources: Vec<Box<dyn DataSource>>,
+}
+
+impl Fallback {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a source to try after those already in the chain.
+    pub fn then(mut self, source: impl DataSource + 'static) -> Self {
+        self.sources.push(Box::new(source));
+        self
+    }
+}
+
+#[async_trait]
+impl DataSource for Fallback {
+    async fn fetch(&self, path: &str) -> Result<String, Error> {
+        let mut last = Error::NotFound(path.to_string());
+        for source in &self.sources {
+            match source.fetch(path).await {
+                Ok(json) => return Ok(json),
+                Err(error) => last = error,
+            }
+        }
+        Err(last)
+    }
+}
+
+/// A path without its query or surrounding slashes, e.g. `pokemon` for `pokemon/?limit=20`.
+fn resource_path(path: &str) -> &str {
+    path.split('?').next().unwrap_or_default().trim_matches('/')
+}
+
+/// The query of a path, e.g. `offset=20&limit=20` for `pokemon/?offset=20&limit=20`, if it has one.
+fn query(path: &str) -> Option<&str> {
+    path.split_once('?')
+        .map(|(_, query)| query)
+        .filter(|query| !query.is_empty())
+}
+
+/// A path without surrounding slashes but with its query, so that each page of a list is kept apart,
+/// e.g. `pokemon?offset=20&limit=20`.
+fn key(path: &str) -> String {
+    match query(path) {
+        Some(query) => format!("{}?{}", resource_path(path), query),
+        None => resource_path(path).to_string(),
+    }
+}
+
+/// The `index.json` file of a path in a directory, the root one for an empty path, or the
+/// `index.<query>.json` file of a page of a list, unless the path could leave the directory.
+fn file_for(dir: &Path, path: &str) -> Option<PathBuf> {
+    let mut file = dir.to_path_buf();
+    for part in resource_path(path)
+        .split('/')
+        .filter(|part| !part.is_empty())
+    {
+        if part == "." || part == ".." || part.contains('\\') {
+            return None;
+        }
+        file.push(part);
+    }
+    match query(path) {
+        Some(query) if query.contains(['/', '\\']) => None,
+        Some(query) => Some(file.join(format!("index.{}.json", query))),
+        None => Some(file.join("index.json")),
+    }
+}
@@ -0,0 +1,157 @@
+//! Permanent stats of Pokémon
+
+use std::ops::RangeInclusive;
+
+use crate::model::{
+    pokemon::{Nature, PokemonStat},
+    resource::name,
+};
+
+/// A full set of the six permanent stats, used for base stats, IVs, EVs and calculated stats alike.
+/// See [Bulbapedia](http://bulbapedia.bulbagarden.net/wiki/Stat) for greater detail.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Stats {
+    pub hp: i64,
+    pub attack: i64,
+    pub defence: i64,
+    pub special_attack: i64,
+    pub special_defence: i64,
+    pub speed: i64,
+}
+
+impl Stats {
+    /// The PokeAPI names of the six permanent stats, in game order.
+    pub const NAMES: [&'static str; 6] = [
+        "hp",
+        "attack",
+        "defense",
+        "special-attack",
+        "special-defense",
+        "speed",
+    ];
+
+    /// Reads the base stats from the `stats` list of a [`Pokemon`](crate::model::pokemon::Pokemon).
+    /// Stats missing from the list are left at 0.
+    pub fn from_base_stats(stats: &[PokemonStat]) -> Self {
+        let mut base = Stats::default();
+        for stat in stats {
+            if let Some(value) = name(&stat.stat).and_then(|name| base.get_mut(name)) {
+                *value = stat.base_stat.unwrap_or_default();
+            }
+        }
+        base
+    }
+
+    /// Gets a stat by its PokeAPI name, e.g. `special-attack`.
+    pub fn get(&self, name: &str) -> Option<i64> {
+        match name {
+            "hp" => Some(self.hp),
+            "attack" => Some(self.attack),
+            "defense" => Some(self.defence),
+            "special-attack" => Some(self.special_attack),
+            "special-defense" => Some(self.special_defence),
+            "speed" => Some(self.speed),
+            _ => None,
+        }
+    }
+
+    /// Gets a mutable reference to a stat by its PokeAPI name, e.g. `special-attack`.
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut i64> {
+        match name {
+            "hp" => Some(&mut self.hp),
+            "attack" => Some(&mut self.attack),
+            "defense" => Some(&mut self.defence),
+            "special-attack" => Some(&mut self.special_attack),
+            "special-defense" => Some(&mut self.special_defence),
+            "speed" => Some(&mut self.speed),
+            _ => None,
+        }
+    }
+}
+
+/// Calculates the stats of an individual Pokémon from its base stats, IVs, EVs, level and nature.
+/// See [Bulbapedia](http://bulbapedia.bulbagarden.net/wiki/Stat#Generation_III_onward) for greater detail.
+pub fn calculate(
+    base: &Stats,
+    iv: &Stats,
+    ev: &Stats,
+    level: i64,
+    nature: Option<&Nature>,
+) -> Stats {
+    let core = |base: i64, iv: i64, ev: i64| (2 * base + iv + ev / 4) * level / 100;
+    let other = |name: &str, base: i64, iv: i64, ev: i64| {
+        (core(base, iv, ev) + 5) * nature_percent(nature, name) / 100
+    };
+    Stats {
+        hp: core(base.hp, iv.hp, ev.hp) + level + 10,
+        attack: other("attack", base.attack, iv.attack, ev.attack),
+        defence: other("defense", base.defence, iv.defence, ev.defence),
+        special_attack: other(
+            "special-attack",
+            base.special_attack,
+            iv.special_attack,
+            ev.special_attack,
+        ),
+        special_defence: other(
+            "special-defense",
+            base.special_defence,
+            iv.special_defence,
+            ev.special_defence,
+        ),
+        speed: other("speed", base.speed, iv.speed, ev.speed),
+    }
+}
+
+/// The percentage a nature applies to a stat: 110 when raised, 90 when lowered and 100 otherwise.
+pub fn nature_percent(nature: Option<&Nature>, stat: &str) -> i64 {
+    let increased = nature.and_then(|nature| name(&nature.increased_stat));
+    let decreased = nature.and_then(|nature| name(&nature.decreased_stat));
+    match (increased == Some(stat), decreased == Some(stat)) {
+        (true, false) => 110,
+        (false, true) => 90,
+        _ => 100,
+    }
+}
+
+/// The values each IV of a Pokémon may still have, in the order of [`Stats::NAMES`].
+/// Starts out allowing every value from 0 to 31 and is narrowed down by what is known of the Pokémon.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PossibleIvs(pub [Vec<i64>; 6]);
+
+impl Default for PossibleIvs {
+    fn default() -> Self {
+        Self(std::array::from_fn(|_| (0..=31).collect()))
+    }
+}
+
+impl PossibleIvs {
+    /// The values an IV may have, by the PokeAPI name of its stat.
+    pub fn get(&self, name: &str) -> Option<&[i64]> {
+        let index = Stats::NAMES.iter().position(|stat| *stat == name)?;
+        Some(&self.0[index])
+    }
+
+    /// The lowest and highest values an IV may have, by the PokeAPI name of its stat.
+    /// `None` if it has no possible value left.
+    pub fn range(&self, name: &str) -> Option<RangeInclusive<i64>> {
+        let values = self.get(name)?;
+        Some(*values.iter().min()?..=*values.iter().max()?)
+    }
+
+    /// Keeps the values of an IV that satisfy a predicate.
+    pub fn retain(&mut self, name: &str, keep: impl FnMut(&i64) -> bool) {
+        if let Some(index) = Stats::NAMES.iter().position(|stat| *stat == name) {
+            self.0[index].retain(keep);
+        }
+    }
+
+    /// Whether some IV has no possible value left, meaning what is known is contradictory.
+    pub fn is_contradictory(&self) -> bool {
+        self.0.iter().any(Vec::is_empty)
+    }
+
+    /// Whether every IV has a single possible value.
+    pub fn is_settled(&self) -> bool {
+        self.0.iter().all(|values| values.len() == 1)
+    }
+}
@@ -0,0 +1,76 @@
+//! Type effectiveness built from the `type` endpoint
+
+use std::collections::HashMap;
+
+use crate::model::{
+    pokemon::{Type, TypeRelations},
+    resource::{name, NamedApiResource},
+};
+
+/// Damage multipliers between types, built from the [`TypeRelations`] of fetched [`Type`] resources.
+/// Types that were never inserted are treated as neutral against everything.
+/// See [Bulbapedia](http://bulbapedia.bulbagarden.net/wiki/Type) for greater detail.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct TypeChart {
+    relations: HashMap<String, TypeRelations>,
+}
+
+impl TypeChart {
+    /// Creates an empty chart in which every matchup is neutral.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a chart from a list of fetched types.
+    pub fn from_types<'a>(types: impl IntoIterator<Item = &'a Type>) -> Self {
+        let mut chart = Self::new();
+        for type_ in types {
+            chart.insert(type_);
+        }
+        chart
+    }
+
+    /// Adds the damage relations of a type to the chart, replacing any earlier entry of the same name.
+    pub fn insert(&mut self, type_: &Type) {
+        if let (Some(name), Some(relations)) = (&type_.name, &type_.damage_relations) {
+            self.relations.insert(name.clone(), relations.clone());
+        }
+    }
+
+    /// The names of every type in the chart.
+    pub fn types(&self) -> impl Iterator<Item = &str> {
+        self.relations.keys().map(String::as_str)
+    }
+
+    /// The damage multiplier of an attacking type against a single defending type.
+    pub fn multiplier(&self, attacking: &str, defending: &str) -> f64 {
+        let relations = match self.relations.get(attacking) {
+            Some(relations) => relations,
+            None => return 1.0,
+        };
+        if contains(&relations.no_damage_to, defending) {
+            0.0
+        } else if contains(&relations.half_damage_to, defending) {
+            0.5
+        } else if contains(&relations.double_damage_to, defending) {
+            2.0
+        } else {
+            1.0
+        }
+    }
+
+    /// The combined damage multiplier of an attacking type against every type of a defender.
+    pub fn effectiveness<S: AsRef<str>>(&self, attacking: &str, defending: &[S]) -> f64 {
+        defending
+            .iter()
+            .map(|type_| self.multiplier(attacking, type_.as_ref()))
+            .product()
+    }
+}
+
+fn contains(resources: &Option<Vec<NamedApiResource>>, type_: &str) -> bool {
+    resources
+        .iter()
+        .flatten()
+        .any(|resource| name(resource) == Some(type_))
+}
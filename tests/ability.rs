@@ -0,0 +1,229 @@
+//! Checks the hooks of ability effects through the abilities registered by default, and a custom one.
+
+use pokemon_game::{
+    battle::{
+        ability::{self, AbilityEffect},
+        stages,
+        status::{self, Status},
+        Battle, BattleEvent, Battler, Position, Weather,
+    },
+    generation::Generation,
+    model::moves::Move,
+    names::{PokemonTypeName, StatName},
+    owned::Gender,
+    stats::Stats,
+    type_chart::TypeChart,
+};
+
+const PLAYER: Position = Position { side: 0, slot: 0 };
+const OPPONENT: Position = Position { side: 1, slot: 0 };
+
+fn battler(name: &str, type_: PokemonTypeName) -> Battler {
+    let stats = Stats {
+        hp: 300,
+        attack: 100,
+        defence: 100,
+        special_attack: 100,
+        special_defence: 100,
+        speed: 100,
+    };
+    Battler::new(name, 50, Gender::Male, vec![type_], stats)
+}
+
+fn single(generation: Generation, player: Battler, opponent: Battler) -> Battle {
+    Battle::single(generation, TypeChart::default(), player, opponent, 0)
+}
+
+/// A move that never misses, built from the JSON PokeAPI would return for it.
+fn move_(name: &str, power: i64, type_: &str) -> Move {
+    serde_json::from_str(&format!(
+        r#"{{
+            "name": "{}",
+            "power": {},
+            "damage_class": {{"name": "physical"}},
+            "type": {{"name": "{}"}},
+            "target": {{"name": "selected-pokemon"}}
+        }}"#,
+        name, power, type_
+    ))
+    .unwrap()
+}
+
+fn activations(battle: &Battle, holder: Position) -> usize {
+    battle
+        .log
+        .iter()
+        .filter(|event| matches!(event, BattleEvent::AbilityActivated { holder: at, .. } if *at == holder))
+        .count()
+}
+
+#[test]
+fn intimidate_only_reaches_adjacent_opponents() {
+    let side = |name: &str| {
+        [0, 1, 2].map(|slot| battler(&format!("{} {}", name, slot), PokemonTypeName::Normal))
+    };
+    let [left, middle, right] = side("Gyarados");
+    let mut battle = Battle::new(
+        Generation::V,
+        TypeChart::default(),
+        [
+            vec![left.with_ability("intimidate"), middle, right],
+            side("Machamp").into(),
+        ],
+        0,
+    );
+    battle.start();
+    assert_eq!(activations(&battle, PLAYER), 1);
+    // The left of one side faces the right of the other, out of reach of its left.
+    let attack = |slot| battle.battler(Position::new(1, slot)).stages.attack;
+    assert_eq!([attack(0), attack(1), attack(2)], [0, -1, -1]);
+    assert_eq!(battle.battler(Position::new(0, 1)).stages.attack, 0);
+}
+
+#[test]
+fn static_only_activates_when_it_paralyzes() {
+    let pikachu = || battler("Pikachu", PokemonTypeName::Electric).with_ability("static");
+
+    // Electric types cannot be paralyzed from Generation VI.
+    let mut battle = single(
+        Generation::VI,
+        battler("Jolteon", PokemonTypeName::Electric),
+        pikachu(),
+    );
+    for _ in 0..20 {
+        battle
+            .use_move(PLAYER, None, &move_("tackle", 10, "normal"))
+            .unwrap();
+    }
+    assert_eq!(activations(&battle, OPPONENT), 0);
+    assert_eq!(battle.battler(PLAYER).status, None);
+
+    // Once paralyzed, the attacker cannot be paralyzed again.
+    let mut battle = single(
+        Generation::VI,
+        battler("Snorlax", PokemonTypeName::Normal),
+        pikachu(),
+    );
+    for _ in 0..20 {
+        battle
+            .use_move(PLAYER, None, &move_("tackle", 10, "normal"))
+            .unwrap();
+    }
+    assert_eq!(activations(&battle, OPPONENT), 1);
+    assert_eq!(battle.battler(PLAYER).status, Some(Status::Paralysis));
+}
+
+#[test]
+fn defensive_abilities_change_the_damage_taken() {
+    let mut battle = single(
+        Generation::V,
+        battler("Golem", PokemonTypeName::Ground),
+        battler("Gengar", PokemonTypeName::Ghost).with_ability("levitate"),
+    );
+    battle
+        .use_move(PLAYER, None, &move_("earthquake", 100, "ground"))
+        .unwrap();
+    assert!(battle
+        .log
+        .contains(&BattleEvent::NoEffect { target: OPPONENT }));
+    assert_eq!(battle.battler(OPPONENT).hp, 300);
+
+    // Sturdy keeps a Pokémon at full HP from being knocked out in one hit from Generation V.
+    for (generation, hp) in [(Generation::IV, 0), (Generation::V, 1)] {
+        let mut battle = single(
+            generation,
+            battler("Machamp", PokemonTypeName::Fighting),
+            battler("Geodude", PokemonTypeName::Rock).with_ability("sturdy"),
+        );
+        battle.battler_mut(PLAYER).stats.attack = 1000;
+        battle
+            .use_move(PLAYER, None, &move_("mega-kick", 250, "normal"))
+            .unwrap();
+        assert_eq!(battle.battler(OPPONENT).hp.max(0), hp, "{:?}", generation);
+    }
+}
+
+#[test]
+fn pinch_and_weather_abilities_scale_attack_and_speed() {
+    let mut battle = single(
+        Generation::V,
+        battler("Charmander", PokemonTypeName::Fire).with_ability("blaze"),
+        battler("Lotad", PokemonTypeName::Water).with_ability("swift-swim"),
+    );
+    let ember = move_("ember", 40, "fire");
+    let tackle = move_("tackle", 40, "normal");
+    assert_eq!(
+        ability::attack_multiplier(&battle, PLAYER, OPPONENT, &ember),
+        1.0
+    );
+    battle.battler_mut(PLAYER).hp = 100;
+    assert_eq!(
+        ability::attack_multiplier(&battle, PLAYER, OPPONENT, &ember),
+        1.5
+    );
+    assert_eq!(
+        ability::attack_multiplier(&battle, PLAYER, OPPONENT, &tackle),
+        1.0
+    );
+
+    assert_eq!(ability::speed_multiplier(&battle, OPPONENT), 1.0);
+    battle.weather = Some(Weather::Rain);
+    assert_eq!(ability::speed_multiplier(&battle, OPPONENT), 2.0);
+    battle.weather = Some(Weather::Sun);
+    assert_eq!(ability::speed_multiplier(&battle, OPPONENT), 1.0);
+}
+
+#[test]
+fn status_immunities_keep_statuses_away() {
+    let mut battle = single(
+        Generation::V,
+        battler("Persian", PokemonTypeName::Normal).with_ability("limber"),
+        battler("Snorlax", PokemonTypeName::Normal).with_ability("immunity"),
+    );
+    assert!(!status::inflict_status(
+        &mut battle,
+        PLAYER,
+        Status::Paralysis
+    ));
+    assert!(status::inflict_status(&mut battle, PLAYER, Status::Burn));
+    assert!(!status::inflict_status(
+        &mut battle,
+        OPPONENT,
+        Status::Toxic { turns: 0 }
+    ));
+    assert!(status::inflict_status(
+        &mut battle,
+        OPPONENT,
+        Status::Paralysis
+    ));
+}
+
+/// Raises the Speed of its holder at the end of every turn.
+#[derive(Debug)]
+struct SpeedBoost;
+
+impl AbilityEffect for SpeedBoost {
+    fn on_end_of_turn(&self, battle: &mut Battle, holder: Position) {
+        ability::activated(battle, holder);
+        stages::change_stage(battle, holder, StatName::Speed, 1);
+    }
+}
+
+#[test]
+fn registered_abilities_take_effect() {
+    let mut battle = single(
+        Generation::V,
+        battler("Yanmega", PokemonTypeName::Bug).with_ability("speed-boost"),
+        battler("Snorlax", PokemonTypeName::Normal),
+    );
+    battle.end_turn();
+    assert_eq!(battle.battler(PLAYER).stages.speed, 0);
+
+    battle.abilities.register("speed-boost", SpeedBoost);
+    battle.end_turn();
+    battle.end_turn();
+    assert_eq!(battle.battler(PLAYER).stages.speed, 2);
+    assert_eq!(activations(&battle, PLAYER), 2);
+    assert!(battle.abilities.get("speed-boost").is_some());
+    assert!(battle.abilities.get("speed-bost").is_none());
+}
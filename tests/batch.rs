@@ -0,0 +1,94 @@
+//! Checks fetching several resources at once against the fixtures, through a source that counts the
+//! requests it receives.
+
+mod common;
+
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use common::fixtures;
+use pokemon_game::{
+    error::Error,
+    moves, pokemon,
+    source::{self, DataSource, FixtureDir},
+};
+
+/// Serves the fixtures slowly, so that requests overlap, recording every path it is asked for.
+#[derive(Debug, Clone)]
+struct Counting {
+    inner: FixtureDir,
+    paths: Arc<Mutex<Vec<String>>>,
+    in_flight: Arc<AtomicUsize>,
+    most_in_flight: Arc<AtomicUsize>,
+}
+
+impl Counting {
+    fn new() -> Self {
+        Self {
+            inner: FixtureDir::new(fixtures()),
+            paths: Arc::default(),
+            in_flight: Arc::default(),
+            most_in_flight: Arc::default(),
+        }
+    }
+
+    fn requests_for(&self, path: &str) -> usize {
+        let paths = self.paths.lock().unwrap();
+        paths.iter().filter(|requested| *requested == path).count()
+    }
+}
+
+#[async_trait]
+impl DataSource for Counting {
+    async fn fetch(&self, path: &str) -> Result<String, Error> {
+        self.paths.lock().unwrap().push(path.to_string());
+        let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.most_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+        self.inner.fetch(path).await
+    }
+}
+
+// The source is global, so everything is checked in one test.
+#[tokio::test]
+async fn get_many() {
+    let counting = Counting::new();
+    source::set_source(counting.clone());
+
+    let results = pokemon::pokemon::get_many_by_name(&["pikachu", "missingno", "pikachu"], 2).await;
+    assert_eq!(results.len(), 3);
+    assert_eq!(
+        results[0].as_ref().unwrap().name.as_deref(),
+        Some("pikachu")
+    );
+    assert!(
+        matches!(results[1], Err(Error::NotFound(_))),
+        "{:?}",
+        results[1]
+    );
+    assert_eq!(results[2].as_ref().unwrap(), results[0].as_ref().unwrap());
+    assert_eq!(counting.requests_for("pokemon/pikachu"), 1);
+
+    let results = moves::move_::get_many_by_id(&[85; 4], 4).await;
+    assert!(results
+        .iter()
+        .all(|result| result.as_ref().unwrap().id == Some(85)));
+    assert_eq!(counting.requests_for("move/85"), 1);
+
+    counting.most_in_flight.store(0, Ordering::SeqCst);
+    let names = ["steel", "electric", "rock", "ice", "dark"];
+    let results = pokemon::type_::get_many_by_name(&names, 2).await;
+    let ids: Vec<_> = results
+        .iter()
+        .map(|result| result.as_ref().ok().and_then(|type_| type_.id))
+        .collect();
+    assert_eq!(ids, [Some(9), Some(13), None, None, None]);
+    assert_eq!(counting.most_in_flight.load(Ordering::SeqCst), 2);
+}
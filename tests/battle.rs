@@ -0,0 +1,290 @@
+//! Checks turns of battles whose outcome does not depend on the random rolls.
+
+use pokemon_game::{
+    battle::{
+        status::CantMoveReason, target::TargetError, Action, Battle, BattleEvent, Battler,
+        DamageCause, Position,
+    },
+    generation::Generation,
+    model::moves::Move,
+    names::PokemonTypeName,
+    owned::Gender,
+    stats::Stats,
+    type_chart::TypeChart,
+};
+
+fn battler(name: &str, hp: i64, speed: i64) -> Battler {
+    let stats = Stats {
+        hp,
+        attack: 100,
+        defence: 100,
+        special_attack: 100,
+        special_defence: 100,
+        speed,
+    };
+    Battler::new(name, 50, Gender::Male, vec![PokemonTypeName::Normal], stats)
+}
+
+/// A move that never misses, built from the JSON PokeAPI would return for it.
+fn move_(json: &str) -> Move {
+    serde_json::from_str(json).unwrap()
+}
+
+fn double_edge() -> Move {
+    move_(
+        r#"{
+            "name": "double-edge",
+            "power": 120,
+            "priority": 0,
+            "damage_class": {"name": "physical"},
+            "type": {"name": "normal"},
+            "target": {"name": "selected-pokemon"},
+            "meta": {"drain": -33}
+        }"#,
+    )
+}
+
+fn tackle() -> Move {
+    move_(
+        r#"{
+            "name": "tackle",
+            "power": 40,
+            "priority": 0,
+            "damage_class": {"name": "physical"},
+            "type": {"name": "normal"},
+            "target": {"name": "selected-pokemon"}
+        }"#,
+    )
+}
+
+#[test]
+fn recoil_double_knock_out_still_ends_the_turn() {
+    let player = Position::new(0, 0);
+    let opponent = Position::new(1, 0);
+    let mut battle = Battle::single(
+        Generation::V,
+        TypeChart::new(),
+        battler("Tauros", 1, 110),
+        battler("Rattata", 1, 72),
+        0,
+    );
+    battle.battler_mut(opponent).volatiles.flinch = true;
+
+    // Neither action names its target, which is fine while there is only one opponent.
+    let actions = vec![
+        Action {
+            user: player,
+            target: None,
+            move_: double_edge(),
+        },
+        Action {
+            user: opponent,
+            target: None,
+            move_: tackle(),
+        },
+    ];
+    battle.run_turn(actions).unwrap();
+
+    assert!(battle.battler(player).is_fainted());
+    assert!(battle.battler(opponent).is_fainted());
+    assert!(battle.log.contains(&BattleEvent::Damaged {
+        target: player,
+        amount: 1,
+        cause: DamageCause::Recoil,
+    }));
+    assert!(!battle.battler(opponent).volatiles.flinch);
+}
+
+#[test]
+fn moves_at_fainted_opponents_are_redirected() {
+    let player = Position::new(0, 0);
+    let partner = Position::new(0, 1);
+    let left = Position::new(1, 0);
+    let right = Position::new(1, 1);
+    let mut battle = Battle::double(
+        Generation::V,
+        TypeChart::new(),
+        [battler("Tauros", 100, 110), battler("Snorlax", 200, 30)],
+        [battler("Rattata", 1, 72), battler("Raticate", 200, 97)],
+        0,
+    );
+
+    let actions = vec![
+        Action {
+            user: player,
+            target: Some(left),
+            move_: tackle(),
+        },
+        Action {
+            user: partner,
+            target: Some(left),
+            move_: tackle(),
+        },
+    ];
+    battle.run_turn(actions).unwrap();
+
+    assert!(battle.battler(left).is_fainted());
+    assert!(battle.battler(right).hp < 200);
+}
+
+fn fake_out() -> Move {
+    move_(
+        r#"{
+            "name": "fake-out",
+            "power": 40,
+            "priority": 3,
+            "damage_class": {"name": "physical"},
+            "type": {"name": "normal"},
+            "target": {"name": "selected-pokemon"},
+            "meta": {"flinch_chance": 100}
+        }"#,
+    )
+}
+
+fn headbutt() -> Move {
+    move_(
+        r#"{
+            "name": "headbutt",
+            "power": 70,
+            "priority": 0,
+            "damage_class": {"name": "physical"},
+            "type": {"name": "normal"},
+            "target": {"name": "selected-pokemon"},
+            "meta": {"flinch_chance": 100}
+        }"#,
+    )
+}
+
+fn flinched(battle: &Battle, user: Position) -> bool {
+    battle.log.contains(&BattleEvent::CantMove {
+        user,
+        reason: CantMoveReason::Flinched,
+    })
+}
+
+#[test]
+fn only_targets_yet_to_move_flinch() {
+    let fast = Position::new(0, 0);
+    let slow = Position::new(1, 0);
+    let battle = || {
+        Battle::single(
+            Generation::V,
+            TypeChart::new(),
+            battler("Tauros", 200, 110),
+            battler("Snorlax", 200, 30),
+            0,
+        )
+    };
+
+    let mut headbutt_first = battle();
+    headbutt_first
+        .run_turn(vec![
+            Action {
+                user: fast,
+                target: None,
+                move_: headbutt(),
+            },
+            Action {
+                user: slow,
+                target: None,
+                move_: tackle(),
+            },
+        ])
+        .unwrap();
+    assert!(flinched(&headbutt_first, slow));
+
+    // Checked before the end of the turn, which would clear the flinch anyway.
+    let mut headbutt_last = battle();
+    headbutt_last.use_move(fast, None, &tackle()).unwrap();
+    headbutt_last.use_move(slow, None, &headbutt()).unwrap();
+    assert!(!headbutt_last.battler(fast).volatiles.flinch);
+}
+
+#[test]
+fn fake_out_only_works_on_the_first_turn() {
+    let player = Position::new(0, 0);
+    let opponent = Position::new(1, 0);
+    let mut battle = Battle::single(
+        Generation::V,
+        TypeChart::new(),
+        battler("Meowth", 200, 90),
+        battler("Snorlax", 200, 30),
+        0,
+    );
+    let turn = || {
+        vec![
+            Action {
+                user: player,
+                target: None,
+                move_: fake_out(),
+            },
+            Action {
+                user: opponent,
+                target: None,
+                move_: tackle(),
+            },
+        ]
+    };
+
+    battle.run_turn(turn()).unwrap();
+    assert!(flinched(&battle, opponent));
+    assert!(!battle.log.contains(&BattleEvent::Failed { user: player }));
+
+    battle.log.clear();
+    battle.run_turn(turn()).unwrap();
+    assert!(battle.log.contains(&BattleEvent::Failed { user: player }));
+    assert!(!flinched(&battle, opponent));
+
+    // A Pokémon sent out again can use it on its first turn back.
+    battle.switch_in(player, battler("Persian", 200, 115));
+    battle.log.clear();
+    battle.run_turn(turn()).unwrap();
+    assert!(flinched(&battle, opponent));
+}
+
+fn helping_hand() -> Move {
+    move_(
+        r#"{
+            "name": "helping-hand",
+            "power": null,
+            "priority": 5,
+            "damage_class": {"name": "status"},
+            "type": {"name": "normal"},
+            "target": {"name": "ally"}
+        }"#,
+    )
+}
+
+#[test]
+fn moves_without_candidates_fail() {
+    let player = Position::new(0, 0);
+    let partner = Position::new(0, 1);
+    let mut battle = Battle::single(
+        Generation::V,
+        TypeChart::new(),
+        battler("Minun", 100, 95),
+        battler("Plusle", 100, 95),
+        0,
+    );
+    // There is no ally to help in a single battle, which is no reason to reject the choice.
+    battle.use_move(player, None, &helping_hand()).unwrap();
+    assert!(battle.log.contains(&BattleEvent::Failed { user: player }));
+
+    let mut battle = Battle::double(
+        Generation::V,
+        TypeChart::new(),
+        [battler("Minun", 100, 95), battler("Plusle", 100, 95)],
+        [battler("Rattata", 100, 72), battler("Raticate", 100, 97)],
+        0,
+    );
+    // Two opponents to choose from need a choice.
+    assert_eq!(
+        battle.use_move(player, None, &tackle()),
+        Err(TargetError::Missing)
+    );
+    battle.use_move(player, None, &helping_hand()).unwrap();
+    assert!(!battle.log.contains(&BattleEvent::Failed { user: player }));
+    battle.battler_mut(partner).hp = 0;
+    battle.use_move(player, None, &helping_hand()).unwrap();
+    assert!(battle.log.contains(&BattleEvent::Failed { user: player }));
+}
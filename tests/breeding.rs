@@ -0,0 +1,192 @@
+//! Checks that eggs follow the breeding mechanics of their generation, using Pikachu
+//! from the fixtures.
+
+mod common;
+
+use common::fixture;
+use pokemon_game::{
+    breeding::{breed, egg_moves, inherit_nature, Egg, Offspring, Parent},
+    generation::Generation,
+    model::pokemon::{Pokemon, PokemonSpecies},
+    owned::{Gender, OwnedPokemon},
+};
+use rand::{rngs::StdRng, SeedableRng};
+
+/// A Pikachu with no IVs, so that the IVs an egg inherits are the ones at zero.
+fn parent(gender: Gender, ability: &str, held_item: Option<&str>) -> OwnedPokemon {
+    let mut pokemon = OwnedPokemon::new("pikachu".to_string(), 30);
+    pokemon.gender = gender;
+    pokemon.ability = Some(ability.to_string());
+    pokemon.held_item = held_item.map(String::from);
+    pokemon
+}
+
+/// Eggs from a father holding a Destiny Knot and a mother with Pikachu's hidden ability.
+fn eggs(generation: Generation) -> Vec<Egg> {
+    let pikachu: Pokemon = serde_json::from_str(&fixture("pokemon/25")).unwrap();
+    let species: PokemonSpecies = serde_json::from_str(&fixture("pokemon-species/25")).unwrap();
+    let father = parent(Gender::Male, "static", Some("destiny-knot"));
+    let mother = parent(Gender::Female, "lightning-rod", None);
+    let father = Parent {
+        pokemon: &father,
+        species: &species,
+    };
+    let mother = Parent {
+        pokemon: &mother,
+        species: &species,
+    };
+    let offspring = Offspring {
+        pokemon: &pikachu,
+        species: &species,
+        growth_rate: None,
+    };
+    (0..50)
+        .map(|seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            breed(
+                generation, &father, &mother, &offspring, "x-y", 0, false, &mut rng,
+            )
+            .unwrap()
+        })
+        .collect()
+}
+
+fn inherited_ivs(egg: &Egg) -> usize {
+    let iv = egg.pokemon.iv;
+    [
+        iv.hp,
+        iv.attack,
+        iv.defence,
+        iv.special_attack,
+        iv.special_defence,
+        iv.speed,
+    ]
+    .into_iter()
+    .filter(|&iv| iv == 0)
+    .count()
+}
+
+fn abilities(generation: Generation) -> Vec<String> {
+    eggs(generation)
+        .into_iter()
+        .filter_map(|egg| egg.pokemon.ability)
+        .collect()
+}
+
+#[test]
+fn destiny_knot_passes_five_ivs_from_generation_vi() {
+    assert!(eggs(Generation::VI)
+        .iter()
+        .all(|egg| inherited_ivs(egg) >= 5));
+    assert!(eggs(Generation::V).iter().any(|egg| inherited_ivs(egg) < 5));
+}
+
+#[test]
+fn hidden_abilities_are_passed_down_from_generation_v() {
+    assert!(abilities(Generation::IV)
+        .iter()
+        .all(|ability| ability == "static"));
+    for generation in [Generation::V, Generation::VI] {
+        let abilities = abilities(generation);
+        assert!(
+            abilities.iter().any(|ability| ability == "lightning-rod"),
+            "{:?}",
+            generation
+        );
+        assert!(
+            abilities.iter().any(|ability| ability == "static"),
+            "{:?}",
+            generation
+        );
+    }
+}
+
+#[test]
+fn hatched_friendship_is_the_base_friendship_from_generation_viii() {
+    assert_eq!(eggs(Generation::VII)[0].pokemon.friendship, 120);
+    assert_eq!(eggs(Generation::VIII)[0].pokemon.friendship, 50);
+}
+
+/// How many of 100 eggs take the nature of the parent holding an Everstone.
+fn everstone_natures(generation: Generation, holder: Gender) -> usize {
+    let species: PokemonSpecies = serde_json::from_str(&fixture("pokemon-species/25")).unwrap();
+    let mut father = parent(Gender::Male, "static", None);
+    let mut mother = parent(Gender::Female, "static", None);
+    let everstone = if holder == Gender::Male {
+        &mut father
+    } else {
+        &mut mother
+    };
+    everstone.held_item = Some("everstone".to_string());
+    // A nature no random roll can give.
+    everstone.nature = Some("inherited".to_string());
+    let (father, mother) = (
+        Parent {
+            pokemon: &father,
+            species: &species,
+        },
+        Parent {
+            pokemon: &mother,
+            species: &species,
+        },
+    );
+    let mut rng = StdRng::seed_from_u64(0);
+    (0..100)
+        .filter_map(|_| inherit_nature(generation, &father, &mother, &mut rng))
+        .filter(|nature| nature == "inherited")
+        .count()
+}
+
+#[test]
+fn everstone_passes_the_nature_down_by_generation() {
+    assert_eq!(everstone_natures(Generation::III, Gender::Male), 0);
+    assert!((30..70).contains(&everstone_natures(Generation::III, Gender::Female)));
+    assert!((30..70).contains(&everstone_natures(Generation::IV, Gender::Male)));
+    assert_eq!(everstone_natures(Generation::V, Gender::Male), 100);
+    assert_eq!(everstone_natures(Generation::V, Gender::Female), 100);
+}
+
+/// Pichu, which learns Wish and Encore as egg moves in X and Y.
+fn pichu() -> Pokemon {
+    let egg_move = |move_: &str| {
+        format!(
+            r#"{{
+                "move": {{"name": "{}"}},
+                "version_group_details": [{{
+                    "level_learned_at": 1,
+                    "move_learn_method": {{"name": "egg"}},
+                    "version_group": {{"name": "x-y"}}
+                }}]
+            }}"#,
+            move_
+        )
+    };
+    let json = format!(
+        r#"{{"name": "pichu", "moves": [{}, {}]}}"#,
+        egg_move("wish"),
+        egg_move("encore")
+    );
+    serde_json::from_str(&json).unwrap()
+}
+
+#[test]
+fn egg_moves_come_from_the_father_until_generation_vi() {
+    let species: PokemonSpecies = serde_json::from_str(&fixture("pokemon-species/25")).unwrap();
+    let mut father = parent(Gender::Male, "static", None);
+    father.moves = vec!["encore".to_string()];
+    let mut mother = parent(Gender::Female, "static", None);
+    mother.moves = vec!["wish".to_string()];
+    let father = Parent {
+        pokemon: &father,
+        species: &species,
+    };
+    let mother = Parent {
+        pokemon: &mother,
+        species: &species,
+    };
+    let pichu = pichu();
+    let moves = |generation| egg_moves(generation, &pichu, &mother, &father, "x-y");
+    assert_eq!(moves(Generation::II), ["encore"]);
+    assert_eq!(moves(Generation::V), ["encore"]);
+    assert_eq!(moves(Generation::VI), ["wish", "encore"]);
+}
@@ -0,0 +1,128 @@
+//! Syncs an offline bundle from fixtures written to a temporary directory and reads it back.
+
+mod common;
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use common::fixture;
+use pokemon_game::{
+    bundle::{LocalSource, BATCH_SIZE},
+    error::Error,
+    source::{DataSource, FixtureDir},
+};
+
+/// A directory in the temporary directory, removed first in case an earlier run left it behind.
+fn temporary(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&path);
+    path
+}
+
+fn write(dir: &Path, path: &str, json: &str) {
+    let dir = dir.join(path);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("index.json"), json).unwrap();
+}
+
+/// Writes the list of an endpoint, as PokeAPI URLs to the given resources.
+fn write_list(dir: &Path, endpoint: &str, ids: impl Iterator<Item = i64>) {
+    let results: Vec<String> = ids
+        .map(|id| {
+            format!(
+                r#"{{"url": "https://pokeapi.co/api/v2/{}/{}/"}}"#,
+                endpoint, id
+            )
+        })
+        .collect();
+    let list = format!(
+        r#"{{"count": {}, "next": null, "results": [{}]}}"#,
+        results.len(),
+        results.join(", ")
+    );
+    write(dir, endpoint, &list);
+}
+
+/// Pikachu and Thunderbolt, a Pokémon listed without a resource and more items than fit in a batch.
+fn pokeapi(name: &str) -> PathBuf {
+    let dir = temporary(name);
+    write(
+        &dir,
+        "",
+        r#"{
+            "item": "https://pokeapi.co/api/v2/item/",
+            "move": "https://pokeapi.co/api/v2/move/",
+            "pokemon": "https://pokeapi.co/api/v2/pokemon/"
+        }"#,
+    );
+    write_list(&dir, "pokemon", [25, 9999].into_iter());
+    write(&dir, "pokemon/25", &fixture("pokemon/25"));
+    write_list(&dir, "move", [85].into_iter());
+    write(&dir, "move/85", &fixture("move/85"));
+    let items = 1..=(BATCH_SIZE as i64 * 2 + 1);
+    write_list(&dir, "item", items.clone());
+    for id in items {
+        let item = format!(r#"{{"id": {}, "name": "item-{}"}}"#, id, id);
+        write(&dir, &format!("item/{}", id), &item);
+    }
+    dir
+}
+
+#[tokio::test]
+async fn sync_stores_what_it_can_and_reports_the_rest() {
+    let dir = pokeapi("pokemon-game-bundle-sync");
+    let from: Arc<dyn DataSource> = Arc::new(FixtureDir::new(&dir));
+    let bundle = LocalSource::open(dir.join("bundle.sqlite")).unwrap();
+
+    let report = bundle.sync(from).await.unwrap();
+    assert_eq!(report.stored, BATCH_SIZE * 2 + 3);
+    let failed: Vec<&str> = report
+        .failed
+        .iter()
+        .map(|(path, _)| path.as_str())
+        .collect();
+    assert_eq!(failed, ["pokemon/9999/"]);
+    assert!(matches!(report.failed[0].1, Error::NotFound(_)));
+
+    let counts = bundle.counts().unwrap();
+    assert_eq!(counts["item"], BATCH_SIZE * 2 + 1);
+    assert_eq!(counts["move"], 1);
+    assert_eq!(counts["pokemon"], 1);
+
+    // Read back through a fresh connection to the file.
+    let bundle = LocalSource::open(dir.join("bundle.sqlite")).unwrap();
+    assert_eq!(
+        bundle.fetch("pokemon/25").await.unwrap(),
+        fixture("pokemon/25")
+    );
+    assert_eq!(
+        bundle.fetch("pokemon/pikachu").await.unwrap(),
+        fixture("pokemon/25")
+    );
+    assert_eq!(
+        bundle.fetch("move/thunderbolt").await.unwrap(),
+        fixture("move/85")
+    );
+    assert!(bundle.fetch("item/item-201").await.is_ok());
+    assert!(matches!(
+        bundle.fetch("pokemon/9999").await,
+        Err(Error::NotFound(_))
+    ));
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn endpoints_that_cannot_be_listed_are_left_as_they_were() {
+    let dir = pokeapi("pokemon-game-bundle-unlisted");
+    let from: Arc<dyn DataSource> = Arc::new(FixtureDir::new(&dir));
+    let bundle = LocalSource::in_memory().unwrap();
+    bundle.sync_endpoint(from.clone(), "move").await.unwrap();
+
+    fs::remove_file(dir.join("move/index.json")).unwrap();
+    assert!(bundle.sync_endpoint(from, "move").await.is_err());
+    assert_eq!(bundle.get("move/85").unwrap(), fixture("move/85"));
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,97 @@
+//! Checks the capture formula against catches worked out by hand, and that throwing balls catches
+//! about as often as the formula says.
+
+use pokemon_game::{
+    battle::status::Status,
+    capture::{capture, modified_catch_rate, shake_probability, Attempt},
+    generation::Generation,
+};
+use rand::{rngs::StdRng, SeedableRng};
+
+/// A full-HP Mewtwo, whose capture rate is 3, facing a Poké Ball.
+fn legendary(generation: Generation) -> Attempt<'static> {
+    Attempt {
+        generation,
+        ball: "poke-ball",
+        capture_rate: 3,
+        max_hp: 416,
+        hp: 416,
+        level: 70,
+        turn: 1,
+        ..Attempt::default()
+    }
+}
+
+/// A sleeping Bulbasaur, whose capture rate is 45, on its last HP and facing an Ultra Ball.
+fn sleeping(generation: Generation) -> Attempt<'static> {
+    Attempt {
+        generation,
+        ball: "ultra-ball",
+        capture_rate: 45,
+        max_hp: 100,
+        hp: 1,
+        status: Some(Status::Sleep { turns: 2 }),
+        level: 5,
+        turn: 1,
+        ..Attempt::default()
+    }
+}
+
+/// How often an attempt catches out of many throws.
+fn catch_rate(attempt: &Attempt) -> f64 {
+    let mut rng = StdRng::seed_from_u64(0);
+    let throws = 20_000;
+    let caught = (0..throws)
+        .filter(|_| capture(attempt, &mut rng).caught)
+        .count();
+    caught as f64 / throws as f64
+}
+
+#[test]
+fn catch_rates_worked_out_by_hand() {
+    // ⌊⅓ × 3 × 1⌋ = 1, then ⌊1048560 / ⌊√⌊√⌊16711680 / 1⌋⌋⌋⌋ = 1048560 / 63.
+    let attempt = legendary(Generation::IV);
+    assert_eq!(modified_catch_rate(&attempt), 1.0);
+    assert_eq!(shake_probability(Generation::IV, 1.0), 16643);
+    // ⌊65536 / (255 / 1)^¼⌋ for the three checks of Generation V.
+    assert_eq!(modified_catch_rate(&legendary(Generation::V)), 1.0);
+    assert_eq!(shake_probability(Generation::V, 1.0), 16400);
+    // ⌊65536 / (255 / 1)^0.1875⌋ for the four checks of later generations.
+    assert_eq!(modified_catch_rate(&legendary(Generation::VIII)), 1.0);
+    assert_eq!(shake_probability(Generation::VIII, 1.0), 23187);
+
+    // ⌊298 / 300 × ⌊45 × 2⌋⌋ × 2 = 178, then 1048560 / ⌊√⌊√⌊16711680 / 178⌋⌋⌋.
+    let attempt = sleeping(Generation::IV);
+    assert_eq!(modified_catch_rate(&attempt), 178.0);
+    assert_eq!(shake_probability(Generation::IV, 178.0), 61680);
+    // ⌊⌊298 / 300 × 4096 × 45 × 2⌋ / 4096 × 2.5⌋ = 223, as sleep counts for 2.5 from Generation V.
+    let attempt = sleeping(Generation::VIII);
+    assert_eq!(modified_catch_rate(&attempt), 223.0);
+    assert_eq!(shake_probability(Generation::VIII, 223.0), 63908);
+}
+
+#[test]
+fn balls_catch_as_often_as_the_formula_says() {
+    // Four shake checks of 16643 / 65536 each: about 0.4%.
+    let rate = catch_rate(&legendary(Generation::IV));
+    assert!((0.002..0.007).contains(&rate), "{}", rate);
+    // Three checks of 16400 / 65536 in Generation V and four of 23187 / 65536 afterwards:
+    // both about 1.6%.
+    let rate = catch_rate(&legendary(Generation::V));
+    assert!((0.012..0.020).contains(&rate), "{}", rate);
+    let rate = catch_rate(&legendary(Generation::VIII));
+    assert!((0.012..0.020).contains(&rate), "{}", rate);
+
+    // Four checks of 61680 / 65536 each: 78.5%.
+    let rate = catch_rate(&sleeping(Generation::IV));
+    assert!((0.77..0.80).contains(&rate), "{}", rate);
+    // Four checks of 63908 / 65536 each: 90.4%.
+    let rate = catch_rate(&sleeping(Generation::VIII));
+    assert!((0.89..0.92).contains(&rate), "{}", rate);
+
+    let master_ball = Attempt {
+        ball: "master-ball",
+        ..legendary(Generation::VIII)
+    };
+    assert_eq!(catch_rate(&master_ball), 1.0);
+}
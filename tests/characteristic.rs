@@ -0,0 +1,103 @@
+//! Checks that characteristics follow from IVs and narrow them down, with the "Loves to eat"
+//! characteristic of the fixtures.
+
+mod common;
+
+use common::fixture;
+use pokemon_game::{
+    characteristic::{self, Key},
+    model::pokemon::Characteristic,
+    owned::OwnedPokemon,
+    stats::{PossibleIvs, Stats},
+};
+
+/// Highest HP, which is a multiple of 5.
+fn loves_to_eat() -> Characteristic {
+    serde_json::from_str(&fixture("characteristic/1")).unwrap()
+}
+
+fn ivs(hp: i64, others: i64) -> Stats {
+    Stats {
+        hp,
+        attack: others,
+        defence: others,
+        special_attack: others,
+        special_defence: others,
+        speed: others,
+    }
+}
+
+#[test]
+fn the_highest_iv_selects_the_characteristic() {
+    let key = Key::of(&ivs(30, 12), 0);
+    assert_eq!(
+        key,
+        Key {
+            highest_stat: "hp",
+            gene_modulo: 0,
+        }
+    );
+    assert!(key.matches(&loves_to_eat()));
+    assert!(!Key::of(&ivs(31, 12), 0).matches(&loves_to_eat()));
+
+    let characteristics = [loves_to_eat()];
+    let mut pokemon = OwnedPokemon::new("pikachu", 5);
+    pokemon.iv = ivs(25, 3);
+    assert_eq!(
+        pokemon.characteristic(&characteristics),
+        Some(&characteristics[0])
+    );
+    assert_eq!(
+        pokemon.characteristic_description(&characteristics, "en"),
+        Some("Loves to eat")
+    );
+    assert_eq!(
+        pokemon.characteristic_description(&characteristics, "xx"),
+        None
+    );
+    pokemon.iv.attack = 26;
+    assert_eq!(pokemon.characteristic(&characteristics), None);
+}
+
+#[test]
+fn ties_are_broken_from_the_personality_value() {
+    let perfect = ivs(31, 31);
+    let highest = |personality| Key::of(&perfect, personality).highest_stat;
+    assert_eq!(highest(0), "hp");
+    assert_eq!(highest(1), "attack");
+    assert_eq!(highest(3), "speed");
+    assert_eq!(highest(5), "special-defense");
+    assert_eq!(highest(6), "hp");
+    assert_eq!(Key::of(&perfect, 3).gene_modulo, 1);
+
+    // Only tied IVs take part, starting from the personality value.
+    let mut iv = ivs(20, 10);
+    iv.special_attack = 20;
+    assert_eq!(Key::of(&iv, 3).highest_stat, "special-attack");
+    assert_eq!(Key::of(&iv, 5).highest_stat, "hp");
+}
+
+#[test]
+fn characteristics_narrow_down_ivs() {
+    let mut possible = PossibleIvs::default();
+    characteristic::narrow(&mut possible, &loves_to_eat(), None);
+    assert_eq!(possible.get("hp"), Some(&[0, 5, 10, 15, 20, 25, 30][..]));
+    assert_eq!(possible.range("attack"), Some(0..=30));
+
+    // Another IV known to be 21 leaves HP at least as high.
+    let mut possible = PossibleIvs::default();
+    possible.retain("attack", |value| *value == 21);
+    characteristic::narrow(&mut possible, &loves_to_eat(), None);
+    assert_eq!(possible.get("hp"), Some(&[25, 30][..]));
+
+    // Attack is checked before HP for a personality value of 1, so it cannot tie with HP.
+    let tied_attack = |personality| {
+        let mut possible = PossibleIvs::default();
+        possible.retain("attack", |value| *value == 30);
+        characteristic::narrow(&mut possible, &loves_to_eat(), personality);
+        possible
+    };
+    assert_eq!(tied_attack(None).get("hp"), Some(&[30][..]));
+    assert!(!tied_attack(Some(0)).is_contradictory());
+    assert!(tied_attack(Some(1)).is_contradictory());
+}
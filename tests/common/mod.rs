@@ -0,0 +1,86 @@
+//! Helpers shared by the tests reading the PokeAPI fixtures in `tests/fixtures`.
+// Each test binary uses only some of the helpers.
+#![allow(dead_code)]
+
+use std::{
+    fmt::Debug,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use pokemon_game::model::{
+    evolution::{EvolutionChain, EvolutionTrigger},
+    location::LocationArea,
+    moves::{Move, MoveAilment, MoveDamageClass, MoveLearnMethod, MoveTarget},
+    pokemon::{
+        Ability, Characteristic, Gender, GrowthRate, Nature, Pokemon, PokemonSpecies, Stat, Type,
+    },
+    resource::NamedApiResourceList,
+};
+use serde::{de::DeserializeOwned, Serialize};
+
+pub fn fixtures() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures")
+}
+
+/// The path of every fixture relative to the fixture directory, e.g. `pokemon/25`.
+pub fn fixture_paths() -> Vec<String> {
+    let mut paths = Vec::new();
+    for endpoint in fs::read_dir(fixtures()).unwrap() {
+        let endpoint = endpoint.unwrap().path();
+        let name = endpoint.file_name().unwrap().to_string_lossy().to_string();
+        if endpoint.join("index.json").exists() {
+            paths.push(name.clone());
+        }
+        for resource in fs::read_dir(&endpoint).unwrap() {
+            let resource = resource.unwrap().path();
+            if resource.join("index.json").exists() {
+                let id = resource.file_name().unwrap().to_string_lossy().to_string();
+                paths.push(format!("{}/{}", name, id));
+            }
+        }
+    }
+    paths.sort();
+    paths
+}
+
+/// The JSON of a fixture by its path, e.g. `pokemon/25`.
+pub fn fixture(path: &str) -> String {
+    fs::read_to_string(fixtures().join(path).join("index.json")).unwrap()
+}
+
+/// A check of the JSON of a fixture against a model, given by [`with_model`].
+pub trait ModelVisitor {
+    type Output;
+
+    fn visit<T: DeserializeOwned + Serialize + PartialEq + Debug>(self, json: &str)
+        -> Self::Output;
+}
+
+/// Visits the JSON of a fixture with the model of its endpoint, or with a list for a fixture of a
+/// list, e.g. `pokemon`.
+pub fn with_model<V: ModelVisitor>(path: &str, json: &str, visitor: V) -> V::Output {
+    let Some((endpoint, _)) = path.split_once('/') else {
+        return visitor.visit::<NamedApiResourceList>(json);
+    };
+    match endpoint {
+        "ability" => visitor.visit::<Ability>(json),
+        "characteristic" => visitor.visit::<Characteristic>(json),
+        "evolution-chain" => visitor.visit::<EvolutionChain>(json),
+        "evolution-trigger" => visitor.visit::<EvolutionTrigger>(json),
+        "gender" => visitor.visit::<Gender>(json),
+        "growth-rate" => visitor.visit::<GrowthRate>(json),
+        "location-area" => visitor.visit::<LocationArea>(json),
+        "move" => visitor.visit::<Move>(json),
+        "move-ailment" => visitor.visit::<MoveAilment>(json),
+        "move-damage-class" => visitor.visit::<MoveDamageClass>(json),
+        "move-learn-method" => visitor.visit::<MoveLearnMethod>(json),
+        "move-target" => visitor.visit::<MoveTarget>(json),
+        "nature" => visitor.visit::<Nature>(json),
+        "pokemon" => visitor.visit::<Pokemon>(json),
+        "pokemon-species" => visitor.visit::<PokemonSpecies>(json),
+        "stat" => visitor.visit::<Stat>(json),
+        "type" => visitor.visit::<Type>(json),
+        _ => panic!("no model for the fixture {}", path),
+    }
+}
@@ -0,0 +1,183 @@
+//! Checks the models against PokeAPI responses of every endpoint, strictly, so that a field missing
+//! from a model or misspelled in one fails a test.
+//!
+//! The fixtures in `tests/fixtures` are laid out like PokeAPI's paths and trimmed to a few entries per list.
+//! `cargo test --test conformance -- --ignored` records them again from PokeAPI, in full.
+
+mod common;
+
+use std::fs;
+
+use common::{fixture, fixture_paths, fixtures, with_model, ModelVisitor};
+use pokemon_game::{
+    error::Error,
+    evolution, locations,
+    model::{
+        pokemon::{PokemonSpecies, Type},
+        strict::{self, Mismatch},
+    },
+    moves, pokemon,
+    source::{self, DataSource, FixtureDir, Http},
+};
+
+/// Serves the endpoint functions from the fixtures, strictly.
+fn use_fixtures() {
+    source::set_source(FixtureDir::new(fixtures()));
+    strict::set_strict(true);
+}
+
+/// Checks the JSON of a fixture against the model of its endpoint.
+struct Mismatches;
+
+impl ModelVisitor for Mismatches {
+    type Output = Result<Vec<Mismatch>, serde_json::Error>;
+
+    fn visit<T: serde::de::DeserializeOwned>(self, json: &str) -> Self::Output {
+        strict::check::<T>(json).map(|(_, mismatches)| mismatches)
+    }
+}
+
+#[test]
+fn every_fixture_matches_its_model() {
+    let mut failures = Vec::new();
+    for path in fixture_paths() {
+        match with_model(&path, &fixture(&path), Mismatches) {
+            Ok(mismatches) => {
+                for mismatch in mismatches {
+                    failures.push(format!("{}: {}", path, mismatch));
+                }
+            }
+            Err(error) => failures.push(format!("{}: {}", path, error)),
+        }
+    }
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+#[test]
+fn strict_mode_reports_unknown_and_missing_fields() {
+    let json = r#"{"id": 25, "name": "pikachu", "base_hapiness": 50, "color": {"name": "yellow"}}"#;
+    let (species, mismatches) = strict::check::<PokemonSpecies>(json).unwrap();
+    assert_eq!(species.base_happiness, None);
+    assert!(mismatches.contains(&Mismatch::Unknown("base_hapiness".to_string())));
+    assert!(mismatches.contains(&Mismatch::Missing("base_happiness".to_string())));
+    assert!(mismatches.contains(&Mismatch::Missing("color.url".to_string())));
+    assert!(strict::from_str::<PokemonSpecies>(json).is_err());
+    assert!(serde_json::from_str::<PokemonSpecies>(json).is_ok());
+}
+
+#[test]
+fn strict_mode_reports_fields_of_list_elements_once() {
+    let json = r#"{"id": 13, "name": "electric", "moves": [{"name": "thunderbolt"}, {"name": "thunder-shock"}]}"#;
+    let (_, mismatches) = strict::check::<Type>(json).unwrap();
+    let moves: Vec<_> = mismatches
+        .iter()
+        .filter(|mismatch| mismatch.to_string().contains("moves"))
+        .collect();
+    assert_eq!(moves, [&Mismatch::Missing("moves[].url".to_string())]);
+}
+
+#[tokio::test]
+async fn pokemon_endpoints() {
+    use_fixtures();
+
+    let pikachu = pokemon::pokemon::get_by_id(25).await.unwrap();
+    assert_eq!(pikachu.name.as_deref(), Some("pikachu"));
+    assert_eq!(pikachu.base_experience, Some(112));
+    assert_eq!(
+        pokemon::pokemon::get_by_name("pikachu").await.unwrap(),
+        pikachu
+    );
+
+    let species = pokemon::pokemon_species::get_by_name("pikachu")
+        .await
+        .unwrap();
+    assert_eq!(species.base_happiness, Some(50));
+    assert_eq!(species.capture_rate, Some(190));
+
+    let ability = pokemon::ability::get_by_name("lightning-rod")
+        .await
+        .unwrap();
+    assert_eq!(ability.id, Some(31));
+    let characteristic = pokemon::characteristic::get_by_id(1).await.unwrap();
+    assert_eq!(characteristic.gene_modulo, Some(0));
+    let gender = pokemon::gender::get_by_name("genderless").await.unwrap();
+    assert_eq!(gender.id, Some(3));
+    let growth_rate = pokemon::growth_rate::get_by_name("medium").await.unwrap();
+    assert_eq!(growth_rate.levels.map(|levels| levels.len()), Some(100));
+    let nature = pokemon::nature::get_by_name("timid").await.unwrap();
+    assert_eq!(
+        nature.increased_stat.and_then(|stat| stat.name).as_deref(),
+        Some("speed")
+    );
+    let stat = pokemon::stat::get_by_name("attack").await.unwrap();
+    assert_eq!(stat.game_index, Some(2));
+    let electric = pokemon::type_::get_by_name("electric").await.unwrap();
+    assert_eq!(electric.id, Some(13));
+    let steel = pokemon::type_::get_by_id(9).await.unwrap();
+    assert_eq!(steel.past_damage_relations.map(|past| past.len()), Some(1));
+}
+
+#[tokio::test]
+async fn move_endpoints() {
+    use_fixtures();
+
+    let thunderbolt = moves::move_::get_by_name("thunderbolt").await.unwrap();
+    assert_eq!(thunderbolt.power, Some(90));
+    assert_eq!(moves::move_::get_by_id(85).await.unwrap(), thunderbolt);
+
+    let paralysis = moves::move_ailment::get_by_name("paralysis").await.unwrap();
+    assert_eq!(paralysis.id, Some(1));
+    let special = moves::move_damage_class::get_by_id(3).await.unwrap();
+    assert_eq!(special.name.as_deref(), Some("special"));
+    let level_up = moves::move_learn_method::get_by_name("level-up")
+        .await
+        .unwrap();
+    assert_eq!(level_up.id, Some(1));
+    let target = moves::move_target::get_by_name("selected-pokemon")
+        .await
+        .unwrap();
+    assert_eq!(target.moves.map(|moves| moves.len()), Some(3));
+}
+
+#[tokio::test]
+async fn evolution_and_location_endpoints() {
+    use_fixtures();
+
+    let chain = evolution::evolution_chain::get_by_id(10).await.unwrap();
+    let base = chain.chain.unwrap();
+    assert_eq!(base.is_baby, Some(true));
+    assert_eq!(base.evolves_to.map(|next| next.len()), Some(1));
+    let trigger = evolution::evolution_trigger::get_by_name("level-up")
+        .await
+        .unwrap();
+    assert_eq!(trigger.id, Some(1));
+
+    let area = locations::location_area::get_by_name("viridian-forest-area")
+        .await
+        .unwrap();
+    assert_eq!(
+        area.pokemon_encounters.map(|encounters| encounters.len()),
+        Some(2)
+    );
+}
+
+#[tokio::test]
+async fn missing_fixtures_are_not_found() {
+    use_fixtures();
+
+    let missing = pokemon::pokemon::get_by_name("missingno").await;
+    assert!(matches!(missing, Err(Error::NotFound(_))), "{:?}", missing);
+}
+
+/// Replaces every fixture with the full response of PokeAPI.
+#[tokio::test]
+#[ignore = "needs network access to PokeAPI"]
+async fn record_fixtures() {
+    let http = Http::new();
+    for path in fixture_paths() {
+        let json = http.fetch(&format!("{}/", path)).await.unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let pretty = serde_json::to_string_pretty(&value).unwrap();
+        fs::write(fixtures().join(&path).join("index.json"), pretty + "\n").unwrap();
+    }
+}
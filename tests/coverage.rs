@@ -0,0 +1,127 @@
+//! Checks which moves count towards a team's coverage, and that coverage reports render as aligned tables.
+
+mod common;
+
+use common::fixture;
+use pokemon_game::{
+    coverage::{Coverage, Defence, Member, Offence},
+    model::{
+        moves::Move,
+        pokemon::{Pokemon, Type},
+    },
+    type_chart::TypeChart,
+};
+use serde_json::json;
+
+#[test]
+fn multiplier_columns_fit_the_widest_label() {
+    let coverage = Coverage {
+        members: vec!["Mew".to_string(), "Gengar".to_string()],
+        offence: vec![
+            Offence {
+                defending: "ghost".to_string(),
+                best: Some(0.0),
+                super_effective: vec![],
+            },
+            Offence {
+                defending: "poison".to_string(),
+                best: Some(2.0),
+                super_effective: vec!["psychic".to_string()],
+            },
+        ],
+        defence: vec![
+            Defence {
+                attacking: "ghost".to_string(),
+                multipliers: vec![2.0, 2.0],
+            },
+            Defence {
+                attacking: "poison".to_string(),
+                multipliers: vec![1.0, 0.25],
+            },
+        ],
+        uncovered: vec!["ghost".to_string()],
+        shared_weaknesses: vec!["ghost".to_string()],
+        suggestions: vec![],
+    };
+    assert_eq!(
+        coverage.to_string(),
+        "Offence
+Type     Best  Super effective moves
+ghost      0× \x20
+poison     2×  psychic
+
+Defence
+Type      Mew  Gengar  Weak  Resist
+ghost      2×      2×     2       0
+poison     1×   0.25×     0       1
+
+Uncovered types: ghost
+Shared weaknesses: ghost
+Suggested types: none
+"
+    );
+}
+
+fn move_(name: &str, damage_class: &str, type_: &str) -> Move {
+    serde_json::from_value(json!({
+        "name": name,
+        "power": null,
+        "damage_class": {"name": damage_class},
+        "type": {"name": type_},
+    }))
+    .unwrap()
+}
+
+/// The electric and steel types of the fixtures, and a fighting type that hits steel super effectively.
+fn chart() -> TypeChart {
+    let fighting: Type = serde_json::from_value(json!({
+        "name": "fighting",
+        "damage_relations": {"double_damage_to": [{"name": "steel"}]},
+    }))
+    .unwrap();
+    let fixtures: Vec<Type> = ["type/13", "type/9"]
+        .map(|path| serde_json::from_str(&fixture(path)).unwrap())
+        .into();
+    TypeChart::from_types(fixtures.iter().chain([&fighting]))
+}
+
+#[test]
+fn moves_without_a_set_power_still_deal_damage() {
+    let pikachu: Pokemon = serde_json::from_str(&fixture("pokemon/25")).unwrap();
+    let moves = [
+        move_("seismic-toss", "physical", "fighting"),
+        move_("growl", "status", "normal"),
+    ];
+    let coverage = Coverage::of(
+        &chart(),
+        &[Member {
+            pokemon: &pikachu,
+            moves: &moves,
+        }],
+    );
+    let steel = coverage
+        .offence
+        .iter()
+        .find(|entry| entry.defending == "steel")
+        .unwrap();
+    assert_eq!(steel.best, Some(2.0));
+    assert_eq!(steel.super_effective, ["seismic-toss"]);
+    assert!(!coverage.uncovered.contains(&"steel".to_string()));
+}
+
+#[test]
+fn teams_without_damaging_moves_have_no_best_multiplier() {
+    let pikachu: Pokemon = serde_json::from_str(&fixture("pokemon/25")).unwrap();
+    let moves = [move_("growl", "status", "normal")];
+    let coverage = Coverage::of(
+        &chart(),
+        &[Member {
+            pokemon: &pikachu,
+            moves: &moves,
+        }],
+    );
+    assert!(coverage.offence.iter().all(|entry| entry.best.is_none()));
+    assert_eq!(coverage.uncovered, ["electric", "fighting", "steel"]);
+    let report = coverage.to_string();
+    assert!(report.contains("\nsteel        -  \n"), "{}", report);
+}
@@ -0,0 +1,190 @@
+//! Checks the domain types made from the fixtures, and the errors for data they cannot hold.
+
+mod common;
+
+use common::fixture;
+use pokemon_game::{
+    domain::{AbilitySlot, DataError, MoveData, PokemonData, SpeciesData},
+    model::{
+        moves::Move,
+        pokemon::{Pokemon, PokemonSpecies},
+    },
+    names::{DamageClass, PokemonTypeName},
+    stats::Stats,
+};
+use serde_json::{json, Value};
+
+/// The JSON of a fixture, to be edited before it is read as a model.
+fn value(path: &str) -> Value {
+    serde_json::from_str(&fixture(path)).unwrap()
+}
+
+fn pikachu(edit: impl FnOnce(&mut Value)) -> Result<PokemonData, DataError> {
+    let mut pikachu = value("pokemon/25");
+    edit(&mut pikachu);
+    PokemonData::try_from(serde_json::from_value::<Pokemon>(pikachu).unwrap())
+}
+
+#[test]
+fn pokemon_data_comes_from_the_pokemon() {
+    let pikachu = pikachu(|_| {}).unwrap();
+    assert_eq!(pikachu.id, 25);
+    assert_eq!(pikachu.name, "pikachu");
+    assert_eq!(pikachu.species, "pikachu");
+    assert_eq!(pikachu.base_experience, Some(112));
+    assert_eq!(pikachu.base_stats, [35, 55, 40, 50, 50, 90]);
+    assert_eq!(
+        pikachu.ev_yield(),
+        Stats {
+            speed: 2,
+            ..Stats::default()
+        }
+    );
+    assert_eq!(pikachu.base_stats().speed, 90);
+    assert_eq!(pikachu.types, [PokemonTypeName::Electric]);
+    assert_eq!(
+        pikachu.abilities,
+        [
+            AbilitySlot {
+                name: "static".to_string(),
+                slot: 1,
+                hidden: false,
+            },
+            AbilitySlot {
+                name: "lightning-rod".to_string(),
+                slot: 3,
+                hidden: true,
+            },
+        ]
+    );
+    assert_eq!((pikachu.height, pikachu.weight), (4, 60));
+}
+
+#[test]
+fn species_data_comes_from_the_species() {
+    let species: PokemonSpecies = serde_json::from_str(&fixture("pokemon-species/25")).unwrap();
+    let pikachu = SpeciesData::try_from(species).unwrap();
+    assert_eq!(pikachu.id, 25);
+    assert_eq!(pikachu.name, "pikachu");
+    assert_eq!(pikachu.gender_rate, 4);
+    assert_eq!(pikachu.capture_rate, 190);
+    assert_eq!(pikachu.growth_rate, "medium");
+    assert_eq!(pikachu.egg_groups, ["ground", "fairy"]);
+    assert_eq!(pikachu.evolves_from.as_deref(), Some("pichu"));
+    assert!(!pikachu.is_baby && !pikachu.is_legendary && !pikachu.is_mythical);
+}
+
+#[test]
+fn move_data_comes_from_the_move() {
+    let thunderbolt: Move = serde_json::from_str(&fixture("move/85")).unwrap();
+    let thunderbolt = MoveData::try_from(thunderbolt).unwrap();
+    assert_eq!(thunderbolt.id, 85);
+    assert_eq!(thunderbolt.name, "thunderbolt");
+    assert_eq!(thunderbolt.type_, PokemonTypeName::Electric);
+    assert_eq!(thunderbolt.damage_class, DamageClass::Special);
+    assert_eq!(thunderbolt.power, Some(90));
+    assert_eq!(thunderbolt.accuracy, Some(100));
+    assert_eq!(thunderbolt.pp, 15);
+    assert_eq!(thunderbolt.priority, 0);
+    assert_eq!(thunderbolt.target, "selected-pokemon");
+    assert_eq!(thunderbolt.effect_chance, Some(10));
+}
+
+#[test]
+fn missing_data_is_reported_with_its_field() {
+    let error = pikachu(|pikachu| pikachu["stats"][2]["base_stat"] = Value::Null).unwrap_err();
+    assert_eq!(
+        error,
+        DataError::Missing {
+            model: "Pokemon",
+            field: "stats[2].base_stat".to_string(),
+        }
+    );
+    assert_eq!(error.to_string(), "Pokemon has no stats[2].base_stat");
+
+    let mut thunderbolt = value("move/85");
+    thunderbolt["pp"] = Value::Null;
+    let thunderbolt: Move = serde_json::from_value(thunderbolt).unwrap();
+    assert_eq!(
+        MoveData::try_from(thunderbolt),
+        Err(DataError::Missing {
+            model: "Move",
+            field: "pp".to_string(),
+        })
+    );
+}
+
+#[test]
+fn numbers_out_of_range_are_reported() {
+    let error = pikachu(|pikachu| pikachu["stats"][0]["base_stat"] = json!(256)).unwrap_err();
+    assert_eq!(
+        error,
+        DataError::OutOfRange {
+            model: "Pokemon",
+            field: "stats[0].base_stat".to_string(),
+            value: 256,
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "Pokemon has stats[0].base_stat out of range: 256"
+    );
+
+    let mut species = value("pokemon-species/25");
+    species["gender_rate"] = json!(9);
+    let species: PokemonSpecies = serde_json::from_value(species).unwrap();
+    assert_eq!(
+        SpeciesData::try_from(species),
+        Err(DataError::OutOfRange {
+            model: "PokemonSpecies",
+            field: "gender_rate".to_string(),
+            value: 9,
+        })
+    );
+}
+
+#[test]
+fn unknown_names_are_reported() {
+    let error =
+        pikachu(|pikachu| pikachu["stats"][5]["stat"]["name"] = json!("accuracy")).unwrap_err();
+    assert_eq!(
+        error,
+        DataError::UnknownName {
+            model: "Pokemon",
+            field: "stats[5].stat.name".to_string(),
+            name: "accuracy".to_string(),
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "Pokemon has an unknown stats[5].stat.name: accuracy"
+    );
+
+    let mut thunderbolt = value("move/85");
+    thunderbolt["damage_class"]["name"] = json!("contest");
+    let thunderbolt: Move = serde_json::from_value(thunderbolt).unwrap();
+    assert_eq!(
+        MoveData::try_from(thunderbolt),
+        Err(DataError::UnknownName {
+            model: "Move",
+            field: "damage_class.name".to_string(),
+            name: "contest".to_string(),
+        })
+    );
+}
+
+#[test]
+fn missing_base_stats_are_reported() {
+    let error = pikachu(|pikachu| {
+        pikachu["stats"].as_array_mut().unwrap().remove(5);
+    })
+    .unwrap_err();
+    assert_eq!(
+        error,
+        DataError::MissingStat {
+            model: "Pokemon",
+            stat: "speed",
+        }
+    );
+    assert_eq!(error.to_string(), "Pokemon has no base speed stat");
+}
@@ -0,0 +1,113 @@
+//! Checks the moves wild Pokémon know against the learnsets of the fixtures.
+
+mod common;
+
+use common::fixture;
+use pokemon_game::{
+    encounter::{level_up_moves, slots, Search, Wild},
+    generation::Generation,
+    model::{
+        location::LocationArea,
+        pokemon::{GrowthRate, Pokemon, PokemonSpecies},
+    },
+};
+use rand::{rngs::StdRng, SeedableRng};
+
+fn pikachu() -> Pokemon {
+    serde_json::from_str(&fixture("pokemon/25")).unwrap()
+}
+
+#[test]
+fn level_up_moves_come_from_one_version_group() {
+    let pikachu = pikachu();
+    // Thunderbolt is only learned by level-up from Sword and Shield, and Thunder Wave earlier there.
+    assert_eq!(
+        level_up_moves(&pikachu, 36, "red-blue"),
+        ["growl", "thunder-shock", "thunder-wave"]
+    );
+    assert_eq!(
+        level_up_moves(&pikachu, 8, "red-blue"),
+        ["growl", "thunder-shock"]
+    );
+    assert_eq!(
+        level_up_moves(&pikachu, 8, "sword-shield"),
+        ["growl", "thunder-shock", "thunder-wave"]
+    );
+    assert_eq!(
+        level_up_moves(&pikachu, 36, "sword-shield"),
+        ["growl", "thunder-shock", "thunder-wave", "thunderbolt"]
+    );
+    assert!(level_up_moves(&pikachu, 36, "gold-silver").is_empty());
+}
+
+#[test]
+fn wild_pokemon_know_the_moves_of_their_game() {
+    let pikachu = pikachu();
+    let species: PokemonSpecies = serde_json::from_str(&fixture("pokemon-species/25")).unwrap();
+    let growth_rate: GrowthRate = serde_json::from_str(&fixture("growth-rate/2")).unwrap();
+    let wild = Wild {
+        generation: Generation::I,
+        pokemon: &pikachu,
+        species: &species,
+        growth_rate: Some(&growth_rate),
+        version_group: "red-blue",
+        trainer_id: 0,
+    };
+    let generated = wild.generate(40, &mut StdRng::seed_from_u64(0));
+    assert_eq!(generated.level, 40);
+    assert_eq!(generated.experience, 64_000);
+    assert_eq!(generated.moves, ["growl", "thunder-shock", "thunder-wave"]);
+}
+
+/// A walking encounter in HeartGold that needs some condition values.
+fn encounter(pokemon: &str, conditions: &[&str]) -> String {
+    let conditions: Vec<String> = conditions
+        .iter()
+        .map(|condition| format!(r#"{{"name": "{}"}}"#, condition))
+        .collect();
+    format!(
+        r#"{{
+            "pokemon": {{"name": "{}"}},
+            "version_details": [{{
+                "version": {{"name": "heartgold"}},
+                "encounter_details": [{{
+                    "method": {{"name": "walk"}},
+                    "chance": 10,
+                    "condition_values": [{}]
+                }}]
+            }}]
+        }}"#,
+        pokemon,
+        conditions.join(", ")
+    )
+}
+
+#[test]
+fn condition_values_are_grouped_by_their_condition() {
+    let area: LocationArea = serde_json::from_str(&format!(
+        r#"{{"pokemon_encounters": [{}, {}, {}, {}]}}"#,
+        encounter("hoothoot", &["time-night"]),
+        encounter("pidgey", &["time-day"]),
+        encounter("rattata", &["story-progress-none"]),
+        encounter("entei", &["story-progress-awakened-beasts"]),
+    ))
+    .unwrap();
+    let found = |conditions: &[&str]| -> Vec<String> {
+        let search = Search {
+            version: "heartgold",
+            method: "walk",
+            conditions,
+        };
+        slots(&area, &search)
+            .iter()
+            .map(|slot| slot.pokemon.to_string())
+            .collect()
+    };
+    assert_eq!(found(&[]), ["rattata"]);
+    assert_eq!(found(&["time-night"]), ["hoothoot", "rattata"]);
+    // Story progress has values of several words, which all replace its default.
+    assert_eq!(
+        found(&["time-day", "story-progress-awakened-beasts"]),
+        ["pidgey", "entei"]
+    );
+}
@@ -0,0 +1,227 @@
+//! Checks evolution conditions against the Pichu family in the fixtures and details written for the
+//! rarer conditions.
+
+mod common;
+
+use common::fixture;
+use pokemon_game::{
+    evolve::{chain_id, evolution_moves, find_evolution, meets, Surroundings, Trigger},
+    model::{
+        evolution::{EvolutionChain, EvolutionDetail},
+        pokemon::{Pokemon, PokemonSpecies},
+    },
+    owned::{Gender, OwnedPokemon},
+    stats::Stats,
+};
+
+fn pichu_chain() -> EvolutionChain {
+    serde_json::from_str(&fixture("evolution-chain/10")).unwrap()
+}
+
+fn detail(json: &str) -> EvolutionDetail {
+    serde_json::from_str(json).unwrap()
+}
+
+fn stats(attack: i64, defence: i64) -> Stats {
+    Stats {
+        attack,
+        defence,
+        ..Stats::default()
+    }
+}
+
+/// The species a Pokémon evolves into for a trigger, if it evolves.
+fn evolves_into(pokemon: &OwnedPokemon, trigger: Trigger) -> Option<String> {
+    let chain = pichu_chain();
+    let candidate = find_evolution(
+        &chain,
+        &pokemon.pokemon,
+        pokemon,
+        &stats(50, 50),
+        &trigger,
+        &Surroundings::default(),
+    );
+    candidate.map(|candidate| candidate.species.to_string())
+}
+
+#[test]
+fn species_lead_to_their_chain() {
+    let species: PokemonSpecies = serde_json::from_str(&fixture("pokemon-species/25")).unwrap();
+    assert_eq!(chain_id(&species), Some(10));
+}
+
+#[test]
+fn evolutions_follow_the_chain() {
+    let mut pichu = OwnedPokemon::new("pichu", 10);
+    assert_eq!(evolves_into(&pichu, Trigger::LevelUp), None);
+    pichu.friendship = 220;
+    assert_eq!(
+        evolves_into(&pichu, Trigger::LevelUp).as_deref(),
+        Some("pikachu")
+    );
+    assert_eq!(
+        evolves_into(&pichu, Trigger::FriendshipChange).as_deref(),
+        Some("pikachu")
+    );
+    assert_eq!(
+        evolves_into(&pichu, Trigger::UseItem("thunder-stone".to_string())),
+        None
+    );
+
+    let pikachu = OwnedPokemon::new("pikachu", 10);
+    assert_eq!(evolves_into(&pikachu, Trigger::LevelUp), None);
+    assert_eq!(evolves_into(&pikachu, Trigger::Trade), None);
+    assert_eq!(
+        evolves_into(&pikachu, Trigger::UseItem("fire-stone".to_string())),
+        None
+    );
+    assert_eq!(
+        evolves_into(&pikachu, Trigger::UseItem("thunder-stone".to_string())).as_deref(),
+        Some("raichu")
+    );
+
+    // Neither the last of the chain nor a species outside of it evolves.
+    let raichu = OwnedPokemon::new("raichu", 100);
+    assert_eq!(
+        evolves_into(&raichu, Trigger::UseItem("thunder-stone".to_string())),
+        None
+    );
+    let eevee = OwnedPokemon::new("eevee", 10);
+    assert_eq!(
+        evolves_into(&eevee, Trigger::UseItem("thunder-stone".to_string())),
+        None
+    );
+}
+
+/// Whether a Pokémon with even Attack and Defense meets an evolution detail upon levelling up.
+fn levels_up(
+    detail: &EvolutionDetail,
+    pokemon: &OwnedPokemon,
+    surroundings: &Surroundings,
+) -> bool {
+    meets(
+        detail,
+        pokemon,
+        &stats(50, 50),
+        &Trigger::LevelUp,
+        surroundings,
+    )
+}
+
+#[test]
+fn conditions_are_all_checked() {
+    let anywhere = Surroundings::default();
+    let tyrogue = OwnedPokemon::new("tyrogue", 20);
+    let hitmonlee = detail(
+        r#"{"trigger": {"name": "level-up"}, "min_level": 20, "relative_physical_stats": 1}"#,
+    );
+    let into_hitmonlee = |pokemon: &OwnedPokemon, stats: Stats, trigger: Trigger| {
+        meets(&hitmonlee, pokemon, &stats, &trigger, &anywhere)
+    };
+    assert!(into_hitmonlee(&tyrogue, stats(40, 30), Trigger::LevelUp));
+    assert!(!into_hitmonlee(&tyrogue, stats(30, 30), Trigger::LevelUp));
+    assert!(!into_hitmonlee(&tyrogue, stats(40, 30), Trigger::Trade));
+    let young = OwnedPokemon::new("tyrogue", 19);
+    assert!(!into_hitmonlee(&young, stats(40, 30), Trigger::LevelUp));
+
+    let mut snorunt = OwnedPokemon::new("snorunt", 42);
+    let froslass =
+        detail(r#"{"trigger": {"name": "use-item"}, "item": {"name": "dawn-stone"}, "gender": 1}"#);
+    let dawn_stone = |pokemon: &OwnedPokemon| {
+        let trigger = Trigger::UseItem("dawn-stone".to_string());
+        meets(&froslass, pokemon, &stats(50, 50), &trigger, &anywhere)
+    };
+    snorunt.gender = Gender::Male;
+    assert!(!dawn_stone(&snorunt));
+    snorunt.gender = Gender::Female;
+    assert!(dawn_stone(&snorunt));
+
+    let mut sneasel = OwnedPokemon::new("sneasel", 30);
+    let weavile = detail(
+        r#"{
+            "trigger": {"name": "level-up"},
+            "held_item": {"name": "razor-claw"},
+            "time_of_day": "night"
+        }"#,
+    );
+    let night = Surroundings {
+        time_of_day: Some("night".to_string()),
+        ..Surroundings::default()
+    };
+    sneasel.held_item = Some("razor-claw".to_string());
+    assert!(levels_up(&weavile, &sneasel, &night));
+    assert!(!levels_up(&weavile, &sneasel, &anywhere));
+    sneasel.held_item = None;
+    assert!(!levels_up(&weavile, &sneasel, &night));
+
+    let mut sliggoo = OwnedPokemon::new("sliggoo", 50);
+    let goodra = detail(
+        r#"{"trigger": {"name": "level-up"}, "min_level": 50, "needs_overworld_rain": true}"#,
+    );
+    let rain = Surroundings {
+        overworld_rain: true,
+        ..Surroundings::default()
+    };
+    assert!(levels_up(&goodra, &sliggoo, &rain));
+    assert!(!levels_up(&goodra, &sliggoo, &anywhere));
+    sliggoo.level = 49;
+    assert!(!levels_up(&goodra, &sliggoo, &rain));
+
+    let mut aipom = OwnedPokemon::new("aipom", 32);
+    let ambipom =
+        detail(r#"{"trigger": {"name": "level-up"}, "known_move": {"name": "double-hit"}}"#);
+    assert!(!levels_up(&ambipom, &aipom, &anywhere));
+    aipom.moves = vec!["double-hit".to_string()];
+    assert!(levels_up(&ambipom, &aipom, &anywhere));
+    // Only evolutions asking for friendship or affection happen when it changes.
+    let friendship = Trigger::FriendshipChange;
+    assert!(!meets(
+        &ambipom,
+        &aipom,
+        &stats(50, 50),
+        &friendship,
+        &anywhere
+    ));
+}
+
+#[test]
+fn evolution_moves_are_those_of_the_level_or_evolution() {
+    let pikachu: Pokemon = serde_json::from_str(&fixture("pokemon/25")).unwrap();
+    assert_eq!(
+        evolution_moves(&pikachu, 36, "sword-shield"),
+        ["thunderbolt"]
+    );
+    assert_eq!(
+        evolution_moves(&pikachu, 4, "sword-shield"),
+        ["thunder-wave"]
+    );
+    assert!(evolution_moves(&pikachu, 37, "sword-shield").is_empty());
+    assert!(evolution_moves(&pikachu, 36, "crystal-clear").is_empty());
+
+    // Level 0 marks moves learned upon evolving, whatever the level.
+    let raichu: Pokemon = serde_json::from_str(
+        r#"{
+            "name": "raichu",
+            "moves": [{
+                "move": {"name": "thunder-punch"},
+                "version_group_details": [{
+                    "level_learned_at": 0,
+                    "move_learn_method": {"name": "level-up"},
+                    "version_group": {"name": "sword-shield"}
+                }]
+            }, {
+                "move": {"name": "thunder"},
+                "version_group_details": [{
+                    "level_learned_at": 0,
+                    "move_learn_method": {"name": "machine"},
+                    "version_group": {"name": "sword-shield"}
+                }]
+            }]
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(
+        evolution_moves(&raichu, 57, "sword-shield"),
+        ["thunder-punch"]
+    );
+}
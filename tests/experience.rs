@@ -0,0 +1,137 @@
+//! Checks the growth rate formulas against the `levels` tables of the fixtures, and the experience
+//! gained in each generation.
+
+mod common;
+
+use common::fixture;
+use pokemon_game::{
+    experience::{
+        experience_for_level, experience_gain, level_for_experience, Defeated, GrowthFormula,
+        Recipient, MAX_LEVEL,
+    },
+    generation::Generation,
+    model::pokemon::GrowthRate,
+};
+
+#[test]
+fn formulas_match_the_levels_tables() {
+    for id in 1..=6 {
+        let growth_rate: GrowthRate =
+            serde_json::from_str(&fixture(&format!("growth-rate/{}", id))).unwrap();
+        let formula = GrowthFormula::of(&growth_rate).unwrap();
+        let levels = growth_rate.levels.as_ref().unwrap();
+        assert_eq!(levels.len(), MAX_LEVEL as usize, "{:?}", formula);
+        for entry in levels {
+            let (level, experience) = (entry.level.unwrap(), entry.experience.unwrap());
+            assert_eq!(
+                formula.experience_for_level(level),
+                experience,
+                "{:?} at level {}",
+                formula,
+                level
+            );
+            assert_eq!(formula.level_for_experience(experience), level);
+            assert_eq!(
+                formula.level_for_experience(experience - 1),
+                level.max(2) - 1
+            );
+        }
+    }
+}
+
+#[test]
+fn levels_tables_are_preferred_to_formulas() {
+    let mut growth_rate: GrowthRate = serde_json::from_str(&fixture("growth-rate/4")).unwrap();
+    assert_eq!(experience_for_level(&growth_rate, 1), Some(0));
+    assert_eq!(experience_for_level(&growth_rate, 100), Some(1_059_860));
+    assert_eq!(level_for_experience(&growth_rate, 135), Some(5));
+
+    growth_rate.levels = None;
+    assert_eq!(experience_for_level(&growth_rate, 100), Some(1_059_860));
+    growth_rate.name = None;
+    assert_eq!(experience_for_level(&growth_rate, 100), None);
+    assert_eq!(level_for_experience(&growth_rate, 135), None);
+}
+
+fn participant(level: i64) -> Recipient {
+    Recipient {
+        level,
+        participated: true,
+        ..Recipient::default()
+    }
+}
+
+#[test]
+fn experience_gain_by_generation() {
+    let wild = Defeated {
+        base_experience: 64,
+        level: 10,
+        trainer: false,
+    };
+    let trainer = Defeated {
+        trainer: true,
+        ..wild
+    };
+    let holder = Recipient {
+        level: 10,
+        exp_share: true,
+        ..Recipient::default()
+    };
+    let bystander = Recipient {
+        level: 10,
+        ..Recipient::default()
+    };
+    let pampered = Recipient {
+        traded: true,
+        international: true,
+        lucky_egg: true,
+        ..participant(10)
+    };
+
+    let cases = [
+        // 64 × 10 / 7 up to Generation IV and in Generation VI.
+        (Generation::IV, wild, vec![participant(10)], vec![91]),
+        (Generation::VI, wild, vec![participant(10)], vec![91]),
+        // 64 × 10 / 5 + 1, scaled by the levels, in Generation V and from Generation VII.
+        (Generation::V, wild, vec![participant(10)], vec![129]),
+        (Generation::VII, wild, vec![participant(10)], vec![129]),
+        (Generation::V, wild, vec![participant(30)], vec![36]),
+        (Generation::V, wild, vec![participant(5)], vec![202]),
+        // Trainers' Pokémon give half as much again until Generation VII.
+        (Generation::IV, trainer, vec![participant(10)], vec![137]),
+        (Generation::VI, trainer, vec![participant(10)], vec![137]),
+        (Generation::VII, trainer, vec![participant(10)], vec![129]),
+        // Up to Generation V participants split it, and share half with Exp. Share holders.
+        (
+            Generation::IV,
+            wild,
+            vec![participant(10), participant(10)],
+            vec![45, 45],
+        ),
+        (
+            Generation::IV,
+            wild,
+            vec![participant(10), holder, bystander],
+            vec![45, 45, 0],
+        ),
+        // From Generation VI participants get it all, and the others half through the Exp. Share.
+        (
+            Generation::VI,
+            wild,
+            vec![participant(10), participant(10), holder, bystander],
+            vec![91, 91, 45, 0],
+        ),
+        // International trades give more from Generation IV, and a Lucky Egg adds half.
+        (Generation::III, wild, vec![pampered], vec![205]),
+        (Generation::IV, wild, vec![pampered], vec![233]),
+    ];
+    for (generation, defeated, recipients, expected) in cases {
+        assert_eq!(
+            experience_gain(generation, &defeated, &recipients),
+            expected,
+            "{:?} with {:?}",
+            generation,
+            recipients
+        );
+    }
+}
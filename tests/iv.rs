@@ -0,0 +1,129 @@
+//! Checks the IV solver against stats calculated for Pikachu from the fixtures.
+
+mod common;
+
+use common::fixture;
+use pokemon_game::{
+    iv::{self, Hints, Observation},
+    model::pokemon::{Characteristic, Nature, Pokemon},
+    stats::{self, PossibleIvs, Stats},
+};
+
+fn pikachu() -> Pokemon {
+    serde_json::from_str(&fixture("pokemon/25")).unwrap()
+}
+
+fn timid() -> Nature {
+    serde_json::from_str(&fixture("nature/5")).unwrap()
+}
+
+fn ivs() -> Stats {
+    Stats {
+        hp: 30,
+        attack: 4,
+        defence: 17,
+        special_attack: 31,
+        special_defence: 22,
+        speed: 9,
+    }
+}
+
+/// The stats Pikachu with the IVs above and no EVs has at a level.
+fn observe(level: i64, nature: Option<&Nature>) -> Observation {
+    let base = Stats::from_base_stats(pikachu().stats.as_deref().unwrap());
+    let ev = Stats::default();
+    Observation {
+        level,
+        ev,
+        stats: stats::calculate(&base, &ivs(), &ev, level, nature),
+    }
+}
+
+fn contains(possible: &PossibleIvs, iv: &Stats) -> bool {
+    Stats::NAMES.into_iter().all(|stat| {
+        possible
+            .get(stat)
+            .is_some_and(|values| values.contains(&iv.get(stat).unwrap()))
+    })
+}
+
+#[test]
+fn stats_at_level_100_settle_every_iv() {
+    let possible = iv::solve(&pikachu(), None, &[observe(100, None)], &Hints::default());
+    assert!(possible.is_settled());
+    assert!(contains(&possible, &ivs()));
+}
+
+#[test]
+fn more_observations_narrow_ivs_down_further() {
+    let timid = timid();
+    let solve = |observations: &[Observation]| {
+        iv::solve(&pikachu(), Some(&timid), observations, &Hints::default())
+    };
+    let young = solve(&[observe(10, Some(&timid))]);
+    assert!(contains(&young, &ivs()));
+    assert!(!young.is_settled());
+
+    let both = solve(&[observe(10, Some(&timid)), observe(60, Some(&timid))]);
+    assert!(contains(&both, &ivs()));
+    for stat in Stats::NAMES {
+        assert!(both.get(stat).unwrap().len() <= young.get(stat).unwrap().len());
+    }
+
+    // Stats from a Pokémon with another nature cannot all come from the same IVs.
+    let mixed = solve(&[observe(100, Some(&timid)), observe(100, None)]);
+    assert!(mixed.is_contradictory());
+}
+
+#[test]
+fn hints_narrow_ivs_down() {
+    let characteristic: Characteristic =
+        serde_json::from_str(&fixture("characteristic/1")).unwrap();
+    let hints = Hints {
+        characteristic: Some(&characteristic),
+        personality: None,
+        hidden_power: None,
+    };
+    // Highest HP, a multiple of 5, while Special Attack is 31: no IVs fit.
+    let possible = iv::solve(&pikachu(), None, &[observe(100, None)], &hints);
+    assert!(possible.is_contradictory());
+
+    let possible = iv::solve(&pikachu(), None, &[], &hints);
+    assert_eq!(possible.range("hp"), Some(0..=30));
+    assert_eq!(possible.get("hp").unwrap().len(), 7);
+}
+
+#[test]
+fn hidden_power_follows_the_lowest_bits() {
+    let all = |value| Stats {
+        hp: value,
+        attack: value,
+        defence: value,
+        special_attack: value,
+        special_defence: value,
+        speed: value,
+    };
+    assert_eq!(iv::hidden_power_type(&all(31)), "dark");
+    assert_eq!(iv::hidden_power_type(&all(30)), "fighting");
+    assert_eq!(iv::hidden_power_type(&ivs()), "ghost");
+
+    // Only odd IVs give a Dark Hidden Power.
+    let mut possible = PossibleIvs::default();
+    iv::narrow_by_hidden_power(&mut possible, "dark");
+    assert!(Stats::NAMES.into_iter().all(|stat| possible
+        .get(stat)
+        .unwrap()
+        .iter()
+        .all(|value| value % 2 == 1)));
+
+    // A Hidden Power type narrows only the IVs whose bits can still vary.
+    let hints = Hints {
+        hidden_power: Some("ghost"),
+        ..Hints::default()
+    };
+    let possible = iv::solve(&pikachu(), None, &[observe(100, None)], &hints);
+    assert!(contains(&possible, &ivs()));
+    let possible = iv::solve(&pikachu(), None, &[], &hints);
+    assert!(!possible.is_contradictory());
+    assert!(!possible.is_settled());
+}
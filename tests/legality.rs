@@ -0,0 +1,317 @@
+//! Checks every rule of the legality validator against Pikachu and its evolution chain in the fixtures.
+
+mod common;
+
+use common::fixture;
+use pokemon_game::{
+    generation::Generation,
+    legality::{Rules, Violation},
+    model::{
+        evolution::EvolutionChain,
+        pokemon::{GrowthRate, Pokemon, PokemonSpecies},
+    },
+    owned::{Gender, OwnedPokemon},
+    stats::Stats,
+};
+
+fn parse<T: serde::de::DeserializeOwned>(path: &str) -> T {
+    serde_json::from_str(&fixture(path)).unwrap()
+}
+
+/// The data Pikachu is checked against.
+struct Data {
+    pokemon: Pokemon,
+    species: PokemonSpecies,
+    chain: EvolutionChain,
+    growth_rate: GrowthRate,
+}
+
+impl Data {
+    fn load() -> Self {
+        Self {
+            pokemon: parse("pokemon/25"),
+            species: parse("pokemon-species/25"),
+            chain: parse("evolution-chain/10"),
+            growth_rate: parse("growth-rate/2"),
+        }
+    }
+
+    fn rules(&self, version_group: &'static str) -> Rules<'_> {
+        Rules {
+            species: Some(&self.species),
+            chain: Some(&self.chain),
+            growth_rate: Some(&self.growth_rate),
+            ..Rules::new(version_group, &self.pokemon)
+        }
+    }
+}
+
+/// A Pikachu that breaks no rule in Sword and Shield.
+fn pikachu() -> OwnedPokemon {
+    let mut pikachu = OwnedPokemon::new("pikachu", 50);
+    pikachu.experience = 125_000;
+    pikachu.gender = Gender::Female;
+    pikachu.ability = Some("static".to_string());
+    pikachu.moves = vec!["thunderbolt".to_string(), "thunder-wave".to_string()];
+    pikachu.iv = Stats {
+        hp: 31,
+        attack: 31,
+        defence: 31,
+        special_attack: 31,
+        special_defence: 31,
+        speed: 31,
+    };
+    pikachu.ev = Stats {
+        special_attack: 252,
+        speed: 252,
+        hp: 4,
+        ..Stats::default()
+    };
+    pikachu
+}
+
+fn violations(pikachu: &OwnedPokemon, rules: &Rules) -> Vec<Violation> {
+    pikachu.validate(rules)
+}
+
+#[test]
+fn a_legal_pokemon_breaks_no_rule() {
+    let data = Data::load();
+    assert_eq!(violations(&pikachu(), &data.rules("sword-shield")), []);
+}
+
+#[test]
+fn the_pokemon_and_its_level_are_checked() {
+    let data = Data::load();
+    let rules = data.rules("sword-shield");
+
+    let mut raichu = pikachu();
+    raichu.pokemon = "raichu".to_string();
+    assert_eq!(
+        violations(&raichu, &rules),
+        [Violation::WrongPokemon {
+            expected: "pikachu".to_string(),
+            found: "raichu".to_string(),
+        }]
+    );
+
+    let mut pikachu_101 = pikachu();
+    pikachu_101.level = 101;
+    pikachu_101.experience = 1_000_000;
+    assert_eq!(
+        violations(&pikachu_101, &rules),
+        [
+            Violation::LevelOutOfRange(101),
+            Violation::ExperienceMismatch {
+                experience: 1_000_000,
+                level: 101,
+            },
+        ]
+    );
+
+    let mut unexperienced = pikachu();
+    unexperienced.experience = 0;
+    assert_eq!(
+        violations(&unexperienced, &rules),
+        [Violation::ExperienceMismatch {
+            experience: 0,
+            level: 50,
+        }]
+    );
+
+    let mut devoted = pikachu();
+    devoted.friendship = 256;
+    assert_eq!(
+        violations(&devoted, &rules),
+        [Violation::FriendshipOutOfRange(256)]
+    );
+
+    let mut genderless = pikachu();
+    genderless.gender = Gender::Genderless;
+    assert_eq!(
+        violations(&genderless, &rules),
+        [Violation::ImpossibleGender(Gender::Genderless)]
+    );
+}
+
+#[test]
+fn evolution_levels_are_checked() {
+    // Pikachu evolves from Pichu by friendship, which sets no level, so the chain gets one here.
+    let mut data = Data::load();
+    let link = data
+        .chain
+        .chain
+        .as_mut()
+        .unwrap()
+        .evolves_to
+        .as_mut()
+        .unwrap();
+    link[0].evolution_details.as_mut().unwrap()[0].min_level = Some(60);
+    assert_eq!(
+        violations(&pikachu(), &data.rules("sword-shield")),
+        [Violation::BelowEvolutionLevel {
+            species: "pikachu".to_string(),
+            min_level: 60,
+        }]
+    );
+}
+
+#[test]
+fn abilities_are_checked() {
+    let data = Data::load();
+    let rules = data.rules("sword-shield");
+
+    let mut without = pikachu();
+    without.ability = None;
+    assert_eq!(violations(&without, &rules), [Violation::MissingAbility]);
+
+    let mut overgrow = pikachu();
+    overgrow.ability = Some("overgrow".to_string());
+    assert_eq!(
+        violations(&overgrow, &rules),
+        [Violation::UnavailableAbility("overgrow".to_string())]
+    );
+
+    let mut hidden = pikachu();
+    hidden.ability = Some("lightning-rod".to_string());
+    assert_eq!(violations(&hidden, &rules), []);
+    let no_hidden_abilities = Rules {
+        hidden_abilities: false,
+        ..rules
+    };
+    assert_eq!(
+        violations(&hidden, &no_hidden_abilities),
+        [Violation::HiddenAbility("lightning-rod".to_string())]
+    );
+}
+
+#[test]
+fn moves_are_checked() {
+    let data = Data::load();
+    let rules = data.rules("sword-shield");
+    let moves = |moves: &[&str]| {
+        let mut pikachu = pikachu();
+        pikachu.moves = moves.iter().map(|move_| move_.to_string()).collect();
+        pikachu
+    };
+
+    let five = moves(&[
+        "thunderbolt",
+        "thunder-wave",
+        "thunder-shock",
+        "growl",
+        "hydro-pump",
+    ]);
+    assert_eq!(
+        violations(&five, &rules),
+        [
+            Violation::TooManyMoves(5),
+            Violation::UnlearnableMove("hydro-pump".to_string()),
+        ]
+    );
+    assert_eq!(
+        violations(&moves(&["growl", "growl"]), &rules),
+        [Violation::DuplicateMove("growl".to_string())]
+    );
+    // Known in another version group, but not this one.
+    assert_eq!(
+        violations(&moves(&["growl"]), &data.rules("crystal")),
+        [Violation::UnlearnableMove("growl".to_string())]
+    );
+
+    let mut young = OwnedPokemon::new("pikachu", 3);
+    young.experience = 27;
+    young.gender = Gender::Male;
+    young.ability = Some("static".to_string());
+    young.moves = vec!["thunder-shock".to_string(), "thunder-wave".to_string()];
+    assert_eq!(
+        violations(&young, &rules),
+        [Violation::MoveAboveLevel {
+            move_: "thunder-wave".to_string(),
+            level: 4,
+        }]
+    );
+}
+
+#[test]
+fn ivs_and_evs_are_checked() {
+    let data = Data::load();
+    let rules = data.rules("sword-shield");
+
+    let mut perfect = pikachu();
+    perfect.iv.speed = 32;
+    assert_eq!(
+        violations(&perfect, &rules),
+        [Violation::IvOutOfRange {
+            stat: "speed",
+            value: 32,
+        }]
+    );
+
+    let mut trained = pikachu();
+    trained.ev.speed = 255;
+    trained.ev.special_attack = 0;
+    assert_eq!(
+        violations(&trained, &rules),
+        [Violation::EvOutOfRange {
+            stat: "speed",
+            value: 255,
+            max: 252,
+        }]
+    );
+
+    let mut overtrained = pikachu();
+    overtrained.ev = Stats {
+        hp: 100,
+        attack: 100,
+        defence: 100,
+        special_attack: 100,
+        special_defence: 100,
+        speed: 100,
+    };
+    assert_eq!(
+        violations(&overtrained, &rules),
+        [Violation::EvTotalTooHigh(600)]
+    );
+}
+
+#[test]
+fn ev_limits_follow_the_generation() {
+    let data = Data::load();
+    let evs = |generation: Generation, speed: i64, total: i64| {
+        let mut pikachu = pikachu();
+        pikachu.moves.clear();
+        pikachu.ev = Stats {
+            speed,
+            hp: total - speed,
+            ..Stats::default()
+        };
+        let rules = Rules {
+            generation,
+            ..data.rules("sword-shield")
+        };
+        violations(&pikachu, &rules)
+    };
+
+    assert_eq!(data.rules("black-white").generation, Generation::V);
+    assert_eq!(data.rules("x-y").generation, Generation::VI);
+    assert_eq!(evs(Generation::V, 255, 510), []);
+    assert_eq!(
+        evs(Generation::VI, 255, 255),
+        [Violation::EvOutOfRange {
+            stat: "speed",
+            value: 255,
+            max: 252,
+        }]
+    );
+    assert_eq!(
+        evs(Generation::III, 256, 256),
+        [Violation::EvOutOfRange {
+            stat: "speed",
+            value: 256,
+            max: 255,
+        }]
+    );
+    // Stat experience, from 0 to 65535 in every stat.
+    assert_eq!(evs(Generation::II, 65535, 65535 + 1000), []);
+}
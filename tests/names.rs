@@ -0,0 +1,70 @@
+//! Checks that every known name parses from, displays as and is (de)serialized as its PokeAPI name.
+
+use std::{
+    fmt::{Debug, Display},
+    str::FromStr,
+};
+
+use pokemon_game::{
+    model::resource::NamedApiResource,
+    names::{Ailment, DamageClass, PokemonTypeName, StatName, UnknownName},
+};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::json;
+
+/// Round-trips every name of a kind, and checks that a name it does not know is refused.
+fn check<T>(all: &[T], kind: &str)
+where
+    T: FromStr<Err = UnknownName>
+        + Display
+        + Serialize
+        + DeserializeOwned
+        + for<'a> TryFrom<&'a NamedApiResource, Error = UnknownName>
+        + Copy
+        + PartialEq
+        + Debug,
+{
+    for &name in all {
+        let text = name.to_string();
+        assert_eq!(text.parse::<T>(), Ok(name));
+        assert_eq!(serde_json::to_value(name).unwrap(), json!(text));
+        assert_eq!(serde_json::from_value::<T>(json!(text)).unwrap(), name);
+        let resource: NamedApiResource = serde_json::from_value(json!({ "name": text })).unwrap();
+        assert_eq!(T::try_from(&resource), Ok(name));
+    }
+
+    let unknown = "not-a-name".parse::<T>().unwrap_err();
+    assert_eq!((unknown.kind, unknown.name.as_str()), (kind, "not-a-name"));
+    assert_eq!(
+        unknown.to_string(),
+        format!("unknown {} name `not-a-name`", kind)
+    );
+    assert!(serde_json::from_value::<T>(json!("not-a-name")).is_err());
+    let nameless: NamedApiResource = serde_json::from_value(json!({})).unwrap();
+    assert_eq!(T::try_from(&nameless).unwrap_err().name, "");
+}
+
+#[test]
+fn type_names_round_trip() {
+    check(PokemonTypeName::ALL, "type");
+    assert_eq!(PokemonTypeName::ALL.len(), 21);
+    assert_eq!(PokemonTypeName::Electric.name(), "electric");
+}
+
+#[test]
+fn stat_names_round_trip() {
+    check(StatName::ALL, "stat");
+    assert_eq!(StatName::SpecialAttack.name(), "special-attack");
+}
+
+#[test]
+fn damage_class_names_round_trip() {
+    check(DamageClass::ALL, "damage class");
+    assert_eq!(DamageClass::ALL.len(), 3);
+}
+
+#[test]
+fn ailment_names_round_trip() {
+    check(Ailment::ALL, "ailment");
+    assert_eq!(Ailment::HealBlock.name(), "heal-block");
+}
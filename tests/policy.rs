@@ -0,0 +1,319 @@
+//! Checks retries, rate limiting and revalidation of [`Http`] against a local stand-in for PokeAPI.
+
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use pokemon_game::{
+    error::Error,
+    policy::{RateLimiter, RequestPolicy},
+    source::{DataSource, Http},
+};
+use reqwest::Url;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+};
+
+/// A response of the stand-in server.
+#[derive(Debug, Clone)]
+struct Reply {
+    status: u16,
+    headers: &'static str,
+    body: &'static str,
+    delay: Duration,
+}
+
+fn reply(status: u16) -> Reply {
+    Reply {
+        status,
+        headers: "",
+        body: if status == 200 { r#"{"id": 25}"# } else { "" },
+        delay: Duration::ZERO,
+    }
+}
+
+/// Answers each request with the next reply, then with the last one, and records the requests.
+#[derive(Debug, Clone)]
+struct Server {
+    base: Url,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl Server {
+    async fn start(replies: Vec<Reply>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base = Url::parse(&format!(
+            "http://{}/api/v2/",
+            listener.local_addr().unwrap()
+        ))
+        .unwrap();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let replies = Arc::new(Mutex::new(VecDeque::from(replies)));
+        let counted = requests.clone();
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let replies = replies.clone();
+                let counted = counted.clone();
+                tokio::spawn(async move {
+                    let mut request = Vec::new();
+                    let mut buffer = [0; 1024];
+                    while !request.ends_with(b"\r\n\r\n") {
+                        match stream.read(&mut buffer).await {
+                            Ok(0) | Err(_) => return,
+                            Ok(read) => request.extend_from_slice(&buffer[..read]),
+                        }
+                    }
+                    let request = String::from_utf8_lossy(&request).to_lowercase();
+                    counted.lock().unwrap().push(request);
+                    let reply = {
+                        let mut replies = replies.lock().unwrap();
+                        if replies.len() > 1 {
+                            replies.pop_front().unwrap()
+                        } else {
+                            replies[0].clone()
+                        }
+                    };
+                    tokio::time::sleep(reply.delay).await;
+                    let response = format!(
+                        "HTTP/1.1 {} Stand-in\r\nContent-Length: {}\r\nConnection: close\r\n{}\r\n{}",
+                        reply.status,
+                        reply.body.len(),
+                        reply.headers,
+                        reply.body
+                    );
+                    let _ = stream.write_all(response.as_bytes()).await;
+                });
+            }
+        });
+        Self { base, requests }
+    }
+
+    fn requests(&self) -> usize {
+        self.requests.lock().unwrap().len()
+    }
+
+    /// The request line and headers of a request, in lowercase.
+    fn request(&self, index: usize) -> String {
+        self.requests.lock().unwrap()[index].clone()
+    }
+
+    fn http(&self, policy: RequestPolicy) -> Http {
+        Http::with_base(self.base.clone()).with_policy(policy)
+    }
+
+    fn revalidating(&self) -> Http {
+        self.http(quick_policy()).with_revalidation()
+    }
+}
+
+fn quick_policy() -> RequestPolicy {
+    RequestPolicy {
+        timeout: Duration::from_millis(500),
+        max_retries: 3,
+        initial_backoff: Duration::from_millis(10),
+        max_backoff: Duration::from_secs(2),
+        rate_limiter: None,
+    }
+}
+
+#[tokio::test]
+async fn server_errors_are_retried() {
+    let server = Server::start(vec![reply(500), reply(503), reply(200)]).await;
+    let json = server
+        .http(quick_policy())
+        .fetch("pokemon/25")
+        .await
+        .unwrap();
+    assert_eq!(json, r#"{"id": 25}"#);
+    assert_eq!(server.requests(), 3);
+}
+
+#[tokio::test]
+async fn retries_give_up_after_the_last_one() {
+    let server = Server::start(vec![reply(502)]).await;
+    let result = server.http(quick_policy()).fetch("pokemon/25").await;
+    assert!(matches!(result, Err(Error::Request(_))), "{:?}", result);
+    assert_eq!(server.requests(), 4);
+}
+
+#[tokio::test]
+async fn client_errors_are_not_retried() {
+    let server = Server::start(vec![reply(404)]).await;
+    let result = server.http(quick_policy()).fetch("pokemon/missingno").await;
+    assert!(matches!(result, Err(Error::NotFound(_))), "{:?}", result);
+    assert_eq!(server.requests(), 1);
+}
+
+#[tokio::test]
+async fn timeouts_are_retried() {
+    let slow = Reply {
+        delay: Duration::from_secs(2),
+        ..reply(200)
+    };
+    let server = Server::start(vec![slow, reply(200)]).await;
+    let json = server
+        .http(quick_policy())
+        .fetch("pokemon/25")
+        .await
+        .unwrap();
+    assert_eq!(json, r#"{"id": 25}"#);
+    assert_eq!(server.requests(), 2);
+}
+
+#[tokio::test]
+async fn retry_after_is_honoured() {
+    let busy = Reply {
+        headers: "Retry-After: 1\r\n",
+        ..reply(429)
+    };
+    let server = Server::start(vec![busy, reply(200)]).await;
+    let start = Instant::now();
+    server
+        .http(quick_policy())
+        .fetch("pokemon/25")
+        .await
+        .unwrap();
+    assert!(start.elapsed() >= Duration::from_secs(1));
+    assert_eq!(server.requests(), 2);
+
+    // Waiting longer than the policy allows is not worth a retry.
+    let down = Reply {
+        headers: "Retry-After: 3600\r\n",
+        ..reply(503)
+    };
+    let server = Server::start(vec![down, reply(200)]).await;
+    let result = server.http(quick_policy()).fetch("pokemon/25").await;
+    assert!(matches!(result, Err(Error::Request(_))), "{:?}", result);
+    assert_eq!(server.requests(), 1);
+}
+
+#[tokio::test]
+async fn rate_limiter_is_shared_between_sources() {
+    let rate_limiter = Arc::new(RateLimiter::new(20.0, 1));
+    let policy = RequestPolicy {
+        rate_limiter: Some(rate_limiter),
+        ..quick_policy()
+    };
+    let server = Server::start(vec![reply(200)]).await;
+    let first = server.http(policy.clone());
+    let second = server.http(policy);
+
+    let start = Instant::now();
+    for _ in 0..3 {
+        first.fetch("pokemon/25").await.unwrap();
+        second.fetch("pokemon/25").await.unwrap();
+    }
+    // The first request takes the only token and the other 5 wait 50 ms each for theirs.
+    assert!(
+        start.elapsed() >= Duration::from_millis(250),
+        "{:?}",
+        start.elapsed()
+    );
+    assert_eq!(server.requests(), 6);
+}
+
+#[test]
+fn backoff_doubles_up_to_its_limit() {
+    let policy = RequestPolicy {
+        initial_backoff: Duration::from_millis(100),
+        max_backoff: Duration::from_millis(500),
+        ..RequestPolicy::none()
+    };
+    let waits: Vec<_> = (0..5)
+        .map(|retries| policy.backoff(retries).as_millis())
+        .collect();
+    assert_eq!(waits, [100, 200, 400, 500, 500]);
+}
+
+#[tokio::test]
+async fn unchanged_resources_are_not_downloaded_again() {
+    let tagged = Reply {
+        headers: "ETag: \"v1\"\r\n",
+        ..reply(200)
+    };
+    let server = Server::start(vec![tagged, reply(304)]).await;
+    let http = server.revalidating();
+    let json = http.fetch("pokemon/25").await.unwrap();
+    assert_eq!(http.fetch("pokemon/25").await.unwrap(), json);
+    assert!(!server.request(0).contains("if-none-match"));
+    assert!(server.request(1).contains("if-none-match: \"v1\""));
+
+    let dated = Reply {
+        headers: "Last-Modified: Wed, 21 Oct 2015 07:28:00 GMT\r\n",
+        ..reply(200)
+    };
+    let server = Server::start(vec![dated, reply(304)]).await;
+    let http = server.revalidating();
+    let json = http.fetch("pokemon/25").await.unwrap();
+    assert_eq!(http.clone().fetch("pokemon/25").await.unwrap(), json);
+    assert!(server
+        .request(1)
+        .contains("if-modified-since: wed, 21 oct 2015 07:28:00 gmt"));
+}
+
+#[tokio::test]
+async fn stale_responses_are_served_while_the_server_is_down() {
+    let server = Server::start(vec![reply(200), reply(503)]).await;
+    let http = server.revalidating();
+    let json = http.fetch("pokemon/25").await.unwrap();
+    assert_eq!(http.fetch("pokemon/25").await.unwrap(), json);
+    assert_eq!(server.requests(), 5);
+
+    let result = http.fetch("pokemon/26").await;
+    assert!(matches!(result, Err(Error::Request(_))), "{:?}", result);
+    let unresponsive = Reply {
+        delay: Duration::from_secs(2),
+        ..reply(200)
+    };
+    let server = Server::start(vec![reply(200), unresponsive]).await;
+    let http = server.revalidating();
+    let json = http.fetch("pokemon/25").await.unwrap();
+    assert_eq!(http.fetch("pokemon/25").await.unwrap(), json);
+}
+
+#[tokio::test]
+async fn removed_resources_are_not_served_stale() {
+    let server = Server::start(vec![reply(200), reply(404), reply(503)]).await;
+    let http = server.revalidating();
+    http.fetch("pokemon/25").await.unwrap();
+    let result = http.fetch("pokemon/25").await;
+    assert!(matches!(result, Err(Error::NotFound(_))), "{:?}", result);
+    let result = http.fetch("pokemon/25").await;
+    assert!(matches!(result, Err(Error::Request(_))), "{:?}", result);
+}
+
+#[tokio::test]
+async fn responses_are_only_kept_with_revalidation() {
+    let tagged = Reply {
+        headers: "ETag: \"v1\"\r\n",
+        ..reply(200)
+    };
+    let server = Server::start(vec![tagged.clone(), tagged, reply(503)]).await;
+    let http = server.http(RequestPolicy::none());
+    http.fetch("pokemon/25").await.unwrap();
+    http.fetch("pokemon/25").await.unwrap();
+    assert!(!server.request(1).contains("if-none-match"));
+    let result = http.fetch("pokemon/25").await;
+    assert!(matches!(result, Err(Error::Request(_))), "{:?}", result);
+}
+
+#[tokio::test]
+async fn only_the_latest_responses_are_kept() {
+    let tagged = Reply {
+        headers: "ETag: \"v1\"\r\n",
+        ..reply(200)
+    };
+    let server = Server::start(vec![tagged]).await;
+    let http = server.http(quick_policy()).with_revalidation_of(1);
+    http.fetch("pokemon/25").await.unwrap();
+    http.fetch("pokemon/26").await.unwrap();
+    http.fetch("pokemon/25").await.unwrap();
+    http.fetch("pokemon/25").await.unwrap();
+    assert!(server.request(1).starts_with("get /api/v2/pokemon/26"));
+    assert!(!server.request(2).contains("if-none-match"));
+    assert!(server.request(3).contains("if-none-match: \"v1\""));
+}
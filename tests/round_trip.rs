@@ -0,0 +1,93 @@
+//! Checks that serializing a model gives back the JSON it was deserialized from, so that fetched data can
+//! be stored or sent on and read again without loss.
+
+mod common;
+
+use std::fmt::Debug;
+
+use common::{fixture, fixture_paths, with_model, ModelVisitor};
+use pokemon_game::model::{
+    moves::Move,
+    pokemon::{Pokemon, PokemonSpecies},
+    resource::NamedApiResourceList,
+    to_canonical_json,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{json, Value};
+
+/// Deserializes the JSON of a fixture into the model of its endpoint and serializes it again,
+/// describing what did not survive the round trip.
+struct RoundTrip;
+
+impl ModelVisitor for RoundTrip {
+    type Output = Result<(), String>;
+
+    fn visit<T: DeserializeOwned + Serialize + PartialEq + Debug>(
+        self,
+        json: &str,
+    ) -> Self::Output {
+        let original: Value = serde_json::from_str(json).map_err(|error| error.to_string())?;
+        let model: T = serde_json::from_str(json).map_err(|error| error.to_string())?;
+        let serialized = serde_json::to_value(&model).map_err(|error| error.to_string())?;
+        if serialized != original {
+            return Err(format!("serializes to\n{:#}", serialized));
+        }
+        let again: T = serde_json::from_value(serialized).map_err(|error| error.to_string())?;
+        if again != model {
+            return Err(format!("deserializes again to\n{:#?}", again));
+        }
+        let canonical = to_canonical_json(&model).map_err(|error| error.to_string())?;
+        if canonical != serde_json::to_string(&original).unwrap() {
+            return Err(format!("has the canonical JSON\n{}", canonical));
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn every_fixture_survives_a_round_trip() {
+    let mut failures = Vec::new();
+    for path in fixture_paths() {
+        if let Err(failure) = with_model(&path, &fixture(&path), RoundTrip) {
+            failures.push(format!("{}: {}", path, failure));
+        }
+    }
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+#[test]
+fn renamed_fields_serialize_under_their_pokeapi_names() {
+    let pikachu: Pokemon = serde_json::from_str(&fixture("pokemon/25")).unwrap();
+    let value = serde_json::to_value(&pikachu).unwrap();
+    assert_eq!(value["types"][0]["type"]["name"], "electric");
+    assert!(value["types"][0].get("type_").is_none());
+    assert!(value["moves"][0]["move"]["name"].is_string());
+    assert!(value["moves"][0].get("move_").is_none());
+
+    let thunderbolt: Move = serde_json::from_str(&fixture("move/85")).unwrap();
+    let value = serde_json::to_value(&thunderbolt).unwrap();
+    assert_eq!(value["type"]["name"], "electric");
+    assert!(value["contest_combos"]["super"].is_object());
+}
+
+#[test]
+fn missing_fields_serialize_as_null() {
+    let species: PokemonSpecies = serde_json::from_str(r#"{"id": 25, "name": "pikachu"}"#).unwrap();
+    let value = serde_json::to_value(&species).unwrap();
+    assert_eq!(value["id"], json!(25));
+    assert_eq!(value["base_happiness"], Value::Null);
+    let again: PokemonSpecies = serde_json::from_value(value).unwrap();
+    assert_eq!(again, species);
+}
+
+#[test]
+fn canonical_json_is_compact_and_sorted() {
+    let list: NamedApiResourceList = serde_json::from_str(
+        r#"{"results": [{"url": "https://pokeapi.co/api/v2/pokemon/1/", "name": "bulbasaur"}], "count": 1}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        to_canonical_json(&list).unwrap(),
+        r#"{"count":1,"next":null,"previous":null,"results":[{"name":"bulbasaur","url":"https://pokeapi.co/api/v2/pokemon/1/"}]}"#
+    );
+}
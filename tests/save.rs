@@ -0,0 +1,214 @@
+//! Checks that save files give back the progress saved in them and are migrated when opened.
+
+use std::{path::PathBuf, time::Duration};
+
+use pokemon_game::{
+    owned::{Gender, OwnedPokemon},
+    save::{ItemStack, PcBox, Profile, SaveData, SaveError, SaveFile},
+    stats::Stats,
+};
+use rusqlite::Connection;
+
+/// The number of migrations, which is the version of every file once opened.
+const LATEST_VERSION: i64 = 2;
+
+/// A path in the temporary directory, removed first in case an earlier run left it behind.
+fn temporary(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("{}-{}.sqlite", name, std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+fn stack(item: &str, quantity: i64) -> ItemStack {
+    ItemStack {
+        item: item.to_string(),
+        quantity,
+    }
+}
+
+fn progress() -> SaveData {
+    let mut pikachu = OwnedPokemon::new("pikachu", 25);
+    pikachu.nickname = Some("Sparky".to_string());
+    pikachu.experience = 15625;
+    pikachu.gender = Gender::Female;
+    pikachu.nature = Some("timid".to_string());
+    pikachu.ability = Some("static".to_string());
+    pikachu.held_item = Some("light-ball".to_string());
+    pikachu.moves = vec!["thunderbolt".to_string(), "quick-attack".to_string()];
+    pikachu.personality = 0xDEAD_BEEF;
+    pikachu.shiny = true;
+    pikachu.iv = Stats {
+        hp: 31,
+        attack: 0,
+        defence: 31,
+        special_attack: 31,
+        special_defence: 31,
+        speed: 31,
+    };
+    pikachu.ev = Stats {
+        special_attack: 252,
+        speed: 252,
+        hp: 4,
+        ..Stats::default()
+    };
+
+    let mut first_box = PcBox::new("Box 1");
+    first_box.slots[0] = Some(OwnedPokemon::new("rattata", 3));
+    first_box.slots[29] = Some(OwnedPokemon::new("geodude", 12));
+
+    SaveData {
+        profile: Profile {
+            name: "Red".to_string(),
+            trainer_id: 0x1234_5678,
+            money: 3000,
+            play_time: Duration::from_millis(3_723_456),
+        },
+        party: vec![pikachu, OwnedPokemon::new("caterpie", 5)],
+        boxes: vec![first_box, PcBox::new("Box 2")],
+        // Out of alphabetical order, with two stacks of Potions.
+        inventory: vec![
+            stack("potion", 999),
+            stack("antidote", 2),
+            stack("potion", 4),
+        ],
+        badges: vec!["boulder-badge".to_string(), "cascade-badge".to_string()],
+    }
+}
+
+#[test]
+fn saved_progress_loads_back_unchanged() {
+    let mut file = SaveFile::in_memory().unwrap();
+    assert_eq!(file.load().unwrap(), None);
+
+    let data = progress();
+    file.save(&data).unwrap();
+    assert_eq!(file.load().unwrap(), Some(data.clone()));
+
+    // Saving again replaces the earlier progress instead of adding to it.
+    let mut data = data;
+    data.inventory.reverse();
+    data.party.pop();
+    file.save(&data).unwrap();
+    assert_eq!(file.load().unwrap(), Some(data));
+}
+
+#[test]
+fn progress_that_cannot_load_back_is_not_saved() {
+    let mut file = SaveFile::in_memory().unwrap();
+    let saved = progress();
+    file.save(&saved).unwrap();
+
+    let mut data = progress();
+    data.boxes[1]
+        .slots
+        .push(Some(OwnedPokemon::new("zubat", 8)));
+    let result = file.save(&data);
+    assert!(matches!(result, Err(SaveError::Invalid(_))), "{:?}", result);
+
+    let mut data = progress();
+    data.badges.push("boulder-badge".to_string());
+    let result = file.save(&data);
+    assert!(matches!(result, Err(SaveError::Invalid(_))), "{:?}", result);
+
+    // Fewer slots than a full box are fine, and the last progress saved is kept.
+    assert_eq!(file.load().unwrap(), Some(saved));
+    let mut data = progress();
+    data.boxes[1].slots.truncate(10);
+    file.save(&data).unwrap();
+}
+
+#[test]
+fn migrations_set_the_version_of_the_file() {
+    let file = SaveFile::in_memory().unwrap();
+    assert_eq!(file.version().unwrap(), LATEST_VERSION);
+
+    // A file of the first version, which kept the bag by item and play time in seconds.
+    let path = temporary("version-1");
+    let connection = Connection::open(&path).unwrap();
+    connection
+        .execute_batch(
+            "CREATE TABLE trainer (
+                id INTEGER PRIMARY KEY CHECK (id = 1),
+                name TEXT NOT NULL,
+                trainer_id INTEGER NOT NULL,
+                money INTEGER NOT NULL,
+                play_time INTEGER NOT NULL
+            );
+            CREATE TABLE pc_box (
+                number INTEGER PRIMARY KEY,
+                name TEXT NOT NULL
+            );
+            CREATE TABLE pokemon (
+                id INTEGER PRIMARY KEY,
+                pc_box INTEGER REFERENCES pc_box (number),
+                slot INTEGER NOT NULL,
+                nickname TEXT,
+                pokemon TEXT NOT NULL,
+                level INTEGER NOT NULL,
+                experience INTEGER NOT NULL,
+                gender TEXT NOT NULL,
+                nature TEXT,
+                ability TEXT,
+                held_item TEXT,
+                friendship INTEGER NOT NULL,
+                personality INTEGER NOT NULL,
+                shiny INTEGER NOT NULL,
+                iv_hp INTEGER NOT NULL,
+                iv_attack INTEGER NOT NULL,
+                iv_defence INTEGER NOT NULL,
+                iv_special_attack INTEGER NOT NULL,
+                iv_special_defence INTEGER NOT NULL,
+                iv_speed INTEGER NOT NULL,
+                ev_hp INTEGER NOT NULL,
+                ev_attack INTEGER NOT NULL,
+                ev_defence INTEGER NOT NULL,
+                ev_special_attack INTEGER NOT NULL,
+                ev_special_defence INTEGER NOT NULL,
+                ev_speed INTEGER NOT NULL
+            );
+            CREATE TABLE pokemon_move (
+                pokemon INTEGER NOT NULL REFERENCES pokemon (id) ON DELETE CASCADE,
+                slot INTEGER NOT NULL,
+                move TEXT NOT NULL,
+                PRIMARY KEY (pokemon, slot)
+            );
+            CREATE TABLE inventory (
+                item TEXT PRIMARY KEY,
+                quantity INTEGER NOT NULL
+            );
+            CREATE TABLE badge (
+                name TEXT PRIMARY KEY,
+                position INTEGER NOT NULL
+            );
+            INSERT INTO trainer VALUES (1, 'Red', 1, 3000, 90);
+            INSERT INTO inventory VALUES ('potion', 3), ('antidote', 1);
+            PRAGMA user_version = 1;",
+        )
+        .unwrap();
+    drop(connection);
+
+    let file = SaveFile::open(&path).unwrap();
+    assert_eq!(file.version().unwrap(), LATEST_VERSION);
+    let data = file.load().unwrap().unwrap();
+    assert_eq!(data.profile.play_time, Duration::from_secs(90));
+    assert_eq!(data.inventory, [stack("antidote", 1), stack("potion", 3)]);
+    drop(file);
+
+    // Opening the file again finds nothing left to migrate.
+    let file = SaveFile::open(&path).unwrap();
+    assert_eq!(file.version().unwrap(), LATEST_VERSION);
+    drop(file);
+
+    let connection = Connection::open(&path).unwrap();
+    connection
+        .pragma_update(None, "user_version", LATEST_VERSION + 1)
+        .unwrap();
+    drop(connection);
+    let result = SaveFile::open(&path);
+    assert!(
+        matches!(result, Err(SaveError::UnsupportedVersion(3))),
+        "{:?}",
+        result
+    );
+    std::fs::remove_file(&path).unwrap();
+}
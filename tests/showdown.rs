@@ -0,0 +1,77 @@
+//! Checks that teams in Showdown's format survive being read and written again.
+
+use pokemon_game::{
+    owned::Gender,
+    showdown::{export, parse, Set},
+    stats::Stats,
+};
+
+/// Two sets as Showdown exports them: one with every line it writes, and one relying on the defaults.
+const TEAM: &str = "Sparky (Pikachu) (F) @ Light Ball
+Ability: Lightning Rod
+Level: 50
+Shiny: Yes
+Happiness: 160
+EVs: 4 HP / 252 SpA / 252 Spe
+Timid Nature
+IVs: 0 Atk / 30 Def
+- Thunderbolt
+- Volt Switch
+- Hidden Power
+- Nasty Plot
+
+Rotom-Wash @ Leftovers
+Ability: Levitate
+EVs: 252 HP / 4 SpA / 252 SpD
+Calm Nature
+- Hydro Pump
+- Scald
+";
+
+#[test]
+fn sets_survive_a_round_trip() {
+    let sets = parse(TEAM).unwrap();
+    assert_eq!(sets.len(), 2);
+    assert_eq!(
+        sets[0],
+        Set {
+            nickname: Some("Sparky".to_string()),
+            pokemon: "pikachu".to_string(),
+            gender: Some(Gender::Female),
+            item: Some("light-ball".to_string()),
+            ability: Some("lightning-rod".to_string()),
+            level: 50,
+            shiny: true,
+            friendship: 160,
+            ev: Stats {
+                hp: 4,
+                special_attack: 252,
+                speed: 252,
+                ..Stats::default()
+            },
+            iv: Stats {
+                hp: 31,
+                attack: 0,
+                defence: 30,
+                special_attack: 31,
+                special_defence: 31,
+                speed: 31,
+            },
+            nature: Some("timid".to_string()),
+            moves: vec![
+                "thunderbolt".to_string(),
+                "volt-switch".to_string(),
+                "hidden-power".to_string(),
+                "nasty-plot".to_string(),
+            ],
+        }
+    );
+    assert_eq!(sets[1].pokemon, "rotom-wash");
+    assert_eq!(sets[1].level, 100);
+    assert_eq!(sets[1].gender, None);
+
+    let team: Vec<_> = sets.iter().map(Set::to_owned_pokemon).collect();
+    let exported = export(&team);
+    assert_eq!(parse(&exported).unwrap(), sets);
+    assert_eq!(exported, TEAM);
+}
@@ -0,0 +1,89 @@
+//! Checks that the sources keep each page of a list apart by its query.
+
+mod common;
+
+use std::path::PathBuf;
+
+use common::{fixture, fixtures};
+use pokemon_game::{
+    error::Error,
+    source::{DataSource, DiskCache, FixtureDir, Memory},
+};
+
+const FIRST_PAGE: &str = "pokemon/?offset=0&limit=2";
+const SECOND_PAGE: &str = "pokemon/?offset=2&limit=2";
+
+/// A directory in the temporary directory, removed first in case an earlier run left it behind.
+fn temporary(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&path);
+    path
+}
+
+/// Two pages of the `pokemon` list and Pikachu.
+fn pages() -> Memory {
+    let mut memory = Memory::new();
+    memory.insert(FIRST_PAGE, r#"{"results": ["bulbasaur", "ivysaur"]}"#);
+    memory.insert(SECOND_PAGE, r#"{"results": ["venusaur", "charmander"]}"#);
+    memory.insert("pokemon/25", fixture("pokemon/25"));
+    memory
+}
+
+#[tokio::test]
+async fn memory_keeps_pages_apart() {
+    let memory = pages();
+    let first = memory.fetch(FIRST_PAGE).await.unwrap();
+    let second = memory.fetch(SECOND_PAGE).await.unwrap();
+    assert!(first.contains("bulbasaur"), "{}", first);
+    assert!(second.contains("venusaur"), "{}", second);
+
+    // Slashes around the path do not matter, but the query does.
+    assert_eq!(
+        memory.fetch("pokemon?offset=0&limit=2").await.unwrap(),
+        first
+    );
+    assert_eq!(
+        memory.fetch("/pokemon/25/").await.unwrap(),
+        fixture("pokemon/25")
+    );
+    let result = memory.fetch("pokemon/").await;
+    assert!(matches!(result, Err(Error::NotFound(_))), "{:?}", result);
+    let result = memory.fetch("pokemon/?offset=4&limit=2").await;
+    assert!(matches!(result, Err(Error::NotFound(_))), "{:?}", result);
+}
+
+#[tokio::test]
+async fn disk_cache_keeps_pages_apart() {
+    let dir = temporary("disk-cache-pages");
+    let cache = DiskCache::new(&dir, pages());
+    let first = cache.fetch(FIRST_PAGE).await.unwrap();
+    let second = cache.fetch(SECOND_PAGE).await.unwrap();
+    assert_ne!(first, second);
+    cache.fetch("pokemon/25").await.unwrap();
+    assert!(dir.join("pokemon/index.offset=0&limit=2.json").exists());
+    assert!(dir.join("pokemon/index.offset=2&limit=2.json").exists());
+    assert!(dir.join("pokemon/25/index.json").exists());
+
+    // Everything fetched is served from the directory afterwards.
+    let cache = DiskCache::new(&dir, Memory::new());
+    assert_eq!(cache.fetch(FIRST_PAGE).await.unwrap(), first);
+    assert_eq!(cache.fetch(SECOND_PAGE).await.unwrap(), second);
+    assert_eq!(
+        cache.fetch("pokemon/25").await.unwrap(),
+        fixture("pokemon/25")
+    );
+    let result = cache.fetch("pokemon/?offset=4&limit=2").await;
+    assert!(matches!(result, Err(Error::NotFound(_))), "{:?}", result);
+    let result = cache.fetch("pokemon/?offset=../../25").await;
+    assert!(matches!(result, Err(Error::NotFound(_))), "{:?}", result);
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn fixture_pages_fall_back_to_the_list() {
+    let source = FixtureDir::new(fixtures());
+    assert_eq!(
+        source.fetch("pokemon/?offset=3&limit=3").await.unwrap(),
+        fixture("pokemon")
+    );
+}
@@ -0,0 +1,135 @@
+//! Checks that stat stages stay between -6 and +6 and scale stats, whether changed directly or by moves.
+
+use pokemon_game::{
+    battle::{
+        stages::{self, StatStages},
+        Battle, BattleEvent, Battler, Position,
+    },
+    generation::Generation,
+    model::moves::Move,
+    names::{PokemonTypeName, StatName},
+    owned::Gender,
+    stats::Stats,
+    type_chart::TypeChart,
+};
+
+const PLAYER: Position = Position { side: 0, slot: 0 };
+const OPPONENT: Position = Position { side: 1, slot: 0 };
+
+fn battle() -> Battle {
+    let battler = |name: &str| {
+        let stats = Stats {
+            hp: 300,
+            attack: 100,
+            defence: 100,
+            special_attack: 100,
+            special_defence: 100,
+            speed: 100,
+        };
+        Battler::new(name, 50, Gender::Male, vec![PokemonTypeName::Normal], stats)
+    };
+    let chart = TypeChart::default();
+    Battle::single(
+        Generation::V,
+        chart,
+        battler("Scizor"),
+        battler("Snorlax"),
+        0,
+    )
+}
+
+/// A move that never misses, built from the JSON PokeAPI would return for it.
+fn move_(json: &str) -> Move {
+    serde_json::from_str(json).unwrap()
+}
+
+fn swords_dance() -> Move {
+    move_(
+        r#"{
+            "name": "swords-dance",
+            "power": null,
+            "damage_class": {"name": "status"},
+            "type": {"name": "normal"},
+            "target": {"name": "user"},
+            "stat_changes": [{"change": 2, "stat": {"name": "attack"}}],
+            "meta": {"category": {"name": "net-good-stats"}, "stat_chance": 0}
+        }"#,
+    )
+}
+
+fn growl() -> Move {
+    move_(
+        r#"{
+            "name": "growl",
+            "power": null,
+            "damage_class": {"name": "status"},
+            "type": {"name": "normal"},
+            "target": {"name": "all-opponents"},
+            "stat_changes": [{"change": -1, "stat": {"name": "attack"}}],
+            "meta": {"category": {"name": "net-good-stats"}, "stat_chance": 0}
+        }"#,
+    )
+}
+
+#[test]
+fn stages_are_clamped_at_six() {
+    let mut stages = StatStages::default();
+    assert_eq!(stages.change(StatName::Attack, 4), Some(4));
+    assert_eq!(stages.change(StatName::Attack, 4), Some(2));
+    assert_eq!(stages.change(StatName::Attack, 1), Some(0));
+    assert_eq!(stages.get(StatName::Attack), Some(StatStages::MAX));
+
+    assert_eq!(stages.change(StatName::Evasion, -12), Some(-6));
+    assert_eq!(stages.change(StatName::Evasion, -1), Some(0));
+    assert_eq!(stages.get(StatName::Evasion), Some(StatStages::MIN));
+
+    assert_eq!(stages.change(StatName::Hp, 1), None);
+    assert_eq!(stages.get(StatName::Hp), None);
+}
+
+#[test]
+fn stages_scale_stats() {
+    assert_eq!(stages::stat_multiplier(0), 1.0);
+    assert_eq!(stages::stat_multiplier(1), 1.5);
+    assert_eq!(stages::stat_multiplier(6), 4.0);
+    assert_eq!(stages::stat_multiplier(-2), 0.5);
+    assert_eq!(stages::stat_multiplier(-6), 0.25);
+    // Beyond the limits, the multiplier is that of the limit.
+    assert_eq!(stages::stat_multiplier(8), 4.0);
+    assert_eq!(stages::stat_multiplier(-8), 0.25);
+
+    assert_eq!(stages::accuracy_multiplier(3), 2.0);
+    assert_eq!(stages::accuracy_multiplier(6), 3.0);
+    assert_eq!(stages::accuracy_multiplier(-6), 1.0 / 3.0);
+    assert_eq!(stages::accuracy_multiplier(-9), 1.0 / 3.0);
+}
+
+#[test]
+fn moves_change_stages_up_to_their_limit() {
+    let mut battle = battle();
+    for _ in 0..4 {
+        battle.use_move(PLAYER, None, &swords_dance()).unwrap();
+    }
+    assert_eq!(battle.battler(PLAYER).stages.attack, 6);
+    assert_eq!(battle.battler(PLAYER).staged_stat(StatName::Attack), 400);
+    let changes: Vec<i64> = battle
+        .log
+        .iter()
+        .filter_map(|event| match event {
+            BattleEvent::StatStageChanged {
+                target: PLAYER,
+                stat: StatName::Attack,
+                change,
+            } => Some(*change),
+            _ => None,
+        })
+        .collect();
+    // The last Swords Dance can raise Attack no further.
+    assert_eq!(changes, [2, 2, 2, 0]);
+
+    for _ in 0..7 {
+        battle.use_move(PLAYER, None, &growl()).unwrap();
+    }
+    assert_eq!(battle.battler(OPPONENT).stages.attack, -6);
+    assert_eq!(battle.battler(OPPONENT).staged_stat(StatName::Attack), 25);
+}
@@ -0,0 +1,285 @@
+//! Checks status conditions: the rules of each generation, type immunities and the counters of
+//! sleep, freeze and bad poison.
+
+use pokemon_game::{
+    battle::{
+        status::{self, CantMoveReason, Status, StatusRules},
+        Battle, BattleEvent, Battler, DamageCause, Position,
+    },
+    generation::Generation,
+    model::{moves::Move, pokemon::Type},
+    names::PokemonTypeName,
+    owned::Gender,
+    stats::Stats,
+    type_chart::TypeChart,
+};
+
+const PLAYER: Position = Position { side: 0, slot: 0 };
+const OPPONENT: Position = Position { side: 1, slot: 0 };
+
+fn battler(name: &str, types: Vec<PokemonTypeName>) -> Battler {
+    let stats = Stats {
+        hp: 160,
+        attack: 100,
+        defence: 100,
+        special_attack: 100,
+        special_defence: 100,
+        speed: 100,
+    };
+    Battler::new(name, 50, Gender::Male, types, stats)
+}
+
+fn battle_against(generation: Generation, opponent: Battler) -> Battle {
+    let chart = TypeChart::from_types(&[
+        type_(
+            r#"{"name": "electric", "damage_relations": {"no_damage_to": [{"name": "ground"}]}}"#,
+        ),
+        type_(r#"{"name": "normal", "damage_relations": {"no_damage_to": [{"name": "ghost"}]}}"#),
+    ]);
+    let player = battler("Jolteon", vec![PokemonTypeName::Electric]);
+    Battle::single(generation, chart, player, opponent, 0)
+}
+
+fn against_snorlax(generation: Generation) -> Battle {
+    battle_against(
+        generation,
+        battler("Snorlax", vec![PokemonTypeName::Normal]),
+    )
+}
+
+fn type_(json: &str) -> Type {
+    serde_json::from_str(json).unwrap()
+}
+
+/// A move that never misses, built from the JSON PokeAPI would return for it.
+fn move_(json: &str) -> Move {
+    serde_json::from_str(json).unwrap()
+}
+
+fn paralyzing(name: &str, type_: &str) -> Move {
+    move_(&format!(
+        r#"{{
+            "name": "{}",
+            "power": null,
+            "damage_class": {{"name": "status"}},
+            "type": {{"name": "{}"}},
+            "target": {{"name": "selected-pokemon"}},
+            "meta": {{"ailment": {{"name": "paralysis"}}, "ailment_chance": 0}}
+        }}"#,
+        name, type_
+    ))
+}
+
+fn toxic() -> Move {
+    move_(
+        r#"{
+            "name": "toxic",
+            "power": null,
+            "damage_class": {"name": "status"},
+            "type": {"name": "poison"},
+            "target": {"name": "selected-pokemon"},
+            "meta": {"ailment": {"name": "poison"}, "ailment_chance": 0},
+            "effect_entries": [{
+                "short_effect": "Badly poisons the target, inflicting more damage every turn.",
+                "language": {"name": "en"}
+            }]
+        }"#,
+    )
+}
+
+fn poison_powder() -> Move {
+    move_(
+        r#"{
+            "name": "poison-powder",
+            "power": null,
+            "damage_class": {"name": "status"},
+            "type": {"name": "poison"},
+            "target": {"name": "selected-pokemon"},
+            "meta": {"ailment": {"name": "poison"}, "ailment_chance": 0},
+            "effect_entries": [{
+                "short_effect": "Poisons the target.",
+                "language": {"name": "en"}
+            }]
+        }"#,
+    )
+}
+
+fn tackle() -> Move {
+    move_(
+        r#"{
+            "name": "tackle",
+            "power": 40,
+            "damage_class": {"name": "physical"},
+            "type": {"name": "normal"},
+            "target": {"name": "selected-pokemon"}
+        }"#,
+    )
+}
+
+fn ember() -> Move {
+    move_(
+        r#"{
+            "name": "ember",
+            "power": 40,
+            "damage_class": {"name": "special"},
+            "type": {"name": "fire"},
+            "target": {"name": "selected-pokemon"}
+        }"#,
+    )
+}
+
+fn cant_move(battle: &Battle, user: Position, reason: CantMoveReason) -> usize {
+    let event = BattleEvent::CantMove { user, reason };
+    battle.log.iter().filter(|logged| **logged == event).count()
+}
+
+#[test]
+fn rules_follow_the_generation() {
+    let first = StatusRules::for_generation(Generation::I);
+    let second = StatusRules::for_generation(Generation::II);
+    let fifth = StatusRules::for_generation(Generation::V);
+    let sixth = StatusRules::for_generation(Generation::VI);
+    let seventh = StatusRules::for_generation(Generation::VII);
+
+    assert_eq!(first.burn_damage, (1, 16));
+    assert_eq!(second.burn_damage, (1, 8));
+    assert_eq!(seventh.burn_damage, (1, 16));
+    assert_eq!(first.poison_damage, (1, 16));
+    assert_eq!(second.poison_damage, (1, 8));
+    assert_eq!(sixth.paralysis_speed, 0.25);
+    assert_eq!(seventh.paralysis_speed, 0.5);
+    assert_eq!(first.thaw_chance, 0);
+    assert_eq!(second.thaw_chance, 10);
+    assert_eq!(fifth.thaw_chance, 20);
+    assert_eq!(first.sleep_turns, 1..=7);
+    assert_eq!(fifth.sleep_turns, 1..=3);
+    assert!(!fifth.electric_immune_to_paralysis);
+    assert!(sixth.electric_immune_to_paralysis);
+    assert!(!first.steel_immune_to_poison);
+    assert!(second.steel_immune_to_poison);
+    assert!(first.normal_status_moves_affect_ghosts);
+    assert!(!StatusRules::for_generation(Generation::III).normal_status_moves_affect_ghosts);
+    assert!(StatusRules::for_generation(Generation::IV).normal_status_moves_affect_ghosts);
+}
+
+#[test]
+fn status_moves_respect_type_immunities() {
+    let thunder_wave = paralyzing("thunder-wave", "electric");
+    let mut battle = battle_against(
+        Generation::V,
+        battler("Sandslash", vec![PokemonTypeName::Ground]),
+    );
+    battle.use_move(PLAYER, None, &thunder_wave).unwrap();
+    assert!(battle
+        .log
+        .contains(&BattleEvent::NoEffect { target: OPPONENT }));
+    assert_eq!(battle.battler(OPPONENT).status, None);
+
+    let mut battle = against_snorlax(Generation::V);
+    battle.use_move(PLAYER, None, &thunder_wave).unwrap();
+    assert_eq!(battle.battler(OPPONENT).status, Some(Status::Paralysis));
+
+    // Glare stopped affecting Ghost types in Generation II and affected them again from Generation IV.
+    let glare = paralyzing("glare", "normal");
+    for (generation, affected) in [
+        (Generation::I, true),
+        (Generation::III, false),
+        (Generation::IV, true),
+    ] {
+        let mut battle =
+            battle_against(generation, battler("Gengar", vec![PokemonTypeName::Ghost]));
+        battle.use_move(PLAYER, None, &glare).unwrap();
+        let paralyzed = battle.battler(OPPONENT).status == Some(Status::Paralysis);
+        assert_eq!(paralyzed, affected, "{:?}", generation);
+    }
+}
+
+#[test]
+fn sleep_counts_down_before_waking_up() {
+    let mut battle = against_snorlax(Generation::V);
+    battle.battler_mut(PLAYER).status = Some(Status::Sleep { turns: 2 });
+    battle.use_move(PLAYER, None, &tackle()).unwrap();
+    assert_eq!(
+        battle.battler(PLAYER).status,
+        Some(Status::Sleep { turns: 1 })
+    );
+    battle.use_move(PLAYER, None, &tackle()).unwrap();
+    assert_eq!(cant_move(&battle, PLAYER, CantMoveReason::Asleep), 2);
+    assert_eq!(battle.battler(OPPONENT).hp, 160);
+
+    battle.use_move(PLAYER, None, &tackle()).unwrap();
+    assert!(battle
+        .log
+        .contains(&BattleEvent::StatusCured { target: PLAYER }));
+    assert_eq!(battle.battler(PLAYER).status, None);
+    assert!(battle.battler(OPPONENT).hp < 160);
+}
+
+#[test]
+fn frozen_pokemon_thaw_by_chance_or_fire() {
+    // Nothing thaws a Pokémon out by chance in Generation I.
+    let mut battle = against_snorlax(Generation::I);
+    battle.battler_mut(PLAYER).status = Some(Status::Freeze);
+    for _ in 0..50 {
+        battle.use_move(PLAYER, None, &tackle()).unwrap();
+    }
+    assert_eq!(cant_move(&battle, PLAYER, CantMoveReason::Frozen), 50);
+    assert_eq!(battle.battler(PLAYER).status, Some(Status::Freeze));
+
+    // One in five tries thaws it out from Generation III.
+    let mut battle = against_snorlax(Generation::V);
+    battle.battler_mut(PLAYER).status = Some(Status::Freeze);
+    let mut tries = 0;
+    while battle.battler(PLAYER).status.is_some() {
+        battle.use_move(PLAYER, None, &tackle()).unwrap();
+        tries += 1;
+        assert!(tries < 100);
+    }
+    assert_eq!(
+        cant_move(&battle, PLAYER, CantMoveReason::Frozen),
+        tries - 1
+    );
+
+    let mut battle = against_snorlax(Generation::I);
+    battle.battler_mut(OPPONENT).status = Some(Status::Freeze);
+    battle.use_move(PLAYER, None, &ember()).unwrap();
+    assert_eq!(battle.battler(OPPONENT).status, None);
+}
+
+#[test]
+fn bad_poison_hurts_more_every_turn() {
+    assert!(status::badly_poisons(&toxic()));
+    assert!(!status::badly_poisons(&poison_powder()));
+
+    let mut battle = against_snorlax(Generation::V);
+    battle.use_move(PLAYER, None, &toxic()).unwrap();
+    assert_eq!(
+        battle.battler(OPPONENT).status,
+        Some(Status::Toxic { turns: 0 })
+    );
+    for _ in 0..3 {
+        battle.end_turn();
+    }
+    let damage: Vec<_> = battle
+        .log
+        .iter()
+        .filter_map(|event| match event {
+            BattleEvent::Damaged {
+                target: OPPONENT,
+                amount,
+                cause: DamageCause::Poison,
+            } => Some(*amount),
+            _ => None,
+        })
+        .collect();
+    // 1, 2 and 3 sixteenths of 160 HP.
+    assert_eq!(damage, [10, 20, 30]);
+    assert_eq!(
+        battle.battler(OPPONENT).status,
+        Some(Status::Toxic { turns: 3 })
+    );
+
+    let mut battle = against_snorlax(Generation::V);
+    battle.use_move(PLAYER, None, &poison_powder()).unwrap();
+    assert_eq!(battle.battler(OPPONENT).status, Some(Status::Poison));
+}